@@ -17,6 +17,14 @@ export function feeAuthorityPda(platform: PublicKey, programId = new PublicKey(P
   ], programId);
 }
 
+export function acceptedMintPda(platform: PublicKey, mint: PublicKey, programId = new PublicKey(PROGRAM_ID)) {
+  return PublicKey.findProgramAddressSync([
+    Buffer.from('accepted_mint'),
+    platform.toBuffer(),
+    mint.toBuffer(),
+  ], programId);
+}
+
 export function creatorProfilePda(authority: PublicKey, platform: PublicKey, programId = new PublicKey(PROGRAM_ID)) {
   return PublicKey.findProgramAddressSync([
     Buffer.from('creator'),
@@ -25,6 +33,7 @@ export function creatorProfilePda(authority: PublicKey, platform: PublicKey, pro
   ], programId);
 }
 
+// Slots created before the per-creator counter were seeded by start_ts
 export function timeSlotPda(profile: PublicKey, startTs: number, programId = new PublicKey(PROGRAM_ID)) {
   const le = Buffer.alloc(8);
   le.writeBigInt64LE(BigInt(startTs));
@@ -35,6 +44,17 @@ export function timeSlotPda(profile: PublicKey, startTs: number, programId = new
   ], programId);
 }
 
+// New slots are seeded by the creator's slot index (0..profile.slot_count)
+export function timeSlotPdaByIndex(profile: PublicKey, index: number | bigint, programId = new PublicKey(PROGRAM_ID)) {
+  const le = Buffer.alloc(8);
+  le.writeBigUInt64LE(BigInt(index));
+  return PublicKey.findProgramAddressSync([
+    Buffer.from('slot'),
+    profile.toBuffer(),
+    le,
+  ], programId);
+}
+
 export function escrowPda(slot: PublicKey, programId = new PublicKey(PROGRAM_ID)) {
   return PublicKey.findProgramAddressSync([
     Buffer.from('escrow'),
@@ -42,6 +62,17 @@ export function escrowPda(slot: PublicKey, programId = new PublicKey(PROGRAM_ID)
   ], programId);
 }
 
+const ASSOCIATED_TOKEN_PROGRAM_ID = new PublicKey('ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL');
+
+// Escrow vault: the escrow PDA's associated token account for the slot mint
+export function escrowVaultAddress(escrow: PublicKey, mint: PublicKey, tokenProgram: PublicKey) {
+  return PublicKey.findProgramAddressSync([
+    escrow.toBuffer(),
+    tokenProgram.toBuffer(),
+    mint.toBuffer(),
+  ], ASSOCIATED_TOKEN_PROGRAM_ID);
+}
+
 export function bidBookPda(slot: PublicKey, programId = new PublicKey(PROGRAM_ID)) {
   return PublicKey.findProgramAddressSync([
     Buffer.from('bidbook'),
@@ -76,3 +107,62 @@ export function nftAuthPda(slot: PublicKey, programId = new PublicKey(PROGRAM_ID
     slot.toBuffer(),
   ], programId);
 }
+
+export function listingPda(slot: PublicKey, programId = new PublicKey(PROGRAM_ID)) {
+  return PublicKey.findProgramAddressSync([
+    Buffer.from('listing'),
+    slot.toBuffer(),
+  ], programId);
+}
+
+export function slotNftMintPda(slot: PublicKey, programId = new PublicKey(PROGRAM_ID)) {
+  return PublicKey.findProgramAddressSync([
+    Buffer.from('nft_mint'),
+    slot.toBuffer(),
+  ], programId);
+}
+
+export function allowlistClaimPda(slot: PublicKey, wallet: PublicKey, programId = new PublicKey(PROGRAM_ID)) {
+  return PublicKey.findProgramAddressSync([
+    Buffer.from('allowlist'),
+    slot.toBuffer(),
+    wallet.toBuffer(),
+  ], programId);
+}
+
+export function bookingRequestPda(slot: PublicKey, requester: PublicKey, programId = new PublicKey(PROGRAM_ID)) {
+  return PublicKey.findProgramAddressSync([
+    Buffer.from('booking_request'),
+    slot.toBuffer(),
+    requester.toBuffer(),
+  ], programId);
+}
+
+export function holdConfigPda(platform: PublicKey, programId = new PublicKey(PROGRAM_ID)) {
+  return PublicKey.findProgramAddressSync([
+    Buffer.from('hold_config'),
+    platform.toBuffer(),
+  ], programId);
+}
+
+export function slotHoldPda(slot: PublicKey, programId = new PublicKey(PROGRAM_ID)) {
+  return PublicKey.findProgramAddressSync([
+    Buffer.from('hold'),
+    slot.toBuffer(),
+  ], programId);
+}
+
+export function holdQuotaPda(platform: PublicKey, wallet: PublicKey, programId = new PublicKey(PROGRAM_ID)) {
+  return PublicKey.findProgramAddressSync([
+    Buffer.from('hold_quota'),
+    platform.toBuffer(),
+    wallet.toBuffer(),
+  ], programId);
+}
+
+export function solReceiversPda(platform: PublicKey, programId = new PublicKey(PROGRAM_ID)) {
+  return PublicKey.findProgramAddressSync([
+    Buffer.from('sol_receivers'),
+    platform.toBuffer(),
+  ], programId);
+}
@@ -0,0 +1,82 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::hash::hashv;
+
+use crate::*;
+use crate::ErrorCode;
+
+// Leaf for `wallet`; entries with a quantity commit to it so the cap cannot be forged
+pub fn allowlist_leaf(wallet: &Pubkey, quantity: Option<u16>) -> [u8; 32] {
+    match quantity {
+        Some(q) => hashv(&[wallet.as_ref(), &q.to_le_bytes()]).to_bytes(),
+        None => hashv(&[wallet.as_ref()]).to_bytes(),
+    }
+}
+
+// Sorted-pair Merkle verification, so proofs need no left/right flags
+pub fn verify_proof(root: &[u8; 32], leaf: [u8; 32], proof: &[[u8; 32]]) -> bool {
+    let computed = proof.iter().fold(leaf, |node, sibling| {
+        if node <= *sibling {
+            hashv(&[&node, sibling]).to_bytes()
+        } else {
+            hashv(&[sibling, &node]).to_bytes()
+        }
+    });
+    computed == *root
+}
+
+// Check `wallet` against the slot's allowlist; returns the per-address cap when the leaf has one.
+// Slots without a root accept everyone.
+pub fn check_allowlist(
+    slot: &TimeSlot,
+    wallet: Pubkey,
+    allowlist: Option<&AllowlistProof>,
+) -> Result<Option<u16>> {
+    let Some(root) = slot.allowlist_root else {
+        return Ok(None);
+    };
+    let entry = allowlist.ok_or(ErrorCode::NotOnAllowlist)?;
+    let leaf = allowlist_leaf(&wallet, entry.quantity);
+    require!(verify_proof(&root, leaf, &entry.proof), ErrorCode::NotOnAllowlist);
+    Ok(entry.quantity)
+}
+
+// Count one booking against a capped allowlist entry
+pub fn consume_allowlist(
+    claim: Option<&mut Account<AllowlistClaim>>,
+    slot: Pubkey,
+    wallet: Pubkey,
+    quantity: Option<u16>,
+    bump: Option<u8>,
+) -> Result<()> {
+    let Some(cap) = quantity else {
+        return Ok(());
+    };
+    let claim = claim.ok_or(ErrorCode::AllowlistQuantityExceeded)?;
+    require!(claim.used < cap, ErrorCode::AllowlistQuantityExceeded);
+    claim.slot = slot;
+    claim.wallet = wallet;
+    claim.used = claim.used.checked_add(1).ok_or(ErrorCode::Overflow)?;
+    if let Some(bump) = bump {
+        claim.bump = bump;
+    }
+    Ok(())
+}
+
+// Creator swaps the allowlist (or lifts it with None) while the slot is still selling
+pub fn set_allowlist_root(ctx: Context<SetAllowlistRoot>, root: Option<[u8; 32]>) -> Result<()> {
+    let slot = &mut ctx.accounts.slot;
+    require!(!(slot.nft_ticket && root.is_some()), ErrorCode::NftTicketMode);
+    require!(
+        slot.state == SlotState::Open || slot.state == SlotState::AuctionLive,
+        ErrorCode::InvalidState
+    );
+    let now = Clock::get()?.unix_timestamp;
+    let closes_at = match slot.mode {
+        Mode::Stable => slot.start_ts,
+        Mode::EnglishAuction | Mode::SealedBid => slot.auction_end_ts.unwrap_or(slot.start_ts),
+    };
+    require!(now < closes_at, ErrorCode::TooLate);
+    slot.allowlist_root = root;
+    emit!(AllowlistRootSetEvent { slot: slot.key(), root });
+    Ok(())
+}
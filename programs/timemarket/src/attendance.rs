@@ -0,0 +1,45 @@
+use anchor_lang::prelude::*;
+
+use crate::*;
+use crate::ErrorCode;
+
+// Who attended a checked-in session: the ticket holder in ticket mode, otherwise the booking's
+// beneficiary (the creator may check in on their behalf)
+pub fn attendee_of(slot: &TimeSlot, escrow: &Escrow, signer: Pubkey) -> Result<Pubkey> {
+    if slot.nft_ticket {
+        Ok(signer)
+    } else {
+        escrow.buyer.ok_or_else(|| error!(ErrorCode::NotReserved))
+    }
+}
+
+// Add a checked-in session to the attendee's soulbound record with this creator
+pub fn record_attendance(
+    attendance: &mut Account<Attendance>,
+    slot: &Account<TimeSlot>,
+    attendee: Pubkey,
+    bump: u8,
+) -> Result<()> {
+    let now = Clock::get()?.unix_timestamp;
+    if attendance.sessions == 0 {
+        attendance.creator_profile = slot.creator_profile;
+        attendance.attendee = attendee;
+        attendance.first_checkin_ts = now;
+        attendance.bump = bump;
+    }
+    let duration = slot.end_ts.saturating_sub(slot.start_ts).max(0) as u64;
+    attendance.sessions = attendance.sessions.checked_add(1).ok_or(ErrorCode::Overflow)?;
+    attendance.seconds_attended = attendance
+        .seconds_attended
+        .checked_add(duration)
+        .ok_or(ErrorCode::Overflow)?;
+    attendance.last_checkin_ts = now;
+    emit!(AttendanceRecordedEvent {
+        creator_profile: slot.creator_profile,
+        attendee,
+        slot: slot.key(),
+        sessions: attendance.sessions,
+        seconds_attended: attendance.seconds_attended,
+    });
+    Ok(())
+}
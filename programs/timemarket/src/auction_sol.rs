@@ -0,0 +1,248 @@
+use anchor_lang::prelude::*;
+
+use crate::*;
+use crate::ErrorCode;
+use crate::escrow::{pay_auction_t0, pay_auction_t1, EscrowPayment, Payout};
+use crate::market::{check_bid, record_bid};
+
+// Lamport-denominated auctions: funds sit on the escrow PDA itself, amounts are in lamports
+// and the T0/T1 split is shared with the SPL auction paths through EscrowPayment.
+
+pub fn bid_place_sol(ctx: Context<BidPlaceSol>, bid_amount: u64, max_auto_bid: Option<u64>, allowlist: Option<AllowlistProof>) -> Result<()> {
+    require!(ctx.accounts.slot.payment_kind == PaymentKind::Sol, ErrorCode::WrongPaymentKind);
+    let beneficiary = ctx.accounts.beneficiary.as_ref().map_or(ctx.accounts.bidder.key(), |b| b.key());
+    crate::gate::check_gate(
+        &ctx.accounts.slot,
+        beneficiary,
+        ctx.accounts.gate_token.as_ref(),
+        ctx.accounts.gate_metadata.as_deref(),
+    )?;
+    crate::allowlist::check_allowlist(&ctx.accounts.slot, beneficiary, allowlist.as_ref())?;
+    let min_required = check_bid(&ctx.accounts.slot, &ctx.accounts.bidbook, ctx.accounts.bidder.key(), bid_amount)?;
+
+    // Transfer lamports bidder -> escrow PDA (full bid amount)
+    let payment = EscrowPayment::sol(&ctx.accounts.slot, &ctx.accounts.escrow, ctx.accounts.system_program.to_account_info())?;
+    let bidder = ctx.accounts.bidder.to_account_info();
+    payment.collect(&bidder, &bidder, bid_amount)?;
+
+    record_bid(
+        &mut ctx.accounts.slot,
+        &mut ctx.accounts.bidbook,
+        &mut ctx.accounts.escrow,
+        &mut ctx.accounts.refund_queue,
+        &mut ctx.accounts.auto_bid_store,
+        ctx.accounts.bidder.key(),
+        beneficiary,
+        bid_amount,
+        min_required,
+        max_auto_bid,
+    )
+}
+
+pub fn bid_outbid_refund_sol(ctx: Context<BidOutbidRefundSol>) -> Result<()> {
+    require!(ctx.accounts.slot.payment_kind == PaymentKind::Sol, ErrorCode::WrongPaymentKind);
+    require!(!ctx.accounts.slot.frozen, ErrorCode::Frozen);
+    let q = &mut ctx.accounts.refund_queue;
+    require!(q.count > 0, ErrorCode::NothingToRefund);
+    let idx = q.cursor as usize;
+    let entry = q.entries.get(idx).cloned().ok_or(ErrorCode::NothingToRefund)?;
+    require_keys_eq!(entry.bidder, ctx.accounts.prev_bidder.key(), ErrorCode::Unauthorized);
+
+    let payment = EscrowPayment::sol(&ctx.accounts.slot, &ctx.accounts.escrow, ctx.accounts.system_program.to_account_info())?;
+    payment.pay(Payout { to: &ctx.accounts.prev_bidder.to_account_info(), amount: entry.amount })?;
+    let escrow = &mut ctx.accounts.escrow;
+    escrow.amount_locked = escrow.amount_locked.checked_sub(entry.amount).ok_or(ErrorCode::Overflow)?;
+    emit!(OutbidRefundedEvent { slot: ctx.accounts.slot.key(), to: entry.bidder, amount: entry.amount });
+    // Advance queue
+    q.cursor = q.cursor.saturating_add(1);
+    q.count = q.count.saturating_sub(1);
+    Ok(())
+}
+
+pub fn buy_now_sol(ctx: Context<BuyNowSol>, allowlist: Option<AllowlistProof>) -> Result<()> {
+    let payment = EscrowPayment::sol(&ctx.accounts.slot, &ctx.accounts.escrow, ctx.accounts.system_program.to_account_info())?;
+    let slot = &mut ctx.accounts.slot;
+    require!(!slot.frozen, ErrorCode::Frozen);
+    require!(slot.mode == Mode::EnglishAuction, ErrorCode::WrongMode);
+    require!(slot.capacity_total == 1, ErrorCode::MultiCapacityUnsupported);
+    require!(slot.state == SlotState::Open || slot.state == SlotState::AuctionLive, ErrorCode::InvalidState);
+    check_on_sale(slot)?;
+    let price = slot.buy_now.ok_or(ErrorCode::InvalidPrice)?;
+    require!(ctx.accounts.bidder.key() != slot.creator_authority, ErrorCode::Unauthorized);
+    let beneficiary = ctx.accounts.beneficiary.as_ref().map_or(ctx.accounts.bidder.key(), |b| b.key());
+    crate::gate::check_gate(
+        slot,
+        beneficiary,
+        ctx.accounts.gate_token.as_ref(),
+        ctx.accounts.gate_metadata.as_deref(),
+    )?;
+    let allowlist_cap = crate::allowlist::check_allowlist(slot, beneficiary, allowlist.as_ref())?;
+    crate::allowlist::consume_allowlist(
+        ctx.accounts.allowlist_claim.as_mut(),
+        slot.key(),
+        beneficiary,
+        allowlist_cap,
+        ctx.bumps.allowlist_claim,
+    )?;
+
+    let bidder = ctx.accounts.bidder.to_account_info();
+    payment.collect(&bidder, &bidder, price)?;
+
+    // Current highest bidder (if any) gets their lamports back through the refund queue
+    let book = &mut ctx.accounts.bidbook;
+    if book.highest_bid > 0 {
+        let q = &mut ctx.accounts.refund_queue;
+        require!(q.count < q.max_entries, ErrorCode::InvalidCapacity);
+        q.entries.push(RefundEntry { bidder: book.highest_bidder, amount: book.highest_bid });
+        q.count = q.count.saturating_add(1);
+    }
+
+    let escrow = &mut ctx.accounts.escrow;
+    escrow.amount_locked = escrow.amount_locked.checked_add(price).ok_or(ErrorCode::Overflow)?;
+    escrow.buyer = Some(beneficiary);
+    escrow.payer = Some(ctx.accounts.bidder.key());
+    book.highest_bid = price;
+    book.highest_bidder = ctx.accounts.bidder.key();
+    book.highest_beneficiary = beneficiary;
+
+    let eff_bps = effective_fee_bps(&ctx.accounts.platform, &ctx.accounts.profile);
+    pay_auction_t0(
+        &payment,
+        escrow,
+        &ctx.accounts.creator_payout.to_account_info(),
+        &ctx.accounts.fee_receiver.to_account_info(),
+        price,
+        eff_bps,
+    )?;
+    slot.state = SlotState::Locked;
+    emit!(AuctionEndedEvent { slot: slot.key(), winner: book.highest_bidder, winning_bid: price });
+    Ok(())
+}
+
+pub fn auction_end_sol(ctx: Context<AuctionEndSol>) -> Result<()> {
+    let payment = EscrowPayment::sol(&ctx.accounts.slot, &ctx.accounts.escrow, ctx.accounts.system_program.to_account_info())?;
+    let slot = &mut ctx.accounts.slot;
+    require!(!slot.frozen, ErrorCode::Frozen);
+    require!(slot.mode == Mode::EnglishAuction, ErrorCode::WrongMode);
+    require!(slot.capacity_total == 1, ErrorCode::MultiCapacityUnsupported);
+    require!(slot.state == SlotState::AuctionLive, ErrorCode::InvalidState);
+    let now = Clock::get()?.unix_timestamp;
+    let end = slot.auction_end_ts.ok_or(ErrorCode::MissingAuctionWindow)?;
+    require!(now >= end, ErrorCode::TooEarly);
+    let book = &ctx.accounts.bidbook;
+    require!(book.highest_bid > 0, ErrorCode::NoBids);
+
+    // Bind escrow to winner; outbid refunds must have been drained first
+    let escrow = &mut ctx.accounts.escrow;
+    escrow.buyer = Some(book.highest_beneficiary);
+    escrow.payer = Some(book.highest_bidder);
+    require!(escrow.amount_locked == book.highest_bid, ErrorCode::InvalidEscrowBalance);
+
+    let eff_bps = effective_fee_bps(&ctx.accounts.platform, &ctx.accounts.profile);
+    pay_auction_t0(
+        &payment,
+        escrow,
+        &ctx.accounts.creator_payout.to_account_info(),
+        &ctx.accounts.fee_receiver.to_account_info(),
+        book.highest_bid,
+        eff_bps,
+    )?;
+    slot.state = SlotState::Locked;
+    emit!(AuctionEndedEvent { slot: slot.key(), winner: book.highest_bidder, winning_bid: book.highest_bid });
+    Ok(())
+}
+
+pub fn auction_settle_sol(ctx: Context<AuctionSettleSol>) -> Result<()> {
+    let payment = EscrowPayment::sol(&ctx.accounts.slot, &ctx.accounts.escrow, ctx.accounts.system_program.to_account_info())?;
+    let slot = &mut ctx.accounts.slot;
+    let book = &ctx.accounts.bidbook;
+    require!(slot.mode == Mode::EnglishAuction, ErrorCode::WrongMode);
+    require!(!slot.frozen, ErrorCode::Frozen);
+    require!(book.highest_bid > 0, ErrorCode::NoBids);
+    // After T0, T1 happens from Completed state
+    require!(slot.state == SlotState::Completed, ErrorCode::InvalidState);
+
+    let t0_base = mul_bps_u64(book.highest_bid, AUCTION_T0_BPS)?;
+    let t1_base = book.highest_bid.checked_sub(t0_base).ok_or(ErrorCode::Overflow)?;
+    let eff_bps = effective_fee_bps(&ctx.accounts.platform, &ctx.accounts.profile);
+    let (t1_creator, t1_fee, t1_withhold) = pay_auction_t1(
+        &payment,
+        &mut ctx.accounts.escrow,
+        &ctx.accounts.creator_payout.to_account_info(),
+        &ctx.accounts.fee_receiver.to_account_info(),
+        &ctx.accounts.dispute_receiver.to_account_info(),
+        t1_base,
+        eff_bps,
+    )?;
+    slot.state = SlotState::Settled;
+    emit!(SettledT1Event { slot: slot.key(), to: ctx.accounts.creator_payout.key(), amount: t1_creator, fee: t1_fee, retained: t1_withhold });
+    Ok(())
+}
+
+pub fn sealed_auction_end_sol(ctx: Context<SealedAuctionEndSol>) -> Result<()> {
+    let payment = EscrowPayment::sol(&ctx.accounts.slot, &ctx.accounts.escrow, ctx.accounts.system_program.to_account_info())?;
+    let slot = &mut ctx.accounts.slot;
+    require!(!slot.frozen, ErrorCode::Frozen);
+    require!(slot.mode == Mode::SealedBid, ErrorCode::WrongMode);
+    require!(slot.capacity_total == 1, ErrorCode::MultiCapacityUnsupported);
+    require!(slot.state == SlotState::Open || slot.state == SlotState::AuctionLive, ErrorCode::InvalidState);
+    let now = Clock::get()?.unix_timestamp;
+    let end = slot.auction_end_ts.ok_or(ErrorCode::MissingAuctionWindow)?;
+    require!(now >= end, ErrorCode::TooEarly);
+
+    // Determine highest revealed bid
+    let mut highest: Option<(Pubkey, u64)> = None;
+    for e in ctx.accounts.commit_store.entries.iter() {
+        if let (true, Some(b)) = (e.revealed, e.bid_amount) {
+            highest = match highest {
+                None => Some((e.bidder, b)),
+                Some((_, hb)) if b > hb => Some((e.bidder, b)),
+                other => other,
+            };
+        }
+    }
+    let (winner, winning_bid) = highest.ok_or(ErrorCode::NoBids)?;
+
+    let escrow = &mut ctx.accounts.escrow;
+    escrow.buyer = Some(winner);
+    escrow.payer = Some(winner);
+    require!(escrow.amount_locked == winning_bid, ErrorCode::InvalidEscrowBalance);
+
+    let eff_bps = effective_fee_bps(&ctx.accounts.platform, &ctx.accounts.profile);
+    pay_auction_t0(
+        &payment,
+        escrow,
+        &ctx.accounts.creator_payout.to_account_info(),
+        &ctx.accounts.fee_receiver.to_account_info(),
+        winning_bid,
+        eff_bps,
+    )?;
+    slot.state = SlotState::Locked;
+    emit!(AuctionEndedEvent { slot: slot.key(), winner, winning_bid });
+    Ok(())
+}
+
+pub fn sealed_auction_settle_sol(ctx: Context<SealedAuctionSettleSol>) -> Result<()> {
+    let payment = EscrowPayment::sol(&ctx.accounts.slot, &ctx.accounts.escrow, ctx.accounts.system_program.to_account_info())?;
+    let slot = &mut ctx.accounts.slot;
+    require!(slot.mode == Mode::SealedBid, ErrorCode::WrongMode);
+    require!(slot.capacity_total == 1, ErrorCode::MultiCapacityUnsupported);
+    require!(!slot.frozen, ErrorCode::Frozen);
+    require!(slot.state == SlotState::Completed, ErrorCode::InvalidState);
+
+    // Same as the SPL sealed path: what is left after T0 is the T1 base
+    let t1_base = ctx.accounts.escrow.amount_locked;
+    let eff_bps = effective_fee_bps(&ctx.accounts.platform, &ctx.accounts.profile);
+    let (t1_creator, t1_fee, t1_withhold) = pay_auction_t1(
+        &payment,
+        &mut ctx.accounts.escrow,
+        &ctx.accounts.creator_payout.to_account_info(),
+        &ctx.accounts.fee_receiver.to_account_info(),
+        &ctx.accounts.dispute_receiver.to_account_info(),
+        t1_base,
+        eff_bps,
+    )?;
+    slot.state = SlotState::Settled;
+    emit!(SettledT1Event { slot: slot.key(), to: ctx.accounts.creator_payout.key(), amount: t1_creator, fee: t1_fee, retained: t1_withhold });
+    Ok(())
+}
@@ -0,0 +1,286 @@
+use anchor_lang::prelude::*;
+
+use crate::*;
+use crate::ErrorCode;
+use crate::escrow::{EscrowPayment, Payout};
+
+// Approval slots: buyers deposit the list price with a request, the creator approves one of
+// them (which books the slot) or declines. Deposits sit in the slot escrow alongside, not inside,
+// amount_locked. Declined or expired requests go back through refund_booking_request; approving
+// one request refunds and closes every other one in the same instruction.
+
+#[allow(clippy::too_many_arguments)]
+fn request<'info>(
+    payment: &EscrowPayment<'info>,
+    slot: &Account<'info, TimeSlot>,
+    escrow: &mut Account<'info, Escrow>,
+    request: &mut Account<'info, BookingRequest>,
+    from: &AccountInfo<'info>,
+    requester: &AccountInfo<'info>,
+    message_hash: Option<[u8; 32]>,
+    bump: u8,
+) -> Result<()> {
+    let window = slot.approval_window_sec.ok_or(ErrorCode::BookingRequestsDisabled)?;
+    require!(!slot.frozen, ErrorCode::Frozen);
+    require!(slot.state == SlotState::Open, ErrorCode::InvalidState);
+    check_on_sale(slot)?;
+    require!(slot.capacity_sold < slot.capacity_total, ErrorCode::CapacityExhausted);
+    require!(requester.key() != slot.creator_authority, ErrorCode::Unauthorized);
+    let now = Clock::get()?.unix_timestamp;
+    require!(now < slot.start_ts, ErrorCode::TooLate);
+
+    let amount = slot.price;
+    payment.collect(from, requester, amount)?;
+    escrow.requests_locked = escrow.requests_locked.checked_add(amount).ok_or(ErrorCode::Overflow)?;
+
+    // The creator must answer before the window runs out or the session starts
+    let expires_at = now.checked_add(window as i64).ok_or(ErrorCode::Overflow)?.min(slot.start_ts);
+    request.slot = slot.key();
+    request.requester = requester.key();
+    request.amount = amount;
+    request.message_hash = message_hash;
+    request.expires_at = expires_at;
+    request.round = slot.booking_round;
+    request.declined = false;
+    request.bump = bump;
+    emit!(BookingRequestedEvent {
+        slot: slot.key(),
+        requester: requester.key(),
+        amount,
+        message_hash,
+        expires_at,
+    });
+    Ok(())
+}
+
+pub fn request_booking<'info>(ctx: Context<'_, '_, 'info, 'info, RequestBooking<'info>>, message_hash: Option<[u8; 32]>, allowlist: Option<AllowlistProof>) -> Result<()> {
+    let payment = EscrowPayment::spl(
+        &ctx.accounts.slot,
+        &ctx.accounts.escrow,
+        ctx.accounts.token_program.to_account_info(),
+        ctx.accounts.escrow_vault.to_account_info(),
+        ctx.accounts.mint.to_account_info(),
+        ctx.accounts.mint.decimals,
+        ctx.remaining_accounts,
+    )?;
+    crate::gate::check_gate(
+        &ctx.accounts.slot,
+        ctx.accounts.requester.key(),
+        ctx.accounts.gate_token.as_ref(),
+        ctx.accounts.gate_metadata.as_deref(),
+    )?;
+    // The creator screens every request, so only membership is checked, not a quantity cap
+    crate::allowlist::check_allowlist(&ctx.accounts.slot, ctx.accounts.requester.key(), allowlist.as_ref())?;
+    request(
+        &payment,
+        &ctx.accounts.slot,
+        &mut ctx.accounts.escrow,
+        &mut ctx.accounts.booking_request,
+        &ctx.accounts.requester_token.to_account_info(),
+        &ctx.accounts.requester.to_account_info(),
+        message_hash,
+        ctx.bumps.booking_request,
+    )
+}
+
+pub fn request_booking_sol(ctx: Context<RequestBookingSol>, message_hash: Option<[u8; 32]>, allowlist: Option<AllowlistProof>) -> Result<()> {
+    let payment = EscrowPayment::sol(
+        &ctx.accounts.slot,
+        &ctx.accounts.escrow,
+        ctx.accounts.system_program.to_account_info(),
+    )?;
+    crate::gate::check_gate(
+        &ctx.accounts.slot,
+        ctx.accounts.requester.key(),
+        ctx.accounts.gate_token.as_ref(),
+        ctx.accounts.gate_metadata.as_deref(),
+    )?;
+    crate::allowlist::check_allowlist(&ctx.accounts.slot, ctx.accounts.requester.key(), allowlist.as_ref())?;
+    let requester = ctx.accounts.requester.to_account_info();
+    request(
+        &payment,
+        &ctx.accounts.slot,
+        &mut ctx.accounts.escrow,
+        &mut ctx.accounts.booking_request,
+        &requester,
+        &requester,
+        message_hash,
+        ctx.bumps.booking_request,
+    )
+}
+
+// Book the slot for this request. The round bump makes every other request refundable, and
+// they all have to be refunded here so no deposit is left behind in a booked slot.
+fn approve(slot: &mut Account<TimeSlot>, escrow: &mut Account<Escrow>, request: &BookingRequest) -> Result<()> {
+    require!(!slot.frozen, ErrorCode::Frozen);
+    require!(slot.state == SlotState::Open, ErrorCode::InvalidState);
+    require!(slot.capacity_sold < slot.capacity_total, ErrorCode::CapacityExhausted);
+    require!(!request.declined && request.round == slot.booking_round, ErrorCode::InvalidState);
+    let now = Clock::get()?.unix_timestamp;
+    require!(now <= request.expires_at, ErrorCode::RequestExpired);
+
+    // The deposit moves from the request pool into the booking
+    escrow.requests_locked = escrow.requests_locked.checked_sub(request.amount).ok_or(ErrorCode::Overflow)?;
+    escrow.amount_locked = escrow.amount_locked.checked_add(request.amount).ok_or(ErrorCode::Overflow)?;
+    escrow.buyer = Some(request.requester);
+    escrow.payer = Some(request.requester);
+    slot.state = SlotState::Reserved;
+    slot.booking_round = slot.booking_round.checked_add(1).ok_or(ErrorCode::Overflow)?;
+    emit!(BookingApprovedEvent { slot: slot.key(), requester: request.requester, amount: request.amount });
+    emit!(ReservedEvent { slot: slot.key(), buyer: request.requester, payer: request.requester, amount: request.amount });
+    Ok(())
+}
+
+// Refund and close one superseded request passed through remaining_accounts. Closing it right
+// away means the same request listed twice fails to deserialize the second time.
+#[allow(clippy::too_many_arguments)]
+fn refund_superseded<'info>(
+    payment: &EscrowPayment<'info>,
+    slot: &TimeSlot,
+    slot_key: Pubkey,
+    escrow: &mut Account<'info, Escrow>,
+    approved: Pubkey,
+    request_info: &'info AccountInfo<'info>,
+    requester: &AccountInfo<'info>,
+    to: &AccountInfo<'info>,
+) -> Result<()> {
+    require!(request_info.key() != approved, ErrorCode::InvalidBookingRequestAccount);
+    let request = Account::<BookingRequest>::try_from(request_info)?;
+    require_keys_eq!(request.slot, slot_key, ErrorCode::InvalidBookingRequestAccount);
+    require_keys_eq!(request.requester, requester.key(), ErrorCode::InvalidBookingRequestAccount);
+    refund_request(payment, slot, escrow, &request, to)?;
+    request.close(requester.clone())
+}
+
+pub fn approve_booking<'info>(ctx: Context<'_, '_, 'info, 'info, ApproveBooking<'info>>, superseded: u8) -> Result<()> {
+    let split = (superseded as usize).checked_mul(3).ok_or(ErrorCode::Overflow)?;
+    require!(split <= ctx.remaining_accounts.len(), ErrorCode::InvalidBookingRequestAccount);
+    let (others, hook_accounts) = ctx.remaining_accounts.split_at(split);
+    let payment = EscrowPayment::spl(
+        &ctx.accounts.slot,
+        &ctx.accounts.escrow,
+        ctx.accounts.token_program.to_account_info(),
+        ctx.accounts.escrow_vault.to_account_info(),
+        ctx.accounts.mint.to_account_info(),
+        ctx.accounts.mint.decimals,
+        hook_accounts,
+    )?;
+    approve(&mut ctx.accounts.slot, &mut ctx.accounts.escrow, &ctx.accounts.booking_request)?;
+
+    let slot_key = ctx.accounts.slot.key();
+    let approved = ctx.accounts.booking_request.key();
+    for chunk in others.chunks(3) {
+        let [request_info, requester, requester_token] = chunk else {
+            return err!(ErrorCode::InvalidBookingRequestAccount);
+        };
+        let token = InterfaceAccount::<TokenAccount>::try_from(requester_token)?;
+        require!(
+            token.owner == requester.key() && token.mint == ctx.accounts.mint.key(),
+            ErrorCode::InvalidBookingRequestAccount
+        );
+        refund_superseded(
+            &payment,
+            &ctx.accounts.slot,
+            slot_key,
+            &mut ctx.accounts.escrow,
+            approved,
+            request_info,
+            requester,
+            requester_token,
+        )?;
+    }
+    require!(ctx.accounts.escrow.requests_locked == 0, ErrorCode::RequestsOutstanding);
+    Ok(())
+}
+
+pub fn approve_booking_sol<'info>(ctx: Context<'_, '_, 'info, 'info, ApproveBookingSol<'info>>) -> Result<()> {
+    let payment = EscrowPayment::sol(
+        &ctx.accounts.slot,
+        &ctx.accounts.escrow,
+        ctx.accounts.system_program.to_account_info(),
+    )?;
+    approve(&mut ctx.accounts.slot, &mut ctx.accounts.escrow, &ctx.accounts.booking_request)?;
+
+    let slot_key = ctx.accounts.slot.key();
+    let approved = ctx.accounts.booking_request.key();
+    for chunk in ctx.remaining_accounts.chunks(2) {
+        let [request_info, requester] = chunk else {
+            return err!(ErrorCode::InvalidBookingRequestAccount);
+        };
+        refund_superseded(
+            &payment,
+            &ctx.accounts.slot,
+            slot_key,
+            &mut ctx.accounts.escrow,
+            approved,
+            request_info,
+            requester,
+            requester,
+        )?;
+    }
+    require!(ctx.accounts.escrow.requests_locked == 0, ErrorCode::RequestsOutstanding);
+    Ok(())
+}
+
+pub fn decline_booking(ctx: Context<DeclineBooking>) -> Result<()> {
+    let request = &mut ctx.accounts.booking_request;
+    require!(!request.declined, ErrorCode::InvalidState);
+    request.declined = true;
+    emit!(BookingDeclinedEvent { slot: ctx.accounts.slot.key(), requester: request.requester });
+    Ok(())
+}
+
+// Return a declined, expired or superseded request's deposit; anyone may crank this
+fn refund_request<'info>(
+    payment: &EscrowPayment<'info>,
+    slot: &TimeSlot,
+    escrow: &mut Account<'info, Escrow>,
+    request: &BookingRequest,
+    to: &AccountInfo<'info>,
+) -> Result<()> {
+    let now = Clock::get()?.unix_timestamp;
+    let open = !request.declined
+        && request.round == slot.booking_round
+        && slot.state == SlotState::Open
+        && now <= request.expires_at;
+    require!(!open, ErrorCode::RequestStillPending);
+
+    payment.pay(Payout { to, amount: request.amount })?;
+    escrow.requests_locked = escrow.requests_locked.checked_sub(request.amount).ok_or(ErrorCode::Overflow)?;
+    emit!(BookingRequestRefundedEvent { slot: request.slot, requester: request.requester, amount: request.amount });
+    Ok(())
+}
+
+pub fn refund_booking_request<'info>(ctx: Context<'_, '_, 'info, 'info, RefundBookingRequest<'info>>) -> Result<()> {
+    let payment = EscrowPayment::spl(
+        &ctx.accounts.slot,
+        &ctx.accounts.escrow,
+        ctx.accounts.token_program.to_account_info(),
+        ctx.accounts.escrow_vault.to_account_info(),
+        ctx.accounts.mint.to_account_info(),
+        ctx.accounts.mint.decimals,
+        ctx.remaining_accounts,
+    )?;
+    refund_request(
+        &payment,
+        &ctx.accounts.slot,
+        &mut ctx.accounts.escrow,
+        &ctx.accounts.booking_request,
+        &ctx.accounts.requester_token.to_account_info(),
+    )
+}
+
+pub fn refund_booking_request_sol(ctx: Context<RefundBookingRequestSol>) -> Result<()> {
+    let payment = EscrowPayment::sol(
+        &ctx.accounts.slot,
+        &ctx.accounts.escrow,
+        ctx.accounts.system_program.to_account_info(),
+    )?;
+    refund_request(
+        &payment,
+        &ctx.accounts.slot,
+        &mut ctx.accounts.escrow,
+        &ctx.accounts.booking_request,
+        &ctx.accounts.requester.to_account_info(),
+    )
+}
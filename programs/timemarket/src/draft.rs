@@ -0,0 +1,45 @@
+use anchor_lang::prelude::*;
+
+use crate::*;
+use crate::ErrorCode;
+use crate::market::{slot_params, validate_slot_params};
+
+// Edit a staged slot; intermediate edits are only validated when the slot is published
+pub fn update_draft_slot(ctx: Context<UpdateDraftSlot>, params: UpdateDraftParams) -> Result<()> {
+    let slot = &mut ctx.accounts.slot;
+    require!(slot.state == SlotState::Draft, ErrorCode::InvalidState);
+    if let Some(v) = params.start_ts { slot.start_ts = v; }
+    if let Some(v) = params.end_ts { slot.end_ts = v; }
+    if let Some(v) = params.tz_offset_min { slot.tz_offset_min = v; }
+    if let Some(v) = params.subject_hash { slot.subject_hash = v; }
+    if let Some(v) = params.venue_hash { slot.venue_hash = v; }
+    if let Some(v) = params.mode { slot.mode = v; }
+    if let Some(v) = params.capacity { slot.capacity_total = v; }
+    if let Some(v) = params.price { slot.price = v; }
+    if let Some(v) = params.min_increment_bps { slot.min_increment_bps = v; }
+    if let Some(v) = params.buy_now { slot.buy_now = v; }
+    if let Some(v) = params.auction_start_ts { slot.auction_start_ts = v; }
+    if let Some(v) = params.auction_end_ts { slot.auction_end_ts = v; }
+    if let Some(v) = params.anti_sniping_sec { slot.anti_sniping_sec = v; }
+    if let Some(v) = params.cancel_policy { slot.cancel_policy = v; }
+    if let Some(v) = params.gate { slot.gate = v; }
+    if let Some(v) = params.allowlist_root { slot.allowlist_root = v; }
+    emit!(DraftSlotUpdatedEvent { slot: slot.key() });
+    Ok(())
+}
+
+// Run the creation checks on the final terms and open the slot, optionally from `publish_at`
+pub fn publish_slot(ctx: Context<PublishSlot>, publish_at: Option<i64>) -> Result<()> {
+    let slot = &mut ctx.accounts.slot;
+    require!(slot.state == SlotState::Draft, ErrorCode::InvalidState);
+    validate_slot_params(&slot_params(slot))?;
+    let now = Clock::get()?.unix_timestamp;
+    require!(slot.start_ts > now, ErrorCode::TooLate);
+    if let Some(at) = publish_at {
+        require!(at < t0_ts(slot), ErrorCode::InvalidTimes);
+    }
+    slot.state = SlotState::Open;
+    slot.publish_at = publish_at;
+    emit!(SlotPublishedEvent { slot: slot.key(), publish_at });
+    Ok(())
+}
@@ -1,7 +1,11 @@
 use anchor_lang::prelude::*;
 use anchor_lang::system_program;
-use anchor_spl::associated_token::AssociatedToken;
-use anchor_spl::token_interface::{transfer_checked, mint_to, Mint, TokenAccount, TokenInterface, TransferChecked, MintTo};
+use anchor_spl::token_2022::spl_token_2022::{
+    self,
+    extension::{transfer_fee::TransferFeeConfig, BaseStateWithExtensions, StateWithExtensions},
+    onchain::{invoke_transfer_checked, invoke_transfer_checked_with_fee},
+};
+use anchor_spl::token_interface::{TokenAccount, TransferChecked};
 
 use crate::*;
 use crate::ErrorCode; // disambiguate from anchor_lang::error::ErrorCode
@@ -9,93 +13,118 @@ use crate::ErrorCode; // disambiguate from anchor_lang::error::ErrorCode
 pub fn init_escrow(ctx: Context<InitEscrow>) -> Result<()> {
     let escrow = &mut ctx.accounts.escrow;
     escrow.slot = ctx.accounts.slot.key();
-    escrow.token_acc = ctx.accounts.escrow_vault.key();
+    // SPL slots hold funds in the escrow PDA's own ATA; SOL slots keep lamports on the PDA
+    escrow.token_acc = match (ctx.accounts.slot.payment_kind, &ctx.accounts.escrow_vault) {
+        (PaymentKind::Spl, Some(vault)) => vault.key(),
+        (PaymentKind::Sol, None) => Pubkey::default(),
+        _ => return err!(ErrorCode::InvalidEscrowVault),
+    };
     escrow.amount_locked = 0;
     escrow.buyer = None;
+    escrow.payer = None;
+    escrow.requests_locked = 0;
     escrow.bump = ctx.bumps.escrow;
     Ok(())
 }
 
-pub fn stable_reserve(ctx: Context<StableReserve>, amount: u64) -> Result<()> {
-    let slot = &mut ctx.accounts.slot;
-    require!(slot.mode == Mode::Stable, ErrorCode::WrongMode);
-    require!(slot.state == SlotState::Open, ErrorCode::InvalidState);
-    require!(amount == slot.price, ErrorCode::InvalidPrice);
-    // Capacity check: ensure remaining capacity
-    require!(slot.capacity_sold < slot.capacity_total, ErrorCode::CapacityExhausted);
-    // Transfer buyer -> escrow_vault
+pub fn stable_reserve<'info>(ctx: Context<'_, '_, 'info, 'info, StableReserve<'info>>, amount: u64, allowlist: Option<AllowlistProof>) -> Result<()> {
     let decimals = ctx.accounts.mint.decimals;
-    let cpi_accounts = TransferChecked {
-        from: ctx.accounts.buyer_token.to_account_info(),
-        mint: ctx.accounts.mint.to_account_info(),
-        to: ctx.accounts.escrow_vault.to_account_info(),
-        authority: ctx.accounts.buyer.to_account_info(),
-    };
-    transfer_checked(
-        CpiContext::new(ctx.accounts.token_program.to_account_info(), cpi_accounts),
-        amount,
+    let payment = EscrowPayment::spl(
+        &ctx.accounts.slot,
+        &ctx.accounts.escrow,
+        ctx.accounts.token_program.to_account_info(),
+        ctx.accounts.escrow_vault.to_account_info(),
+        ctx.accounts.mint.to_account_info(),
         decimals,
+        ctx.remaining_accounts,
     )?;
-
-    let escrow = &mut ctx.accounts.escrow;
-    escrow.amount_locked = escrow
-        .amount_locked
-        .checked_add(amount)
-        .ok_or(ErrorCode::Overflow)?;
-    escrow.buyer = Some(ctx.accounts.buyer.key());
-    slot.state = SlotState::Reserved;
-    emit!(ReservedEvent { slot: slot.key(), buyer: ctx.accounts.buyer.key(), amount });
-    Ok(())
+    // The gate applies to whoever attends, not to whoever pays
+    let beneficiary = ctx.accounts.beneficiary.as_ref().map_or(ctx.accounts.buyer.key(), |b| b.key());
+    crate::gate::check_gate(
+        &ctx.accounts.slot,
+        beneficiary,
+        ctx.accounts.gate_token.as_ref(),
+        ctx.accounts.gate_metadata.as_deref(),
+    )?;
+    let allowlist_cap = crate::allowlist::check_allowlist(&ctx.accounts.slot, beneficiary, allowlist.as_ref())?;
+    crate::allowlist::consume_allowlist(
+        ctx.accounts.allowlist_claim.as_mut(),
+        ctx.accounts.slot.key(),
+        beneficiary,
+        allowlist_cap,
+        ctx.bumps.allowlist_claim,
+    )?;
+    let price = match ctx.accounts.slot.usd_quote {
+        // Re-quote at the feed's current price; `amount` is the buyer's ceiling
+        Some(quote) => {
+            let feed = ctx.accounts.price_feed.as_deref();
+            let price = crate::oracle::usd_to_mint_units(&quote, quote.price_usd_cents, feed, decimals)?;
+            crate::oracle::check_max_payment(price, Some(amount), true)?;
+            // Cancel and settle split the locked amount by slot.price
+            ctx.accounts.slot.price = price;
+            emit!(UsdPriceQuotedEvent {
+                slot: ctx.accounts.slot.key(),
+                usd_cents: quote.price_usd_cents,
+                amount: price,
+            });
+            price
+        }
+        None => {
+            require!(amount == ctx.accounts.slot.price, ErrorCode::InvalidPrice);
+            amount
+        }
+    };
+    reserve(
+        &payment,
+        &mut ctx.accounts.slot,
+        &mut ctx.accounts.escrow,
+        &ctx.accounts.buyer_token.to_account_info(),
+        &ctx.accounts.buyer.to_account_info(),
+        beneficiary,
+        price,
+    )?;
+    crate::ticket::mint_ticket(
+        &mut ctx.accounts.slot,
+        &ctx.accounts.token_program.to_account_info(),
+        ctx.accounts.nft_mint.as_ref(),
+        ctx.accounts.beneficiary_ticket.as_ref(),
+        ctx.accounts.nft_auth.as_ref().map(|a| a.as_ref()),
+        beneficiary,
+    )
 }
 
-pub fn stable_cancel(ctx: Context<StableCancel>) -> Result<()> {
+pub fn stable_cancel<'info>(ctx: Context<'_, '_, 'info, 'info, StableCancel<'info>>) -> Result<()> {
     let decimals = ctx.accounts.mint.decimals;
-    let slot_key = ctx.accounts.slot.key();
-    let buyer_key = ctx.accounts.buyer.key();
-    let escrow_bump = ctx.accounts.escrow.bump;
-    let token_program = ctx.accounts.token_program.to_account_info();
-    let escrow_vault = ctx.accounts.escrow_vault.to_account_info();
-    let mint = ctx.accounts.mint.to_account_info();
-    let buyer_token = ctx.accounts.buyer_token.to_account_info();
-    let escrow_info = ctx.accounts.escrow.to_account_info();
-    let bump_seed = [escrow_bump];
-    let seeds: &[&[u8]] = &[b"escrow", slot_key.as_ref(), &bump_seed];
-    let signer: &[&[&[u8]]] = &[seeds];
-
-    let slot = &mut ctx.accounts.slot;
-    require!(!slot.frozen, ErrorCode::Frozen);
-    require!(slot.mode == Mode::Stable, ErrorCode::WrongMode);
-    require!(slot.state == SlotState::Reserved, ErrorCode::InvalidState);
-    // Before T0 only
-    let now = Clock::get()?.unix_timestamp;
-    let t0 = t0_ts(slot);
-    require!(now < t0, ErrorCode::TooLate);
-
-    let escrow = &mut ctx.accounts.escrow;
-    let buyer = escrow.buyer.ok_or(ErrorCode::NotReserved)?;
-    require_keys_eq!(buyer, buyer_key, ErrorCode::UnauthorizedBuyer);
-    let amount = escrow.amount_locked;
-    require!(amount > 0, ErrorCode::NothingToRefund);
-    let cpi_accounts = TransferChecked {
-        from: escrow_vault,
-        mint,
-        to: buyer_token,
-        authority: escrow_info,
-    };
-    transfer_checked(
-        CpiContext::new_with_signer(
-            token_program,
-            cpi_accounts,
-            signer,
-        ),
-        amount,
+    let payment = EscrowPayment::spl(
+        &ctx.accounts.slot,
+        &ctx.accounts.escrow,
+        ctx.accounts.token_program.to_account_info(),
+        ctx.accounts.escrow_vault.to_account_info(),
+        ctx.accounts.mint.to_account_info(),
         decimals,
+        ctx.remaining_accounts,
     )?;
-    escrow.amount_locked = 0;
-    escrow.buyer = None;
-    slot.state = SlotState::Open;
-    emit!(RefundedEvent { slot: slot_key, to: buyer_key, amount });
-    Ok(())
+    if ctx.accounts.slot.nft_ticket {
+        // The slot reopens, so the ticket for this booking must not survive it
+        crate::ticket::burn_ticket(
+            &mut ctx.accounts.slot,
+            Some(&ctx.accounts.token_program.to_account_info()),
+            ctx.accounts.nft_mint.as_ref(),
+            ctx.accounts.ticket.as_ref(),
+            &ctx.accounts.buyer.to_account_info(),
+        )?;
+    }
+    let eff_bps = effective_fee_bps(&ctx.accounts.platform, &ctx.accounts.profile);
+    cancel_reservation(
+        &payment,
+        &mut ctx.accounts.slot,
+        &mut ctx.accounts.escrow,
+        eff_bps,
+        ctx.accounts.buyer.key(),
+        &ctx.accounts.buyer_token.to_account_info(),
+        &ctx.accounts.creator_payout_ata.to_account_info(),
+        &ctx.accounts.fee_vault.to_account_info(),
+    )
 }
 
 pub fn stable_checkin(ctx: Context<StableCheckin>) -> Result<()> {
@@ -103,266 +132,617 @@ pub fn stable_checkin(ctx: Context<StableCheckin>) -> Result<()> {
     require!(!slot.frozen, ErrorCode::Frozen);
     require!(slot.mode == Mode::Stable, ErrorCode::WrongMode);
     require!(slot.state == SlotState::Reserved || slot.state == SlotState::Locked, ErrorCode::InvalidState);
-    // Only buyer or creator authority may mark check-in for now (MVP)
-    let buyer = ctx.accounts.escrow.buyer.ok_or(ErrorCode::NotReserved)?;
-    require!(
-        ctx.accounts.signer.key() == buyer || ctx.accounts.signer.key() == slot.creator_authority,
-        ErrorCode::Unauthorized
-    );
+    require!(slot.reschedule.is_none(), ErrorCode::ReschedulePending);
+    if slot.nft_ticket {
+        // Ticket mode: whoever holds the ticket checks in, and the ticket is used up
+        require!(
+            crate::ticket::is_holder(slot, &ctx.accounts.escrow, ctx.accounts.ticket.as_ref(), ctx.accounts.signer.key())?,
+            ErrorCode::InvalidTicket
+        );
+        crate::ticket::burn_ticket(
+            slot,
+            Some(&ctx.accounts.token_program.to_account_info()),
+            Some(&ctx.accounts.nft_mint),
+            ctx.accounts.ticket.as_ref(),
+            &ctx.accounts.signer.to_account_info(),
+        )?;
+    } else {
+        // Only the beneficiary or creator authority may mark check-in for now (MVP)
+        let buyer = ctx.accounts.escrow.buyer.ok_or(ErrorCode::NotReserved)?;
+        require!(
+            ctx.accounts.signer.key() == buyer || ctx.accounts.signer.key() == slot.creator_authority,
+            ErrorCode::Unauthorized
+        );
+    }
     slot.buyer_checked_in = true;
     slot.state = SlotState::Completed; // allow T1 payout
     // Increment capacity sold on successful check-in
     if slot.capacity_sold < slot.capacity_total {
         slot.capacity_sold = slot.capacity_sold.saturating_add(1);
     }
-    // Mint NFT to buyer upon check-in if configured
-    if slot.nft_mint != Pubkey::default() && ctx.accounts.nft_mint.key() == slot.nft_mint {
-        let slot_key = slot.key();
-        let (_pda, bump) = Pubkey::find_program_address(&[b"nft_auth", slot_key.as_ref()], &crate::ID);
-        let seeds: &[&[u8]] = &[b"nft_auth", slot_key.as_ref(), &[bump]];
-        let signer: &[&[&[u8]]] = &[seeds];
-        mint_to(
-            CpiContext::new_with_signer(
-                ctx.accounts.token_program.to_account_info(),
-                MintTo {
-                    mint: ctx.accounts.nft_mint.to_account_info(),
-                    to: ctx.accounts.buyer_nft_ata.to_account_info(),
-                    authority: ctx.accounts.nft_auth.to_account_info(),
-                },
-                signer,
-            ),
-            1,
+    // Mint NFT to the beneficiary upon check-in if configured (ticket mode already minted it)
+    if !slot.nft_ticket && slot.nft_mint != Pubkey::default() && ctx.accounts.nft_mint.key() == slot.nft_mint {
+        crate::nft::mint_slot_nft(
+            slot,
+            &ctx.accounts.token_program.to_account_info(),
+            &ctx.accounts.nft_mint.to_account_info(),
+            &ctx.accounts.buyer_nft_ata.to_account_info(),
+            &ctx.accounts.nft_auth.to_account_info(),
         )?;
     }
+    let attendee = crate::attendance::attendee_of(slot, &ctx.accounts.escrow, ctx.accounts.signer.key())?;
+    require_keys_eq!(ctx.accounts.attendee.key(), attendee, ErrorCode::Unauthorized);
+    crate::attendance::record_attendance(&mut ctx.accounts.attendance, slot, attendee, ctx.bumps.attendance)?;
     emit!(CheckinEvent { slot: slot.key(), by: ctx.accounts.signer.key() });
     Ok(())
 }
 
-pub fn stable_settle(ctx: Context<StableSettle>) -> Result<()> {
-    let decimals = ctx.accounts.mint.decimals;
-    let slot_key = ctx.accounts.slot.key();
-    let escrow_bump = ctx.accounts.escrow.bump;
-    let token_program = ctx.accounts.token_program.to_account_info();
-    let escrow_vault = ctx.accounts.escrow_vault.to_account_info();
-    let mint = ctx.accounts.mint.to_account_info();
-    let creator_payout = ctx.accounts.creator_payout_ata.to_account_info();
-    let fee_vault = ctx.accounts.fee_vault.to_account_info();
-    let dispute_vault = ctx.accounts.dispute_vault.to_account_info();
-    let escrow_info = ctx.accounts.escrow.to_account_info();
-    let bump_seed = [escrow_bump];
-    let seeds: &[&[u8]] = &[b"escrow", slot_key.as_ref(), &bump_seed];
-    let signer: &[&[&[u8]]] = &[seeds];
 
-    let platform = &ctx.accounts.platform;
-    let slot = &mut ctx.accounts.slot;
-    let escrow = &mut ctx.accounts.escrow;
-    require!(slot.mode == Mode::Stable, ErrorCode::WrongMode);
+// Hand a booking to another wallet before check-in. Check-in, dispute and NFT rights follow
+// the holder; refund rights stay with the original payer unless the payer hands them over too.
+pub fn transfer_reservation(
+    ctx: Context<TransferReservation>,
+    new_holder: Pubkey,
+    with_refund_rights: bool,
+    allowlist: Option<AllowlistProof>,
+) -> Result<()> {
+    let slot = &ctx.accounts.slot;
+    require!(!slot.transfers_disabled, ErrorCode::TransfersDisabled);
+    require!(!slot.nft_ticket, ErrorCode::NftTicketMode);
     require!(!slot.frozen, ErrorCode::Frozen);
-    require!(escrow.amount_locked == slot.price, ErrorCode::InvalidEscrowBalance);
+    require!(slot.state == SlotState::Reserved || slot.state == SlotState::Locked, ErrorCode::InvalidState);
+    require!(!slot.buyer_checked_in, ErrorCode::InvalidState);
+    require!(new_holder != Pubkey::default(), ErrorCode::UnauthorizedBuyer);
+    crate::gate::check_gate(
+        slot,
+        new_holder,
+        ctx.accounts.gate_token.as_ref(),
+        ctx.accounts.gate_metadata.as_deref(),
+    )?;
+    // The new holder must be on the list too, and the booking counts against their cap
+    let allowlist_cap = crate::allowlist::check_allowlist(slot, new_holder, allowlist.as_ref())?;
+    crate::allowlist::consume_allowlist(
+        ctx.accounts.allowlist_claim.as_mut(),
+        slot.key(),
+        new_holder,
+        allowlist_cap,
+        ctx.bumps.allowlist_claim,
+    )?;
+
+    let escrow = &mut ctx.accounts.escrow;
+    let holder = escrow.buyer.ok_or(ErrorCode::NotReserved)?;
+    require_keys_eq!(ctx.accounts.holder.key(), holder, ErrorCode::UnauthorizedBuyer);
+    let payer = escrow.refund_to().ok_or(ErrorCode::NotReserved)?;
+    let refund_to = if with_refund_rights {
+        // Only the payer can give away its own refund
+        require_keys_eq!(payer, holder, ErrorCode::UnauthorizedBuyer);
+        new_holder
+    } else {
+        payer
+    };
+    escrow.buyer = Some(new_holder);
+    escrow.payer = Some(refund_to);
+    emit!(ReservationTransferredEvent { slot: slot.key(), from: holder, to: new_holder, refund_to });
+    Ok(())
+}
+
+pub fn stable_settle<'info>(ctx: Context<'_, '_, 'info, 'info, StableSettle<'info>>) -> Result<()> {
+    let decimals = ctx.accounts.mint.decimals;
+    let payment = EscrowPayment::spl(
+        &ctx.accounts.slot,
+        &ctx.accounts.escrow,
+        ctx.accounts.token_program.to_account_info(),
+        ctx.accounts.escrow_vault.to_account_info(),
+        ctx.accounts.mint.to_account_info(),
+        decimals,
+        ctx.remaining_accounts,
+    )?;
+    let platform = &ctx.accounts.platform;
+    let eff_bps = effective_fee_bps(platform, &ctx.accounts.profile);
+    settle(
+        &payment,
+        &mut ctx.accounts.slot,
+        &mut ctx.accounts.escrow,
+        eff_bps,
+        &ctx.accounts.creator_payout_ata.to_account_info(),
+        &ctx.accounts.fee_vault.to_account_info(),
+        &ctx.accounts.dispute_vault.to_account_info(),
+    )
+}
 
+// ===================== SOL Stable flow (MVP) =====================
+
+pub fn stable_reserve_sol(ctx: Context<StableReserveSol>, allowlist: Option<AllowlistProof>) -> Result<()> {
+    let payment = EscrowPayment::sol(
+        &ctx.accounts.slot,
+        &ctx.accounts.escrow,
+        ctx.accounts.system_program.to_account_info(),
+    )?;
+    let buyer = ctx.accounts.buyer.to_account_info();
+    let beneficiary = ctx.accounts.beneficiary.as_ref().map_or(buyer.key(), |b| b.key());
+    crate::gate::check_gate(
+        &ctx.accounts.slot,
+        beneficiary,
+        ctx.accounts.gate_token.as_ref(),
+        ctx.accounts.gate_metadata.as_deref(),
+    )?;
+    let allowlist_cap = crate::allowlist::check_allowlist(&ctx.accounts.slot, beneficiary, allowlist.as_ref())?;
+    crate::allowlist::consume_allowlist(
+        ctx.accounts.allowlist_claim.as_mut(),
+        ctx.accounts.slot.key(),
+        beneficiary,
+        allowlist_cap,
+        ctx.bumps.allowlist_claim,
+    )?;
+    // Price is in lamports for the SOL path
+    let price = ctx.accounts.slot.price;
+    reserve(&payment, &mut ctx.accounts.slot, &mut ctx.accounts.escrow, &buyer, &buyer, beneficiary, price)
+}
+
+pub fn stable_cancel_sol(ctx: Context<StableCancelSol>) -> Result<()> {
+    let payment = EscrowPayment::sol(
+        &ctx.accounts.slot,
+        &ctx.accounts.escrow,
+        ctx.accounts.system_program.to_account_info(),
+    )?;
+    let eff_bps = effective_fee_bps(&ctx.accounts.platform, &ctx.accounts.profile);
+    cancel_reservation(
+        &payment,
+        &mut ctx.accounts.slot,
+        &mut ctx.accounts.escrow,
+        eff_bps,
+        ctx.accounts.buyer.key(),
+        &ctx.accounts.refund_receiver.to_account_info(),
+        &ctx.accounts.creator_payout.to_account_info(),
+        &ctx.accounts.fee_receiver.to_account_info(),
+    )
+}
+
+pub fn stable_settle_sol(ctx: Context<StableSettleSol>) -> Result<()> {
+    let payment = EscrowPayment::sol(
+        &ctx.accounts.slot,
+        &ctx.accounts.escrow,
+        ctx.accounts.system_program.to_account_info(),
+    )?;
+    let platform = &ctx.accounts.platform;
     let eff_bps = effective_fee_bps(platform, &ctx.accounts.profile);
-    let total_fee = mul_bps_u64(slot.price, eff_bps as u64)?;
-    let t0_base = mul_bps_u64(slot.price, STABLE_T0_BPS)?;
-    let t1_base = slot.price.checked_sub(t0_base).ok_or(ErrorCode::Overflow)?;
+    settle(
+        &payment,
+        &mut ctx.accounts.slot,
+        &mut ctx.accounts.escrow,
+        eff_bps,
+        &ctx.accounts.creator_payout.to_account_info(),
+        &ctx.accounts.fee_receiver.to_account_info(),
+        &ctx.accounts.dispute_receiver.to_account_info(),
+    )
+}
 
-    match slot.state {
-        SlotState::Reserved => {
-            // T0 release (50%) if time reached
-            let now = Clock::get()?.unix_timestamp;
-            let t0 = t0_ts(slot);
-            require!(now >= t0, ErrorCode::TooEarly);
-            let t0_fee = mul_bps_u64(total_fee, STABLE_T0_BPS)?;
-            let t0_creator = t0_base.checked_sub(t0_fee).ok_or(ErrorCode::Overflow)?;
+// Lamport payout from the escrow PDA that never dips into its rent-exempt reserve
+pub fn pay_from_escrow_lamports<'info>(
+    escrow: &AccountInfo<'info>,
+    to: &AccountInfo<'info>,
+    amount: u64,
+) -> Result<()> {
+    if amount == 0 {
+        return Ok(());
+    }
+    let min_balance = Rent::get()?.minimum_balance(escrow.data_len());
+    let mut from = escrow.try_borrow_mut_lamports()?;
+    let mut dest = to.try_borrow_mut_lamports()?;
+    let new_from = (*from).checked_sub(amount).ok_or(ErrorCode::Overflow)?;
+    require!(new_from >= min_balance, ErrorCode::EscrowRentViolation);
+    let new_dest = (*dest).checked_add(amount).ok_or(ErrorCode::Overflow)?;
+    **from = new_from;
+    **dest = new_dest;
+    Ok(())
+}
 
-            transfer_checked(
-                CpiContext::new_with_signer(
-                    token_program.clone(),
-                    TransferChecked {
-                        from: escrow_vault.clone(),
-                        mint: mint.clone(),
-                        to: creator_payout.clone(),
-                        authority: escrow_info.clone(),
-                    },
-                    signer,
-                ),
-                t0_creator,
-                decimals,
-            )?;
-            transfer_checked(
-                CpiContext::new_with_signer(
-                    token_program.clone(),
-                    TransferChecked {
-                        from: escrow_vault.clone(),
-                        mint: mint.clone(),
-                        to: fee_vault.clone(),
-                        authority: escrow_info.clone(),
-                    },
-                    signer,
-                ),
-                t0_fee,
+fn t0_ts(slot: &TimeSlot) -> i64 {
+    match slot.mode {
+        Mode::Stable => slot.start_ts,
+        Mode::EnglishAuction | Mode::SealedBid => slot.auction_end_ts.unwrap_or(slot.start_ts),
+    }
+}
+
+// ===================== Close/Cancel slot by creator/admin (P2) =====================
+// SPL path
+pub fn close_slot<'info>(ctx: Context<'_, '_, 'info, 'info, CloseSlot<'info>>) -> Result<()> {
+    let decimals = ctx.accounts.mint.decimals;
+    let payment = EscrowPayment::spl(
+        &ctx.accounts.slot,
+        &ctx.accounts.escrow,
+        ctx.accounts.token_program.to_account_info(),
+        ctx.accounts.escrow_vault.to_account_info(),
+        ctx.accounts.mint.to_account_info(),
+        decimals,
+        ctx.remaining_accounts,
+    )?;
+    if let Some(buyer_key) = ctx.accounts.escrow.refund_to() {
+        require!(ctx.accounts.buyer_token.owner == buyer_key, ErrorCode::UnauthorizedBuyer);
+    }
+    let is_admin = ctx.accounts.platform.admin == ctx.accounts.authority.key();
+    close_or_refund(
+        &payment,
+        &mut ctx.accounts.slot,
+        &mut ctx.accounts.escrow,
+        ctx.accounts.authority.key(),
+        is_admin,
+        &ctx.accounts.buyer_token.to_account_info(),
+    )
+}
+
+// SOL path
+pub fn close_slot_sol(ctx: Context<CloseSlotSol>) -> Result<()> {
+    let payment = EscrowPayment::sol(
+        &ctx.accounts.slot,
+        &ctx.accounts.escrow,
+        ctx.accounts.system_program.to_account_info(),
+    )?;
+    if let Some(buyer_key) = ctx.accounts.escrow.refund_to() {
+        require_keys_eq!(ctx.accounts.buyer.key(), buyer_key, ErrorCode::UnauthorizedBuyer);
+    }
+    let is_admin = ctx.accounts.platform.admin == ctx.accounts.authority.key();
+    close_or_refund(
+        &payment,
+        &mut ctx.accounts.slot,
+        &mut ctx.accounts.escrow,
+        ctx.accounts.authority.key(),
+        is_admin,
+        &ctx.accounts.buyer.to_account_info(),
+    )
+}
+
+// ===================== Token-2022 transfer fees =====================
+
+// Fee a Token-2022 TransferFee mint charges in the current epoch. `inverse` computes the fee
+// that leaves `amount` after deduction instead of the fee taken out of `amount`. Plain SPL
+// mints and Token-2022 mints without the extension charge nothing.
+fn transfer_fee(mint: &AccountInfo, amount: u64, inverse: bool) -> Result<u64> {
+    if *mint.owner != spl_token_2022::ID {
+        return Ok(0);
+    }
+    let data = mint.try_borrow_data()?;
+    let state = StateWithExtensions::<spl_token_2022::state::Mint>::unpack(&data)?;
+    let Ok(config) = state.get_extension::<TransferFeeConfig>() else {
+        return Ok(0);
+    };
+    let epoch = Clock::get()?.epoch;
+    let fee = if inverse {
+        config.calculate_inverse_epoch_fee(epoch, amount)
+    } else {
+        config.calculate_epoch_fee(epoch, amount)
+    };
+    Ok(fee.ok_or(ErrorCode::Overflow)?)
+}
+
+fn token_amount(account: &AccountInfo) -> Result<u64> {
+    let data = account.try_borrow_data()?;
+    Ok(TokenAccount::try_deserialize(&mut &data[..])?.amount)
+}
+
+// Drop-in for token_interface::transfer_checked that also works for Token-2022 mints with a
+// TransferHook: the hook program's extra accounts are resolved from the context's remaining
+// accounts through the mint's ExtraAccountMetaList. A non-zero transfer fee is asserted with
+// TransferCheckedWithFee so the transfer fails if the mint charges something else.
+pub fn hooked_transfer_checked<'info>(
+    ctx: CpiContext<'_, '_, '_, 'info, TransferChecked<'info>>,
+    amount: u64,
+    decimals: u8,
+) -> Result<()> {
+    let TransferChecked { from, mint, to, authority } = ctx.accounts;
+    let fee = transfer_fee(&mint, amount, false)?;
+    if fee == 0 {
+        invoke_transfer_checked(
+            ctx.program.key,
+            from,
+            mint,
+            to,
+            authority,
+            &ctx.remaining_accounts,
+            amount,
+            decimals,
+            ctx.signer_seeds,
+        )?;
+    } else {
+        invoke_transfer_checked_with_fee(
+            ctx.program.key,
+            from,
+            mint,
+            to,
+            authority,
+            &ctx.remaining_accounts,
+            amount,
+            decimals,
+            fee,
+            ctx.signer_seeds,
+        )?;
+    }
+    Ok(())
+}
+
+// Move tokens into an escrow vault so that at least `amount` lands there. The payer is charged
+// the gross amount including any inbound transfer fee, and the vault balance is measured around
+// the CPI so escrow never credits more than it actually holds.
+#[allow(clippy::too_many_arguments)]
+pub fn collect_spl<'info>(
+    token_program: &AccountInfo<'info>,
+    from: &AccountInfo<'info>,
+    mint: &AccountInfo<'info>,
+    vault: &AccountInfo<'info>,
+    authority: &AccountInfo<'info>,
+    hook_accounts: &[AccountInfo<'info>],
+    amount: u64,
+    decimals: u8,
+) -> Result<()> {
+    let gross = amount
+        .checked_add(transfer_fee(mint, amount, true)?)
+        .ok_or(ErrorCode::Overflow)?;
+    let before = token_amount(vault)?;
+    hooked_transfer_checked(
+        CpiContext::new(
+            token_program.clone(),
+            TransferChecked {
+                from: from.clone(),
+                mint: mint.clone(),
+                to: vault.clone(),
+                authority: authority.clone(),
+            },
+        )
+        .with_remaining_accounts(hook_accounts.to_vec()),
+        gross,
+        decimals,
+    )?;
+    let received = token_amount(vault)?.checked_sub(before).ok_or(ErrorCode::Overflow)?;
+    require!(received >= amount, ErrorCode::InvalidEscrowBalance);
+    Ok(())
+}
+
+// Release `amount` from an escrow vault, signed by the escrow PDA. The recipient bears the
+// mint's transfer fee (calculate_epoch_fee), which hooked_transfer_checked budgets for.
+#[allow(clippy::too_many_arguments)]
+pub fn pay_spl<'info>(
+    token_program: &AccountInfo<'info>,
+    vault: &AccountInfo<'info>,
+    mint: &AccountInfo<'info>,
+    to: &AccountInfo<'info>,
+    escrow: &AccountInfo<'info>,
+    hook_accounts: &[AccountInfo<'info>],
+    amount: u64,
+    decimals: u8,
+    signer: &[&[&[u8]]],
+) -> Result<()> {
+    hooked_transfer_checked(
+        CpiContext::new_with_signer(
+            token_program.clone(),
+            TransferChecked {
+                from: vault.clone(),
+                mint: mint.clone(),
+                to: to.clone(),
+                authority: escrow.clone(),
+            },
+            signer,
+        )
+        .with_remaining_accounts(hook_accounts.to_vec()),
+        amount,
+        decimals,
+    )
+}
+
+// ===================== Escrow payment abstraction =====================
+// A slot's escrowed funds live either in the SPL vault (escrow PDA as token authority) or as
+// lamports on the escrow PDA itself. Handlers build one from the slot's payment kind, which
+// rejects the wrong instruction family, and the flow bodies below never branch on currency.
+// Every move into or out of escrow goes through it. Resale and tips pay wallet to wallet and
+// never touch escrow, so they call hooked_transfer_checked directly.
+
+enum PaymentRail<'info> {
+    Spl {
+        token_program: AccountInfo<'info>,
+        vault: AccountInfo<'info>,
+        mint: AccountInfo<'info>,
+        decimals: u8,
+        hook_accounts: Vec<AccountInfo<'info>>,
+    },
+    Sol {
+        system_program: AccountInfo<'info>,
+    },
+}
+
+pub struct EscrowPayment<'info> {
+    escrow: AccountInfo<'info>,
+    slot: Pubkey,
+    bump: u8,
+    rail: PaymentRail<'info>,
+}
+
+// One outgoing transfer from escrow
+pub struct Payout<'a, 'info> {
+    pub to: &'a AccountInfo<'info>,
+    pub amount: u64,
+}
+
+impl<'info> EscrowPayment<'info> {
+    pub fn spl(
+        slot: &Account<'info, TimeSlot>,
+        escrow: &Account<'info, Escrow>,
+        token_program: AccountInfo<'info>,
+        vault: AccountInfo<'info>,
+        mint: AccountInfo<'info>,
+        decimals: u8,
+        hook_accounts: &[AccountInfo<'info>],
+    ) -> Result<Self> {
+        require!(slot.payment_kind == PaymentKind::Spl, ErrorCode::WrongPaymentKind);
+        Ok(Self {
+            escrow: escrow.to_account_info(),
+            slot: slot.key(),
+            bump: escrow.bump,
+            rail: PaymentRail::Spl {
+                token_program,
+                vault,
+                mint,
                 decimals,
-            )?;
+                hook_accounts: hook_accounts.to_vec(),
+            },
+        })
+    }
 
-            // Update remaining escrow (T1 base still in escrow)
-            escrow.amount_locked = escrow
-                .amount_locked
-                .checked_sub(t0_creator + t0_fee)
-                .ok_or(ErrorCode::Overflow)?;
-            slot.state = SlotState::Locked;
-            emit!(SettledT0Event { slot: slot_key, to: ctx.accounts.creator_payout_ata.key(), amount: t0_creator, fee: t0_fee });
-            Ok(())
-        }
-        SlotState::Completed => {
-            // T1 release (98% of remainder)
-            let t1_release = mul_bps_u64(t1_base, FINAL_RELEASE_BPS)?; // amount destined for creator before platform fee
-            let t1_withhold = t1_base.checked_sub(t1_release).ok_or(ErrorCode::Overflow)?; // retained per policy
-            let eff_bps = effective_fee_bps(platform, &ctx.accounts.profile);
-            let t1_fee = mul_bps_u64(t1_base, eff_bps as u64)?;
-            let t1_creator = t1_release.checked_sub(t1_fee).ok_or(ErrorCode::Overflow)?;
+    pub fn sol(
+        slot: &Account<'info, TimeSlot>,
+        escrow: &Account<'info, Escrow>,
+        system_program: AccountInfo<'info>,
+    ) -> Result<Self> {
+        require!(slot.payment_kind == PaymentKind::Sol, ErrorCode::WrongPaymentKind);
+        Ok(Self {
+            escrow: escrow.to_account_info(),
+            slot: slot.key(),
+            bump: escrow.bump,
+            rail: PaymentRail::Sol { system_program },
+        })
+    }
 
-            // payouts
-            transfer_checked(
-                CpiContext::new_with_signer(
-                    token_program.clone(),
-                    TransferChecked {
-                        from: escrow_vault.clone(),
-                        mint: mint.clone(),
-                        to: creator_payout.clone(),
-                        authority: escrow_info.clone(),
-                    },
-                    signer,
+    // Pull `amount` into escrow. SPL: `from` is the payer's token account and `authority` its
+    // owner. SOL: lamports come straight from `authority`.
+    pub fn collect(&self, from: &AccountInfo<'info>, authority: &AccountInfo<'info>, amount: u64) -> Result<()> {
+        match &self.rail {
+            PaymentRail::Spl { token_program, vault, mint, decimals, hook_accounts } => {
+                collect_spl(token_program, from, mint, vault, authority, hook_accounts, amount, *decimals)
+            }
+            PaymentRail::Sol { system_program } => system_program::transfer(
+                CpiContext::new(
+                    system_program.clone(),
+                    system_program::Transfer { from: authority.clone(), to: self.escrow.clone() },
                 ),
-                t1_creator,
-                decimals,
-            )?;
-            transfer_checked(
-                CpiContext::new_with_signer(
-                    token_program.clone(),
-                    TransferChecked {
-                        from: escrow_vault.clone(),
-                        mint: mint.clone(),
-                        to: fee_vault.clone(),
-                        authority: escrow_info.clone(),
-                    },
+                amount,
+            ),
+        }
+    }
+
+    // Release funds held in escrow; zero amounts are skipped
+    pub fn pay(&self, payout: Payout<'_, 'info>) -> Result<()> {
+        if payout.amount == 0 {
+            return Ok(());
+        }
+        match &self.rail {
+            PaymentRail::Spl { token_program, vault, mint, decimals, hook_accounts } => {
+                let bump_seed = [self.bump];
+                let seeds: &[&[u8]] = &[b"escrow", self.slot.as_ref(), &bump_seed];
+                let signer: &[&[&[u8]]] = &[seeds];
+                pay_spl(
+                    token_program,
+                    vault,
+                    mint,
+                    payout.to,
+                    &self.escrow,
+                    hook_accounts,
+                    payout.amount,
+                    *decimals,
                     signer,
-                ),
-                t1_fee,
-                decimals,
-            )?;
-            if t1_withhold > 0 {
-                transfer_checked(
-                    CpiContext::new_with_signer(
-                        token_program.clone(),
-                        TransferChecked {
-                            from: escrow_vault.clone(),
-                            mint: mint.clone(),
-                            to: dispute_vault.clone(),
-                            authority: escrow_info.clone(),
-                        },
-                        signer,
-                    ),
-                    t1_withhold,
-                    decimals,
-                )?;
+                )
             }
-
-            // Update escrow and state
-            let total_out = t1_creator
-                .checked_add(t1_fee).ok_or(ErrorCode::Overflow)?
-                .checked_add(t1_withhold).ok_or(ErrorCode::Overflow)?;
-            require!(escrow.amount_locked >= total_out, ErrorCode::InvalidEscrowBalance);
-            escrow.amount_locked = escrow.amount_locked.checked_sub(total_out).ok_or(ErrorCode::Overflow)?;
-            slot.state = SlotState::Settled;
-            emit!(SettledT1Event { slot: slot_key, to: ctx.accounts.creator_payout_ata.key(), amount: t1_creator, fee: t1_fee, retained: t1_withhold });
-            Ok(())
+            PaymentRail::Sol { .. } => pay_from_escrow_lamports(&self.escrow, payout.to, payout.amount),
         }
-        _ => err!(ErrorCode::InvalidState),
     }
 }
 
-// ===================== SOL Stable flow (MVP) =====================
-
-pub fn stable_reserve_sol(ctx: Context<StableReserveSol>) -> Result<()> {
-    let slot = &mut ctx.accounts.slot;
+// ===================== Shared SPL/SOL flow bodies =====================
+
+fn reserve<'info>(
+    payment: &EscrowPayment<'info>,
+    slot: &mut Account<'info, TimeSlot>,
+    escrow: &mut Account<'info, Escrow>,
+    from: &AccountInfo<'info>,
+    buyer: &AccountInfo<'info>,
+    beneficiary: Pubkey,
+    amount: u64,
+) -> Result<()> {
     require!(slot.mode == Mode::Stable, ErrorCode::WrongMode);
+    require!(slot.approval_window_sec.is_none(), ErrorCode::ApprovalRequired);
     require!(slot.state == SlotState::Open, ErrorCode::InvalidState);
-    // Capacity check
+    check_on_sale(slot)?;
+    if let Some(hold) = slot.hold {
+        let now = Clock::get()?.unix_timestamp;
+        // The hold is for the attendee, whoever ends up paying for the booking
+        require!(now >= hold.expires_at || hold.holder == beneficiary, ErrorCode::SlotHeld);
+    }
+    // Capacity check: ensure remaining capacity
     require!(slot.capacity_sold < slot.capacity_total, ErrorCode::CapacityExhausted);
+    payment.collect(from, buyer, amount)?;
 
-    // Transfer lamports from buyer to escrow PDA data account
-    let price = slot.price; // lamports for SOL path
-    let ix = system_program::Transfer {
-        from: ctx.accounts.buyer.to_account_info(),
-        to: ctx.accounts.escrow.to_account_info(),
-    };
-    system_program::transfer(
-        CpiContext::new(ctx.accounts.system_program.to_account_info(), ix),
-        price,
-    )?;
-
-    let escrow = &mut ctx.accounts.escrow;
     escrow.amount_locked = escrow
         .amount_locked
-        .checked_add(price)
+        .checked_add(amount)
         .ok_or(ErrorCode::Overflow)?;
-    escrow.buyer = Some(ctx.accounts.buyer.key());
+    // The signer pays and is refunded; the beneficiary attends
+    escrow.buyer = Some(beneficiary);
+    escrow.payer = Some(buyer.key());
     slot.state = SlotState::Reserved;
-    emit!(ReservedEvent { slot: slot.key(), buyer: ctx.accounts.buyer.key(), amount: price });
+    emit!(ReservedEvent { slot: slot.key(), buyer: beneficiary, payer: buyer.key(), amount });
     Ok(())
 }
 
-pub fn stable_cancel_sol(ctx: Context<StableCancelSol>) -> Result<()> {
-    let slot = &mut ctx.accounts.slot;
+#[allow(clippy::too_many_arguments)]
+fn cancel_reservation<'info>(
+    payment: &EscrowPayment<'info>,
+    slot: &mut Account<'info, TimeSlot>,
+    escrow: &mut Account<'info, Escrow>,
+    eff_bps: u16,
+    signer: Pubkey,
+    buyer_dest: &AccountInfo<'info>,
+    creator_payout: &AccountInfo<'info>,
+    fee_vault: &AccountInfo<'info>,
+) -> Result<()> {
+    require!(!slot.frozen, ErrorCode::Frozen);
     require!(slot.mode == Mode::Stable, ErrorCode::WrongMode);
     require!(slot.state == SlotState::Reserved, ErrorCode::InvalidState);
-
+    require!(slot.reschedule.is_none(), ErrorCode::ReschedulePending);
     // Before T0 only
     let now = Clock::get()?.unix_timestamp;
     let t0 = t0_ts(slot);
     require!(now < t0, ErrorCode::TooLate);
 
-    // Get buyer before mutably borrowing escrow
-    let buyer = ctx.accounts.escrow.buyer.ok_or(ErrorCode::NotReserved)?;
-    require_keys_eq!(buyer, ctx.accounts.buyer.key(), ErrorCode::UnauthorizedBuyer);
-    let amount = ctx.accounts.escrow.amount_locked;
+    // Either the beneficiary or the payer may cancel; the refund always goes to the payer
+    let buyer = escrow.buyer.ok_or(ErrorCode::NotReserved)?;
+    let buyer_key = escrow.refund_to().ok_or(ErrorCode::NotReserved)?;
+    require!(signer == buyer || signer == buyer_key, ErrorCode::UnauthorizedBuyer);
+    let amount = escrow.amount_locked;
     require!(amount > 0, ErrorCode::NothingToRefund);
 
-    // Move lamports back from escrow to buyer
-    {
-        let escrow_info = ctx.accounts.escrow.to_account_info();
-        let buyer_info = ctx.accounts.buyer.to_account_info();
-        let mut escrow_lamports = escrow_info.try_borrow_mut_lamports()?;
-        let mut buyer_lamports = buyer_info.try_borrow_mut_lamports()?;
-        // Explicit intermediate arithmetic to prevent underflow/overflow and avoid borrow mixups
-        let new_escrow = (*escrow_lamports)
-            .checked_sub(amount)
-            .ok_or(ErrorCode::Overflow)?;
-        let new_buyer = (*buyer_lamports)
-            .checked_add(amount)
-            .ok_or(ErrorCode::Overflow)?;
-    **escrow_lamports = new_escrow;
-    **buyer_lamports = new_buyer;
-    }
+    // Split per the tier in effect: refund to buyer, retained share to creator less platform fee
+    let refund_bps = cancel_refund_bps(slot, now);
+    let refund = mul_bps_u64(amount, refund_bps)?;
+    let retained = amount.checked_sub(refund).ok_or(ErrorCode::Overflow)?;
+    let fee = mul_bps_u64(retained, eff_bps as u64)?;
+    let creator_amount = retained.checked_sub(fee).ok_or(ErrorCode::Overflow)?;
+
+    payment.pay(Payout { to: buyer_dest, amount: refund })?;
+    payment.pay(Payout { to: creator_payout, amount: creator_amount })?;
+    payment.pay(Payout { to: fee_vault, amount: fee })?;
 
-    let escrow = &mut ctx.accounts.escrow;
     escrow.amount_locked = 0;
     escrow.buyer = None;
+    escrow.payer = None;
     slot.state = SlotState::Open;
-    emit!(RefundedEvent { slot: slot.key(), to: ctx.accounts.buyer.key(), amount });
+    let slot_key = slot.key();
+    emit!(RefundedEvent { slot: slot_key, to: buyer_key, amount: refund });
+    emit!(CancelledEvent { slot: slot_key, buyer, refund_bps: refund_bps as u16, refunded: refund, creator_amount, fee });
     Ok(())
 }
 
-pub fn stable_settle_sol(ctx: Context<StableSettleSol>) -> Result<()> {
-    let slot_key = ctx.accounts.slot.key();
-    let platform = &ctx.accounts.platform;
-    let slot = &mut ctx.accounts.slot;
-    // Work with values first to avoid conflicting borrows
-    let escrow_locked = ctx.accounts.escrow.amount_locked;
+fn settle<'info>(
+    payment: &EscrowPayment<'info>,
+    slot: &mut Account<'info, TimeSlot>,
+    escrow: &mut Account<'info, Escrow>,
+    eff_bps: u16,
+    creator_payout: &AccountInfo<'info>,
+    fee_vault: &AccountInfo<'info>,
+    dispute_vault: &AccountInfo<'info>,
+) -> Result<()> {
+    let slot_key = slot.key();
     require!(slot.mode == Mode::Stable, ErrorCode::WrongMode);
     require!(!slot.frozen, ErrorCode::Frozen);
-    require!(escrow_locked == slot.price, ErrorCode::InvalidEscrowBalance);
+    require!(slot.reschedule.is_none(), ErrorCode::ReschedulePending);
+    require!(escrow.amount_locked == slot.price, ErrorCode::InvalidEscrowBalance);
 
-    let eff_bps = effective_fee_bps(platform, &ctx.accounts.profile);
     let total_fee = mul_bps_u64(slot.price, eff_bps as u64)?;
     let t0_base = mul_bps_u64(slot.price, STABLE_T0_BPS)?;
     let t1_base = slot.price.checked_sub(t0_base).ok_or(ErrorCode::Overflow)?;
@@ -376,36 +756,16 @@ pub fn stable_settle_sol(ctx: Context<StableSettleSol>) -> Result<()> {
             let t0_fee = mul_bps_u64(total_fee, STABLE_T0_BPS)?;
             let t0_creator = t0_base.checked_sub(t0_fee).ok_or(ErrorCode::Overflow)?;
 
-            // Move lamports: escrow -> creator and fee
-            {
-                let escrow_info = ctx.accounts.escrow.to_account_info();
-                let creator_info = ctx.accounts.creator_payout.to_account_info();
-                let fee_info = ctx.accounts.fee_receiver.to_account_info();
-                let mut from = escrow_info.try_borrow_mut_lamports()?;
-                let mut to_creator = creator_info.try_borrow_mut_lamports()?;
-                let mut to_fee = fee_info.try_borrow_mut_lamports()?;
-                let new_from = (*from)
-                    .checked_sub(t0_creator + t0_fee)
-                    .ok_or(ErrorCode::Overflow)?;
-                let new_creator = (*to_creator)
-                    .checked_add(t0_creator)
-                    .ok_or(ErrorCode::Overflow)?;
-                let new_fee = (*to_fee)
-                    .checked_add(t0_fee)
-                    .ok_or(ErrorCode::Overflow)?;
-                **from = new_from;
-                **to_creator = new_creator;
-                **to_fee = new_fee;
-            }
+            payment.pay(Payout { to: creator_payout, amount: t0_creator })?;
+            payment.pay(Payout { to: fee_vault, amount: t0_fee })?;
 
             // Update remaining escrow (T1 base still in escrow)
-            let new_locked = escrow_locked
+            escrow.amount_locked = escrow
+                .amount_locked
                 .checked_sub(t0_creator + t0_fee)
                 .ok_or(ErrorCode::Overflow)?;
-            let escrow = &mut ctx.accounts.escrow;
-            escrow.amount_locked = new_locked;
             slot.state = SlotState::Locked;
-            emit!(SettledT0Event { slot: slot_key, to: ctx.accounts.creator_payout.key(), amount: t0_creator, fee: t0_fee });
+            emit!(SettledT0Event { slot: slot_key, to: creator_payout.key(), amount: t0_creator, fee: t0_fee });
             Ok(())
         }
         SlotState::Completed => {
@@ -414,102 +774,45 @@ pub fn stable_settle_sol(ctx: Context<StableSettleSol>) -> Result<()> {
             let t1_withhold = t1_base.checked_sub(t1_release).ok_or(ErrorCode::Overflow)?; // retained per policy
             let t1_fee = mul_bps_u64(t1_base, eff_bps as u64)?;
             let t1_creator = t1_release.checked_sub(t1_fee).ok_or(ErrorCode::Overflow)?;
-
-            // payouts from escrow lamports
-            {
-                let escrow_info = ctx.accounts.escrow.to_account_info();
-                let creator_info = ctx.accounts.creator_payout.to_account_info();
-                let fee_info = ctx.accounts.fee_receiver.to_account_info();
-                let dispute_info = ctx.accounts.dispute_receiver.to_account_info();
-                let mut from = escrow_info.try_borrow_mut_lamports()?;
-                let mut to_creator = creator_info.try_borrow_mut_lamports()?;
-                let mut to_fee = fee_info.try_borrow_mut_lamports()?;
-                let mut to_dispute = dispute_info.try_borrow_mut_lamports()?;
-                let new_from = (*from)
-                    .checked_sub(t1_creator + t1_fee + t1_withhold)
-                    .ok_or(ErrorCode::Overflow)?;
-                let new_creator = (*to_creator).checked_add(t1_creator).ok_or(ErrorCode::Overflow)?;
-                let new_fee = (*to_fee).checked_add(t1_fee).ok_or(ErrorCode::Overflow)?;
-                let new_dispute = if t1_withhold > 0 {
-                    (*to_dispute).checked_add(t1_withhold).ok_or(ErrorCode::Overflow)?
-                } else {
-                    **to_dispute
-                };
-                **from = new_from;
-                **to_creator = new_creator;
-                **to_fee = new_fee;
-                **to_dispute = new_dispute;
-            }
-
-            // Update escrow and state
             let total_out = t1_creator
                 .checked_add(t1_fee).ok_or(ErrorCode::Overflow)?
                 .checked_add(t1_withhold).ok_or(ErrorCode::Overflow)?;
-            require!(escrow_locked >= total_out, ErrorCode::InvalidEscrowBalance);
-            let new_locked = escrow_locked.checked_sub(total_out).ok_or(ErrorCode::Overflow)?;
-            let escrow = &mut ctx.accounts.escrow;
-            escrow.amount_locked = new_locked;
+            require!(escrow.amount_locked >= total_out, ErrorCode::InvalidEscrowBalance);
+
+            // payouts
+            payment.pay(Payout { to: creator_payout, amount: t1_creator })?;
+            payment.pay(Payout { to: fee_vault, amount: t1_fee })?;
+            payment.pay(Payout { to: dispute_vault, amount: t1_withhold })?;
+
+            escrow.amount_locked = escrow.amount_locked.checked_sub(total_out).ok_or(ErrorCode::Overflow)?;
             slot.state = SlotState::Settled;
-            emit!(SettledT1Event { slot: slot_key, to: ctx.accounts.creator_payout.key(), amount: t1_creator, fee: t1_fee, retained: t1_withhold });
+            emit!(SettledT1Event { slot: slot_key, to: creator_payout.key(), amount: t1_creator, fee: t1_fee, retained: t1_withhold });
             Ok(())
         }
         _ => err!(ErrorCode::InvalidState),
     }
 }
 
-fn t0_ts(slot: &TimeSlot) -> i64 {
-    match slot.mode {
-        Mode::Stable => slot.start_ts,
-        Mode::EnglishAuction | Mode::SealedBid => slot.auction_end_ts.unwrap_or(slot.start_ts),
-    }
-}
-
-// ===================== Close/Cancel slot by creator/admin (P2) =====================
-// SPL path
-pub fn close_slot(ctx: Context<CloseSlot>) -> Result<()> {
-    let slot = &mut ctx.accounts.slot;
+fn close_or_refund<'info>(
+    payment: &EscrowPayment<'info>,
+    slot: &mut Account<'info, TimeSlot>,
+    escrow: &mut Account<'info, Escrow>,
+    authority: Pubkey,
+    is_admin: bool,
+    buyer_dest: &AccountInfo<'info>,
+) -> Result<()> {
     // Permission: platform admin or creator authority
-    let is_admin = ctx.accounts.platform.admin == ctx.accounts.authority.key();
-    let is_creator = slot.creator_authority == ctx.accounts.authority.key();
+    let is_creator = slot.creator_authority == authority;
     require!(is_admin || is_creator, ErrorCode::Unauthorized);
-    // Allowed states: Open (no buyer), Reserved/Locked (refund buyer), Completed/Settled should be handled by normal settle; forbid when frozen
+    // Completed/Settled should be handled by normal settle; forbid when frozen
     require!(!slot.frozen, ErrorCode::Frozen);
 
-    // If reserved/locked, refund buyer full remaining escrow and mark Refunded; otherwise just Close
-    let escrow = &mut ctx.accounts.escrow;
-    let has_buyer = escrow.buyer.is_some();
-    if has_buyer && escrow.amount_locked > 0 {
-        let buyer_key = escrow.buyer.unwrap();
-        require!(ctx.accounts.buyer_token.owner == buyer_key, ErrorCode::UnauthorizedBuyer);
-        // Refund entire remaining amount to provided buyer token
-        let decimals = ctx.accounts.mint.decimals;
-        let slot_key = slot.key();
-        let escrow_bump = escrow.bump;
-        let token_program = ctx.accounts.token_program.to_account_info();
-        let escrow_vault = ctx.accounts.escrow_vault.to_account_info();
-        // Optimistically accept buyer_token provided by caller; in production, constrain owner/mint via accounts for safety
-        let buyer_token = ctx.accounts.buyer_token.to_account_info();
-        let escrow_info = ctx.accounts.escrow.to_account_info();
-        let bump_seed = [escrow_bump];
-        let seeds: &[&[u8]] = &[b"escrow", slot_key.as_ref(), &bump_seed];
-        let signer: &[&[&[u8]]] = &[seeds];
-        let amt = escrow.amount_locked;
-        transfer_checked(
-            CpiContext::new_with_signer(
-                token_program,
-                TransferChecked {
-                    from: escrow_vault,
-                    mint: ctx.accounts.mint.to_account_info(),
-                    to: buyer_token,
-                    authority: escrow_info,
-                },
-                signer,
-            ),
-            amt,
-            decimals,
-        )?;
+    // If reserved/locked, refund the payer the full remaining escrow and mark Refunded; otherwise just Close
+    if escrow.buyer.is_some() && escrow.amount_locked > 0 {
+        payment.pay(Payout { to: buyer_dest, amount: escrow.amount_locked })?;
         escrow.amount_locked = 0;
         escrow.buyer = None;
+        escrow.payer = None;
         slot.state = SlotState::Refunded;
     } else {
         // No funds/buyer — close directly
@@ -518,32 +821,48 @@ pub fn close_slot(ctx: Context<CloseSlot>) -> Result<()> {
     Ok(())
 }
 
-// SOL path
-pub fn close_slot_sol(ctx: Context<CloseSlotSol>) -> Result<()> {
-    let slot = &mut ctx.accounts.slot;
-    let is_admin = ctx.accounts.platform.admin == ctx.accounts.authority.key();
-    let is_creator = slot.creator_authority == ctx.accounts.authority.key();
-    require!(is_admin || is_creator, ErrorCode::Unauthorized);
-    require!(!slot.frozen, ErrorCode::Frozen);
-
-    let escrow_locked = ctx.accounts.escrow.amount_locked;
-    if ctx.accounts.escrow.buyer.is_some() && escrow_locked > 0 {
-        // Return remaining lamports to buyer system account
-        let escrow_info = ctx.accounts.escrow.to_account_info();
-        let buyer_info = ctx.accounts.buyer.to_account_info();
-        let mut from = escrow_info.try_borrow_mut_lamports()?;
-        let mut to = buyer_info.try_borrow_mut_lamports()?;
-        let new_from = (*from).checked_sub(escrow_locked).ok_or(ErrorCode::Overflow)?;
-        let new_to = (*to).checked_add(escrow_locked).ok_or(ErrorCode::Overflow)?;
-        **from = new_from;
-        **to = new_to;
-        let escrow = &mut ctx.accounts.escrow;
-        escrow.amount_locked = 0;
-        escrow.buyer = None;
-        slot.state = SlotState::Refunded;
-    } else {
-        slot.state = SlotState::Closed;
-    }
+// T0 release for auctions (40% base, fee pro-rata), shared by English end, buy-now and sealed end
+pub fn pay_auction_t0<'info>(
+    payment: &EscrowPayment<'info>,
+    escrow: &mut Account<'info, Escrow>,
+    creator_payout: &AccountInfo<'info>,
+    fee_dest: &AccountInfo<'info>,
+    winning_bid: u64,
+    eff_bps: u16,
+) -> Result<()> {
+    let total_fee = mul_bps_u64(winning_bid, eff_bps as u64)?;
+    let t0_base = mul_bps_u64(winning_bid, AUCTION_T0_BPS)?;
+    let t0_fee = mul_bps_u64(total_fee, AUCTION_T0_BPS)?;
+    let t0_creator = t0_base.checked_sub(t0_fee).ok_or(ErrorCode::Overflow)?;
+    payment.pay(Payout { to: creator_payout, amount: t0_creator })?;
+    payment.pay(Payout { to: fee_dest, amount: t0_fee })?;
+    escrow.amount_locked = escrow.amount_locked.checked_sub(t0_creator + t0_fee).ok_or(ErrorCode::Overflow)?;
     Ok(())
 }
 
+// T1 release of `t1_base` for auctions (98% to creator minus fee, remainder retained for disputes);
+// returns (creator, fee, retained)
+pub fn pay_auction_t1<'info>(
+    payment: &EscrowPayment<'info>,
+    escrow: &mut Account<'info, Escrow>,
+    creator_payout: &AccountInfo<'info>,
+    fee_dest: &AccountInfo<'info>,
+    dispute_dest: &AccountInfo<'info>,
+    t1_base: u64,
+    fee_bps: u16,
+) -> Result<(u64, u64, u64)> {
+    let t1_release = mul_bps_u64(t1_base, FINAL_RELEASE_BPS)?;
+    let t1_withhold = t1_base.checked_sub(t1_release).ok_or(ErrorCode::Overflow)?;
+    let t1_fee = mul_bps_u64(t1_base, fee_bps as u64)?;
+    let t1_creator = t1_release.checked_sub(t1_fee).ok_or(ErrorCode::Overflow)?;
+    let total_out = t1_creator
+        .checked_add(t1_fee).ok_or(ErrorCode::Overflow)?
+        .checked_add(t1_withhold).ok_or(ErrorCode::Overflow)?;
+    require!(escrow.amount_locked >= total_out, ErrorCode::InvalidEscrowBalance);
+
+    payment.pay(Payout { to: creator_payout, amount: t1_creator })?;
+    payment.pay(Payout { to: fee_dest, amount: t1_fee })?;
+    payment.pay(Payout { to: dispute_dest, amount: t1_withhold })?;
+    escrow.amount_locked = escrow.amount_locked.checked_sub(total_out).ok_or(ErrorCode::Overflow)?;
+    Ok((t1_creator, t1_fee, t1_withhold))
+}
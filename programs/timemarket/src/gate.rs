@@ -0,0 +1,44 @@
+use anchor_lang::prelude::*;
+use anchor_spl::metadata::mpl_token_metadata::{self, accounts::Metadata};
+use anchor_spl::token_interface::TokenAccount;
+
+use crate::*;
+use crate::ErrorCode;
+
+// Check that `holder` may book a gated slot; ungated slots pass without any gate accounts.
+// Mint gates read the balance of `gate_token`. Collection gates additionally need the Metaplex
+// metadata of the held NFT, which must name the gate's collection as verified.
+pub fn check_gate(
+    slot: &TimeSlot,
+    holder: Pubkey,
+    gate_token: Option<&InterfaceAccount<TokenAccount>>,
+    gate_metadata: Option<&AccountInfo>,
+) -> Result<()> {
+    let Some(gate) = slot.gate else {
+        return Ok(());
+    };
+    let token = gate_token.ok_or(ErrorCode::GateNotSatisfied)?;
+    require_keys_eq!(token.owner, holder, ErrorCode::GateNotSatisfied);
+
+    match gate.kind {
+        GateKind::Mint => {
+            require_keys_eq!(token.mint, gate.key, ErrorCode::GateNotSatisfied);
+            require!(token.amount >= gate.min_amount, ErrorCode::GateNotSatisfied);
+        }
+        GateKind::Collection => {
+            require!(token.amount >= 1, ErrorCode::GateNotSatisfied);
+            let metadata = gate_metadata.ok_or(ErrorCode::GateNotSatisfied)?;
+            require_keys_eq!(*metadata.owner, mpl_token_metadata::ID, ErrorCode::GateNotSatisfied);
+            let (expected, _) = Metadata::find_pda(&token.mint);
+            require_keys_eq!(metadata.key(), expected, ErrorCode::GateNotSatisfied);
+            let data = metadata.try_borrow_data()?;
+            let md = Metadata::safe_deserialize(&data).map_err(|_| ErrorCode::GateNotSatisfied)?;
+            let member = md
+                .collection
+                .map(|c| c.verified && c.key == gate.key)
+                .unwrap_or(false);
+            require!(member, ErrorCode::GateNotSatisfied);
+        }
+    }
+    Ok(())
+}
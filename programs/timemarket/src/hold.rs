@@ -0,0 +1,116 @@
+use anchor_lang::prelude::*;
+use anchor_lang::system_program;
+
+use crate::*;
+use crate::ErrorCode;
+
+// Checkout holds: a buyer reserves the right to book a Stable slot for a few seconds while
+// their wallet flow completes. The platform caps the duration, may ask for a refundable
+// deposit, and always enforces a cooldown after each hold ends before the wallet can hold again.
+
+pub fn set_hold_config(
+    ctx: Context<SetHoldConfig>,
+    max_hold_sec: u32,
+    deposit_lamports: u64,
+    cooldown_sec: u32,
+) -> Result<()> {
+    // The deposit always comes back, so only the cooldown stops a wallet from chaining holds
+    require!(max_hold_sec > 0 && cooldown_sec > 0, ErrorCode::InvalidHoldConfig);
+    let config = &mut ctx.accounts.hold_config;
+    config.platform = ctx.accounts.platform.key();
+    config.max_hold_sec = max_hold_sec;
+    config.deposit_lamports = deposit_lamports;
+    config.cooldown_sec = cooldown_sec;
+    config.bump = ctx.bumps.hold_config;
+    emit!(HoldConfigSetEvent { platform: config.platform, max_hold_sec, deposit_lamports, cooldown_sec });
+    Ok(())
+}
+
+pub fn hold_slot(ctx: Context<HoldSlot>, duration_sec: u32) -> Result<()> {
+    let config = &ctx.accounts.hold_config;
+    let slot = &mut ctx.accounts.slot;
+    let holder = ctx.accounts.holder.key();
+    require!(slot.mode == Mode::Stable, ErrorCode::WrongMode);
+    require!(slot.approval_window_sec.is_none(), ErrorCode::ApprovalRequired);
+    require!(!slot.frozen, ErrorCode::Frozen);
+    require!(slot.state == SlotState::Open, ErrorCode::InvalidState);
+    check_on_sale(slot)?;
+    require!(slot.capacity_sold < slot.capacity_total, ErrorCode::CapacityExhausted);
+    require!(holder != slot.creator_authority, ErrorCode::Unauthorized);
+    require!(duration_sec > 0 && duration_sec <= config.max_hold_sec, ErrorCode::HoldTooLong);
+    let now = Clock::get()?.unix_timestamp;
+    require!(now < slot.start_ts, ErrorCode::TooLate);
+
+    // The cooldown runs from the end of this hold, so other buyers get a window before the
+    // same wallet can hold again
+    let expires_at = now.checked_add(duration_sec as i64).ok_or(ErrorCode::Overflow)?.min(slot.start_ts);
+    let quota = &mut ctx.accounts.hold_quota;
+    require!(now >= quota.next_hold_at, ErrorCode::HoldRateLimited);
+    quota.wallet = holder;
+    quota.next_hold_at = expires_at.checked_add(config.cooldown_sec as i64).ok_or(ErrorCode::Overflow)?;
+    quota.bump = ctx.bumps.hold_quota;
+
+    // The hold PDA outlives an expired hold that nobody released; take it over once it ran out and
+    // hand its deposit and rent back to the previous holder before this hold funds it again
+    let hold_info = ctx.accounts.hold.to_account_info();
+    let previous = &ctx.accounts.hold;
+    if previous.holder != Pubkey::default() {
+        require!(previous.expires_at <= now, ErrorCode::SlotHeld);
+        let previous_holder = ctx.accounts.previous_holder.as_ref().ok_or(ErrorCode::Unauthorized)?;
+        require_keys_eq!(previous_holder.key(), previous.holder, ErrorCode::Unauthorized);
+        let refund = hold_info.lamports();
+        let rent = Rent::get()?.minimum_balance(hold_info.data_len());
+        **hold_info.try_borrow_mut_lamports()? = 0;
+        let mut to = previous_holder.try_borrow_mut_lamports()?;
+        **to = (*to).checked_add(refund).ok_or(ErrorCode::Overflow)?;
+        drop(to);
+        emit!(HoldReleasedEvent { slot: slot.key(), holder: previous.holder, deposit: previous.deposit });
+        system_program::transfer(
+            CpiContext::new(
+                ctx.accounts.system_program.to_account_info(),
+                system_program::Transfer {
+                    from: ctx.accounts.holder.to_account_info(),
+                    to: hold_info.clone(),
+                },
+            ),
+            rent,
+        )?;
+    }
+
+    let deposit = config.deposit_lamports;
+    if deposit > 0 {
+        system_program::transfer(
+            CpiContext::new(
+                ctx.accounts.system_program.to_account_info(),
+                system_program::Transfer {
+                    from: ctx.accounts.holder.to_account_info(),
+                    to: ctx.accounts.hold.to_account_info(),
+                },
+            ),
+            deposit,
+        )?;
+    }
+
+    let hold = &mut ctx.accounts.hold;
+    hold.slot = slot.key();
+    hold.holder = holder;
+    hold.deposit = deposit;
+    hold.expires_at = expires_at;
+    hold.bump = ctx.bumps.hold;
+    slot.hold = Some(ActiveHold { holder, expires_at });
+    emit!(SlotHeldEvent { slot: slot.key(), holder, deposit, expires_at });
+    Ok(())
+}
+
+// The holder may release early; anyone may close an expired hold or one whose slot has sold.
+// Closing returns the deposit and rent to the holder.
+pub fn release_hold(ctx: Context<ReleaseHold>) -> Result<()> {
+    let slot = &mut ctx.accounts.slot;
+    let hold = &ctx.accounts.hold;
+    let now = Clock::get()?.unix_timestamp;
+    let active = now < hold.expires_at && slot.state == SlotState::Open;
+    require!(!active || ctx.accounts.caller.key() == hold.holder, ErrorCode::HoldStillActive);
+    slot.hold = None;
+    emit!(HoldReleasedEvent { slot: slot.key(), holder: hold.holder, deposit: hold.deposit });
+    Ok(())
+}
@@ -1,11 +1,27 @@
 use anchor_lang::prelude::*;
 use anchor_spl::associated_token::AssociatedToken;
+use anchor_spl::metadata::Metadata;
 use anchor_spl::token_interface::{transfer_checked, mint_to, Mint, TokenAccount, TokenInterface, TransferChecked, MintTo};
 
 // Group logic into focused modules for readability
 mod market;
 mod escrow;
 mod tipping;
+mod reschedule;
+mod series;
+mod auction_sol;
+mod oracle;
+mod audit;
+mod resale;
+mod ticket;
+mod nft;
+mod attendance;
+mod gate;
+mod allowlist;
+mod booking;
+mod draft;
+mod hold;
+mod migrate;
 
 declare_id!("Gz7jdgqsn3R8mBrthEx5thAFYdM369kHN7wMTY3PKhty");
 
@@ -30,6 +46,35 @@ impl Platform {
     pub const LEN: usize = 32 + 2 + 32 + 32 + 32 + 1;
 }
 
+// Platform-approved SPL mint with its own fee and dispute vaults
+#[account]
+pub struct AcceptedMint {
+    pub platform: Pubkey,
+    pub mint: Pubkey,
+    pub fee_vault: Pubkey,
+    pub dispute_vault: Pubkey,
+    // Disabled mints cannot price new slots; existing slots still settle
+    pub enabled: bool,
+    pub bump: u8,
+}
+
+impl AcceptedMint {
+    pub const LEN: usize = 32 + 32 + 32 + 32 + 1 + 1;
+}
+
+// Wallets that collect platform fees and dispute withholds on native SOL slots
+#[account]
+pub struct SolReceivers {
+    pub platform: Pubkey,
+    pub fee_receiver: Pubkey,
+    pub dispute_receiver: Pubkey,
+    pub bump: u8,
+}
+
+impl SolReceivers {
+    pub const LEN: usize = 32 + 32 + 32 + 1;
+}
+
 #[account]
 pub struct CreatorProfile {
     pub authority: Pubkey,
@@ -39,10 +84,17 @@ pub struct CreatorProfile {
     pub total_tips_received: u64,
     pub tip_count: u32,
     pub bump: u8,
+    // Fields below were appended after launch; see migrate.rs
+    // Monotonic counter used as the slot PDA seed; slots are indexed 0..slot_count
+    pub slot_count: u64,
+    // Counter seeding SlotSeries PDAs
+    pub series_count: u64,
 }
 
 impl CreatorProfile {
-    pub const LEN: usize = 32 + 32 + 1 + 2 + 32 + 8 + 4 + 1; // Option<u16> ~ 1 tag + 2 value
+    pub const LEN: usize = Self::LEN_V0 + 8 + 8;
+    // Size of profiles created before slot_count was appended
+    pub const LEN_V0: usize = 32 + 32 + 1 + 2 + 32 + 8 + 4 + 1; // Option<u16> ~ 1 tag + 2 value
 }
 
 // Fee policy helper: use creator override when present, otherwise platform default
@@ -57,6 +109,70 @@ pub enum Mode {
     SealedBid,
 }
 
+// Currency a slot is paid in; fixed at creation so SOL and SPL flows never share an escrow
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq)]
+pub enum PaymentKind {
+    Spl,
+    Sol,
+}
+
+// Optional USD pricing for SPL slots: the mint amount is worked out from an oracle feed at
+// purchase time instead of being fixed at listing
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq)]
+pub struct UsdQuote {
+    pub price_feed: Pubkey,
+    // Stable price and buy-now price in USD cents
+    pub price_usd_cents: u64,
+    pub buy_now_usd_cents: Option<u64>,
+    // Feed must be at most this old and its confidence interval within max_conf_bps of price
+    pub max_staleness_sec: u32,
+    pub max_conf_bps: u16,
+}
+
+impl UsdQuote {
+    pub const LEN: usize = 32 + 8 + (1 + 8) + 4 + 2;
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq)]
+pub enum GateKind {
+    // Hold at least min_amount of the `key` mint
+    Mint,
+    // Hold an NFT whose Metaplex metadata names `key` as its verified collection
+    Collection,
+}
+
+// Restricts who may book a slot to holders of a token or collection
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq)]
+pub struct TokenGate {
+    pub kind: GateKind,
+    pub key: Pubkey,
+    // Balance required for Mint gates; Collection gates need a single member NFT
+    pub min_amount: u64,
+}
+
+impl TokenGate {
+    pub const LEN: usize = 1 + 32 + 8;
+}
+
+// Checkout hold mirrored on the slot so reservations can honour it without the hold account
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq)]
+pub struct ActiveHold {
+    pub holder: Pubkey,
+    pub expires_at: i64,
+}
+
+impl ActiveHold {
+    pub const LEN: usize = 32 + 8;
+}
+
+// Merkle proof that the signer is on a slot's allowlist
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct AllowlistProof {
+    // Bookings this address may make; must match the leaf (None for uncapped entries)
+    pub quantity: Option<u16>,
+    pub proof: Vec<[u8; 32]>,
+}
+
 #[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq)]
 pub enum SlotState {
     Draft,
@@ -70,6 +186,20 @@ pub enum SlotState {
     Closed,
 }
 
+// Cancellation policy tier: refund `refund_bps` when cancelling more than
+// `secs_before_start` seconds ahead of the session start
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq)]
+pub struct CancelTier {
+    pub secs_before_start: u32,
+    pub refund_bps: u16,
+}
+
+impl CancelTier {
+    pub const LEN: usize = 4 + 2;
+}
+
+pub const MAX_CANCEL_TIERS: usize = 4;
+
 #[derive(AnchorSerialize, AnchorDeserialize, Clone)]
 pub struct CreateSlotParams {
     pub start_ts: i64,
@@ -86,6 +216,91 @@ pub struct CreateSlotParams {
     pub auction_start_ts: Option<i64>,
     pub auction_end_ts: Option<i64>,
     pub anti_sniping_sec: Option<u32>,
+    // Empty policy keeps the legacy behaviour: full refund until T0
+    pub cancel_policy: Vec<CancelTier>,
+    pub payment_kind: PaymentKind,
+    // Registered AcceptedMint for SPL slots; default for SOL slots
+    pub mint: Pubkey,
+    pub usd_quote: Option<UsdQuote>,
+    // Pins the booking to the wallet it was made for; transfer_reservation is rejected
+    pub transfers_disabled: bool,
+    // Creator cut of every resale, and the ceiling a booking may be listed at
+    pub resale_royalty_bps: u16,
+    pub max_resale_price: Option<u64>,
+    // Ticket mode: nft_mint is minted at booking and holding it carries check-in/dispute rights
+    pub nft_ticket: bool,
+    // Only holders of the gate token/collection may reserve, bid or buy
+    pub gate: Option<TokenGate>,
+    // Invite-only: Merkle root of the addresses allowed to reserve, bid or buy
+    pub allowlist_root: Option<[u8; 32]>,
+    // Creator approval: buyers submit booking requests the creator must answer within this window
+    pub approval_window_sec: Option<u32>,
+    // Stage the slot as Draft: editable with update_draft_slot until publish_slot opens it
+    pub draft: bool,
+}
+
+// Draft edits; None keeps the current value
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct UpdateDraftParams {
+    pub start_ts: Option<i64>,
+    pub end_ts: Option<i64>,
+    pub tz_offset_min: Option<i16>,
+    pub subject_hash: Option<[u8; 32]>,
+    pub venue_hash: Option<[u8; 32]>,
+    pub mode: Option<Mode>,
+    pub capacity: Option<u16>,
+    pub price: Option<u64>,
+    pub min_increment_bps: Option<u16>,
+    pub buy_now: Option<Option<u64>>,
+    pub auction_start_ts: Option<Option<i64>>,
+    pub auction_end_ts: Option<Option<i64>>,
+    pub anti_sniping_sec: Option<Option<u32>>,
+    pub cancel_policy: Option<Vec<CancelTier>>,
+    pub gate: Option<Option<TokenGate>>,
+    pub allowlist_root: Option<Option<[u8; 32]>>,
+}
+
+// Edits to an Open slot nobody has bought or bid on yet; None keeps the current value
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct UpdateSlotParams {
+    pub price: Option<u64>,
+    pub buy_now: Option<Option<u64>>,
+    pub min_increment_bps: Option<u16>,
+    pub anti_sniping_sec: Option<Option<u32>>,
+    pub subject_hash: Option<[u8; 32]>,
+    pub venue_hash: Option<[u8; 32]>,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq)]
+pub enum SlotField {
+    Price,
+    BuyNow,
+    MinIncrementBps,
+    AntiSnipingSec,
+    SubjectHash,
+    VenueHash,
+}
+
+// Metaplex metadata for a program-created slot NFT
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct SlotNftParams {
+    pub name: String,
+    pub symbol: String,
+    pub uri: String,
+}
+
+// Creator-proposed move of a booked session, awaiting the buyer's answer
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq)]
+pub struct RescheduleProposal {
+    pub new_start_ts: i64,
+    pub new_end_ts: i64,
+    pub respond_by: i64,
+    // Outcome applied by anyone once respond_by has passed without an answer
+    pub default_accept: bool,
+}
+
+impl RescheduleProposal {
+    pub const LEN: usize = 8 + 8 + 8 + 1;
 }
 
 #[account]
@@ -115,10 +330,151 @@ pub struct TimeSlot {
     pub anti_sniping_sec: Option<u32>,
     pub total_tips_received: u64,
     pub bump: u8,
+    // Fields below were appended after launch; see migrate.rs
+    // Tiers sorted by secs_before_start descending (max MAX_CANCEL_TIERS). Migrated slots start
+    // with no tiers, which keeps their pre-upgrade full refund before start.
+    pub cancel_policy: Vec<CancelTier>,
+    pub reschedule: Option<RescheduleProposal>,
+    // Template this slot was materialized from (default when created directly)
+    pub series: Pubkey,
+    pub payment_kind: PaymentKind,
+    // When set, `price` is re-quoted from the feed at each reservation
+    pub usd_quote: Option<UsdQuote>,
+    pub transfers_disabled: bool,
+    pub resale_royalty_bps: u16,
+    pub max_resale_price: Option<u64>,
+    pub nft_ticket: bool,
+    // NFTs outstanding (minted minus burned tickets); capped at capacity_total
+    pub nft_minted: u16,
+    pub gate: Option<TokenGate>,
+    // Rotated by the creator until sales close
+    pub allowlist_root: Option<[u8; 32]>,
+    pub approval_window_sec: Option<u32>,
+    // Bumped on every approval; requests from earlier rounds become refundable
+    pub booking_round: u32,
+    // Scheduled on-sale time set at publish; reservations and bids are rejected before it
+    pub publish_at: Option<i64>,
+    // Only the holder may reserve until it expires
+    pub hold: Option<ActiveHold>,
+    // Seed index taken from CreatorProfile.slot_count at creation; 0 on slots seeded by start_ts
+    pub index: u64,
 }
 
 impl TimeSlot {
-    pub const LEN: usize = 32 + 32 + 32 + 32 + 8 + 8 + 2 + 32 + 32 + 1 + 1 + 1 + 2 + 2 + 32 + 8 + 2 + (1 + 8) + (1 + 8) + (1 + 8) + (1 + 4) + 8 + 1;
+    pub const LEN: usize = Self::LEN_V0
+        + (4 + CancelTier::LEN * MAX_CANCEL_TIERS)
+        + (1 + RescheduleProposal::LEN)
+        + 32
+        + 1
+        + (1 + UsdQuote::LEN)
+        + 1
+        + 2 + (1 + 8)
+        + 1
+        + 2
+        + (1 + TokenGate::LEN)
+        + (1 + 32)
+        + (1 + 4) + 4
+        + (1 + 8)
+        + (1 + ActiveHold::LEN)
+        + 8;
+    // Size of slots created before cancel_policy and later fields were appended
+    pub const LEN_V0: usize = 32 + 32 + 32 + 32 + 8 + 8 + 2 + 32 + 32 + 1 + 1 + 1 + 2 + 2 + 32 + 8 + 2 + (1 + 8) + (1 + 8) + (1 + 8) + (1 + 4) + 8 + 1;
+}
+
+// Recurring slot template: occurrence k starts at first_start_ts + k * interval_sec
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct CreateSeriesParams {
+    pub first_start_ts: i64,
+    pub duration_sec: u32,
+    pub interval_sec: u32,
+    pub count: u16,
+    pub tz_offset_min: i16,
+    pub subject_hash: [u8; 32],
+    pub venue_hash: [u8; 32],
+    pub mode: Mode,
+    pub capacity: u16,
+    pub price: u64,
+    pub min_increment_bps: u16,
+    pub buy_now: Option<u64>,
+    // Auction window relative to each occurrence start
+    pub auction_open_before_sec: Option<u32>,
+    pub auction_close_before_sec: Option<u32>,
+    pub anti_sniping_sec: Option<u32>,
+    pub cancel_policy: Vec<CancelTier>,
+    pub payment_kind: PaymentKind,
+    pub mint: Pubkey,
+    pub usd_quote: Option<UsdQuote>,
+    pub transfers_disabled: bool,
+    pub resale_royalty_bps: u16,
+    pub max_resale_price: Option<u64>,
+    pub gate: Option<TokenGate>,
+    pub allowlist_root: Option<[u8; 32]>,
+    pub approval_window_sec: Option<u32>,
+}
+
+// Template edits; None keeps the current value
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct UpdateSeriesParams {
+    pub duration_sec: Option<u32>,
+    pub interval_sec: Option<u32>,
+    pub count: Option<u16>,
+    pub subject_hash: Option<[u8; 32]>,
+    pub venue_hash: Option<[u8; 32]>,
+    pub capacity: Option<u16>,
+    pub price: Option<u64>,
+    pub min_increment_bps: Option<u16>,
+    pub buy_now: Option<Option<u64>>,
+    pub anti_sniping_sec: Option<Option<u32>>,
+    pub cancel_policy: Option<Vec<CancelTier>>,
+}
+
+#[account]
+pub struct SlotSeries {
+    pub creator_profile: Pubkey,
+    pub creator_authority: Pubkey,
+    pub platform: Pubkey,
+    pub mint: Pubkey,
+    pub index: u64,
+    pub first_start_ts: i64,
+    pub duration_sec: u32,
+    pub interval_sec: u32,
+    pub count: u16,
+    // Occurrences already created as TimeSlots
+    pub materialized: u16,
+    pub tz_offset_min: i16,
+    pub subject_hash: [u8; 32],
+    pub venue_hash: [u8; 32],
+    pub mode: Mode,
+    pub capacity: u16,
+    pub price: u64,
+    pub min_increment_bps: u16,
+    pub buy_now: Option<u64>,
+    pub auction_open_before_sec: Option<u32>,
+    pub auction_close_before_sec: Option<u32>,
+    pub anti_sniping_sec: Option<u32>,
+    pub cancel_policy: Vec<CancelTier>,
+    pub payment_kind: PaymentKind,
+    pub usd_quote: Option<UsdQuote>,
+    pub transfers_disabled: bool,
+    pub resale_royalty_bps: u16,
+    pub max_resale_price: Option<u64>,
+    pub gate: Option<TokenGate>,
+    pub allowlist_root: Option<[u8; 32]>,
+    pub approval_window_sec: Option<u32>,
+    pub bump: u8,
+}
+
+impl SlotSeries {
+    pub const LEN: usize = 32 + 32 + 32 + 32 + 8 + 8 + 4 + 4 + 2 + 2 + 2 + 32 + 32 + 1 + 2 + 8 + 2 + (1 + 8) + (1 + 4) + (1 + 4) + (1 + 4)
+        + (4 + CancelTier::LEN * MAX_CANCEL_TIERS)
+        + 1
+        + (1 + UsdQuote::LEN)
+        + 1
+        + 2 + (1 + 8)
+        + (1 + TokenGate::LEN)
+        + (1 + 32)
+        + (1 + 4)
+        + 1;
 }
 
 #[account]
@@ -126,26 +482,147 @@ pub struct Escrow {
     pub slot: Pubkey,
     pub token_acc: Pubkey,
     pub amount_locked: u64,
+    // Beneficiary: attends, checks in and receives the ticket NFT
     pub buyer: Option<Pubkey>,
     pub bump: u8,
+    // Fields below were appended after launch; see migrate.rs
+    // Wallet that funded the booking; refunds go here. None means the buyer paid.
+    pub payer: Option<Pubkey>,
+    // Deposits of booking requests not yet approved or refunded; not part of amount_locked
+    pub requests_locked: u64,
 }
 
 impl Escrow {
-    pub const LEN: usize = 32 + 32 + 8 + (1 + 32) + 1;
+    pub const LEN: usize = Self::LEN_V0 + (1 + 32) + 8;
+    // Size of escrows created before payer was appended
+    pub const LEN_V0: usize = 32 + 32 + 8 + (1 + 32) + 1;
+
+    pub fn refund_to(&self) -> Option<Pubkey> {
+        self.payer.or(self.buyer)
+    }
+}
+
+// Soulbound record of sessions an attendee checked in to with one creator
+#[account]
+pub struct Attendance {
+    pub creator_profile: Pubkey,
+    pub attendee: Pubkey,
+    pub sessions: u32,
+    // Scheduled length of every attended session, summed
+    pub seconds_attended: u64,
+    pub first_checkin_ts: i64,
+    pub last_checkin_ts: i64,
+    pub bump: u8,
+}
+
+impl Attendance {
+    pub const LEN: usize = 32 + 32 + 4 + 8 + 8 + 8 + 1;
+}
+
+// Bookings made by one allowlisted address on a slot, for entries with a quantity cap
+#[account]
+pub struct AllowlistClaim {
+    pub slot: Pubkey,
+    pub wallet: Pubkey,
+    pub used: u16,
+    pub bump: u8,
+}
+
+impl AllowlistClaim {
+    pub const LEN: usize = 32 + 32 + 2 + 1;
+}
+
+// A buyer's deposit on an approval slot, waiting for the creator's answer
+#[account]
+pub struct BookingRequest {
+    pub slot: Pubkey,
+    pub requester: Pubkey,
+    pub amount: u64,
+    pub message_hash: Option<[u8; 32]>,
+    pub expires_at: i64,
+    // Slot's booking_round when requested; approving another request supersedes it
+    pub round: u32,
+    pub declined: bool,
+    pub bump: u8,
+}
+
+impl BookingRequest {
+    pub const LEN: usize = 32 + 32 + 8 + (1 + 32) + 8 + 4 + 1 + 1;
+}
+
+// Platform-wide limits for checkout holds, set by the admin
+#[account]
+pub struct HoldConfig {
+    pub platform: Pubkey,
+    pub max_hold_sec: u32,
+    // Lamports parked on the hold and returned when it is released; 0 makes holds free
+    pub deposit_lamports: u64,
+    // Wait after a hold ends before the same wallet may hold again; must be non-zero
+    pub cooldown_sec: u32,
+    pub bump: u8,
+}
+
+impl HoldConfig {
+    pub const LEN: usize = 32 + 4 + 8 + 4 + 1;
+}
+
+// A buyer's hold on a slot; the account carries the deposit and goes back to the holder on release
+#[account]
+pub struct SlotHold {
+    pub slot: Pubkey,
+    pub holder: Pubkey,
+    pub deposit: u64,
+    pub expires_at: i64,
+    pub bump: u8,
+}
+
+impl SlotHold {
+    pub const LEN: usize = 32 + 32 + 8 + 8 + 1;
+}
+
+// Per-wallet rate limit for holds on a platform
+#[account]
+pub struct HoldQuota {
+    pub wallet: Pubkey,
+    pub next_hold_at: i64,
+    pub bump: u8,
+}
+
+impl HoldQuota {
+    pub const LEN: usize = 32 + 8 + 1;
+}
+
+// Open resale offer for a slot's booking; closed when bought or withdrawn
+#[account]
+pub struct Listing {
+    pub slot: Pubkey,
+    pub seller: Pubkey,
+    pub price: u64,
+    pub bump: u8,
+}
+
+impl Listing {
+    pub const LEN: usize = 32 + 32 + 8 + 1;
 }
 
 #[account]
 pub struct BidBook {
     pub slot: Pubkey,
     pub highest_bid: u64,
+    // Funds the highest bid and receives its refund when outbid
     pub highest_bidder: Pubkey,
     pub next_min_bid: u64,
     pub last_bid_ts: i64,
     pub bump: u8,
+    // Fields below were appended after launch; see migrate.rs
+    // Attends the session if the highest bid wins
+    pub highest_beneficiary: Pubkey,
 }
 
 impl BidBook {
-    pub const LEN: usize = 32 + 8 + 32 + 8 + 8 + 1;
+    pub const LEN: usize = Self::LEN_V0 + 32;
+    // Size of bid books created before highest_beneficiary was appended
+    pub const LEN_V0: usize = 32 + 8 + 32 + 8 + 8 + 1;
 }
 
 #[derive(AnchorSerialize, AnchorDeserialize, Clone)]
@@ -256,6 +733,15 @@ pub struct InitPlatform<'info> {
     /// Dispute vault token account owned by platform (key stored on Platform)
     #[account(mut)]
     pub dispute_vault: InterfaceAccount<'info, TokenAccount>,
+    /// Registry entry for the initial mint
+    #[account(
+        init,
+        payer = admin,
+        space = 8 + AcceptedMint::LEN,
+        seeds = [b"accepted_mint", platform.key().as_ref(), mint.key().as_ref()],
+        bump
+    )]
+    pub accepted_mint: Account<'info, AcceptedMint>,
     pub system_program: Program<'info, System>,
 }
 
@@ -263,18 +749,22 @@ pub struct InitPlatform<'info> {
 pub struct BidCommit<'info> {
     pub bidder: Signer<'info>,
     pub platform: Account<'info, Platform>,
-    #[account(constraint = mint.key() == platform.mint)]
+    #[account(constraint = slot.payment_kind == PaymentKind::Sol || mint.key() == slot.mint)]
     pub mint: InterfaceAccount<'info, Mint>,
     pub slot: Account<'info, TimeSlot>,
     #[account(mut, seeds = [b"commit", slot.key().as_ref()], bump = commit_store.bump)]
     pub commit_store: Account<'info, CommitStore>,
+    // Token-gated slots only: the signer's token account (and its NFT metadata for collection gates)
+    pub gate_token: Option<InterfaceAccount<'info, TokenAccount>>,
+    /// CHECK: Metaplex metadata of gate_token's mint; owner and PDA are checked in gate::check_gate
+    pub gate_metadata: Option<UncheckedAccount<'info>>,
 }
 
 #[derive(Accounts)]
 pub struct BidReveal<'info> {
     pub bidder: Signer<'info>,
     pub platform: Account<'info, Platform>,
-    #[account(constraint = mint.key() == platform.mint)]
+    #[account(constraint = slot.payment_kind == PaymentKind::Sol || mint.key() == slot.mint)]
     pub mint: InterfaceAccount<'info, Mint>,
     pub slot: Account<'info, TimeSlot>,
     #[account(mut, seeds = [b"commit", slot.key().as_ref()], bump = commit_store.bump)]
@@ -305,12 +795,12 @@ pub struct UpdateCreatorProfile<'info> {
 }
 
 #[derive(Accounts)]
-#[instruction(params: CreateSlotParams)]
 pub struct CreateTimeSlot<'info> {
+    #[account(mut)]
     pub authority: Signer<'info>,
     pub platform: Account<'info, Platform>,
-    #[account(constraint = mint.key() == platform.mint)]
-    pub mint: InterfaceAccount<'info, Mint>,
+    /// Registry entry for params.mint; omitted for SOL slots
+    pub accepted_mint: Option<Account<'info, AcceptedMint>>,
     #[account(
         mut,
         seeds = [b"creator", authority.key().as_ref(), platform.key().as_ref()],
@@ -321,19 +811,36 @@ pub struct CreateTimeSlot<'info> {
         init,
         payer = authority,
         space = 8 + TimeSlot::LEN,
-        // deterministic seed: profile + start_ts
-        seeds = [b"slot", profile.key().as_ref(), &params.start_ts.to_le_bytes()],
+        // deterministic seed: profile + per-creator slot index
+        seeds = [b"slot", profile.key().as_ref(), &profile.slot_count.to_le_bytes()],
         bump
     )]
     pub slot: Account<'info, TimeSlot>,
     pub system_program: Program<'info, System>,
 }
 
+#[derive(Accounts)]
+pub struct CreateTimeSlotsBatch<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    pub platform: Account<'info, Platform>,
+    /// Registry entry for params.mint; omitted for SOL slots
+    pub accepted_mint: Option<Account<'info, AcceptedMint>>,
+    #[account(
+        mut,
+        seeds = [b"creator", authority.key().as_ref(), platform.key().as_ref()],
+        bump = profile.bump
+    )]
+    pub profile: Account<'info, CreatorProfile>,
+    pub system_program: Program<'info, System>,
+    // remaining_accounts: one slot PDA per params entry at profile.slot_count, +1, ...
+}
+
 #[derive(Accounts)]
 pub struct InitBidBook<'info> {
     pub authority: Signer<'info>,
     pub platform: Account<'info, Platform>,
-    #[account(constraint = mint.key() == platform.mint)]
+    #[account(constraint = slot.payment_kind == PaymentKind::Sol || mint.key() == slot.mint)]
     pub mint: InterfaceAccount<'info, Mint>,
     #[account(mut)]
     pub slot: Account<'info, TimeSlot>,
@@ -353,7 +860,7 @@ pub struct InitBidBook<'info> {
 pub struct InitCommitStore<'info> {
     pub authority: Signer<'info>,
     pub platform: Account<'info, Platform>,
-    #[account(constraint = mint.key() == platform.mint)]
+    #[account(constraint = slot.payment_kind == PaymentKind::Sol || mint.key() == slot.mint)]
     pub mint: InterfaceAccount<'info, Mint>,
     #[account(mut)]
     pub slot: Account<'info, TimeSlot>,
@@ -370,9 +877,10 @@ pub struct InitCommitStore<'info> {
 
 #[derive(Accounts)]
 pub struct InitEscrow<'info> {
+    #[account(mut)]
     pub admin: Signer<'info>,
     pub platform: Account<'info, Platform>,
-    #[account(constraint = mint.key() == platform.mint)]
+    #[account(constraint = slot.payment_kind == PaymentKind::Sol || mint.key() == slot.mint)]
     pub mint: InterfaceAccount<'info, Mint>,
     #[account(mut)]
     pub slot: Account<'info, TimeSlot>,
@@ -384,8 +892,17 @@ pub struct InitEscrow<'info> {
         bump
     )]
     pub escrow: Account<'info, Escrow>,
-    #[account(mut)]
-    pub escrow_vault: InterfaceAccount<'info, TokenAccount>,
+    /// Vault owned by the escrow PDA (its ATA for the slot mint); omitted for SOL slots
+    #[account(
+        init_if_needed,
+        payer = admin,
+        associated_token::mint = mint,
+        associated_token::authority = escrow,
+        associated_token::token_program = token_program
+    )]
+    pub escrow_vault: Option<InterfaceAccount<'info, TokenAccount>>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub token_program: Interface<'info, TokenInterface>,
     pub system_program: Program<'info, System>,
 }
 
@@ -465,6 +982,88 @@ pub enum ErrorCode {
     MultiCapacityUnsupported,
     #[msg("Invalid amount")]
     InvalidAmount,
+    #[msg("Invalid cancellation policy")]
+    InvalidCancelPolicy,
+    #[msg("Reschedule pending")]
+    ReschedulePending,
+    #[msg("No reschedule pending")]
+    NoReschedulePending,
+    #[msg("Invalid slot account")]
+    InvalidSlotAccount,
+    #[msg("Series has no occurrences left")]
+    SeriesExhausted,
+    #[msg("Payout would leave escrow below its rent-exempt minimum")]
+    EscrowRentViolation,
+    #[msg("Instruction does not match the slot's payment currency")]
+    WrongPaymentKind,
+    #[msg("Mint is not accepted by the platform")]
+    MintNotAccepted,
+    #[msg("Price feed account is missing, invalid or not trading")]
+    InvalidOracle,
+    #[msg("Price feed is stale")]
+    OracleStale,
+    #[msg("Price feed confidence interval too wide")]
+    OracleConfidence,
+    #[msg("Quoted amount exceeds the buyer's max payment")]
+    SlippageExceeded,
+    #[msg("Escrow vault is not the one created for this escrow")]
+    InvalidEscrowVault,
+    #[msg("Escrow holds no surplus to reconcile")]
+    NoEscrowSurplus,
+    #[msg("Reservations for this slot cannot be transferred")]
+    TransfersDisabled,
+    #[msg("Listing price exceeds the slot's max resale price")]
+    ResalePriceTooHigh,
+    #[msg("Ticket NFT missing or not held by the signer")]
+    InvalidTicket,
+    #[msg("Slot uses NFT tickets; move the ticket instead")]
+    NftTicketMode,
+    #[msg("Slot already has an NFT mint")]
+    NftAlreadyInitialized,
+    #[msg("Slot NFT supply is capped at capacity")]
+    NftSupplyExhausted,
+    #[msg("Token gate needs a non-default key and a positive minimum amount")]
+    InvalidGate,
+    #[msg("Signer does not hold the token or collection this slot is gated on")]
+    GateNotSatisfied,
+    #[msg("Signer is not on this slot's allowlist")]
+    NotOnAllowlist,
+    #[msg("Allowlist quantity for this address is used up")]
+    AllowlistQuantityExceeded,
+    #[msg("Slot requires creator approval; use request_booking")]
+    ApprovalRequired,
+    #[msg("Slot does not take booking requests")]
+    BookingRequestsDisabled,
+    #[msg("Booking request has expired")]
+    RequestExpired,
+    #[msg("Booking request is still awaiting the creator")]
+    RequestStillPending,
+    #[msg("Slot is not on sale yet")]
+    NotYetOnSale,
+    #[msg("Slot already has a buyer, bid, commit or booking request")]
+    SlotHasActivity,
+    #[msg("Hold config needs a duration and a cooldown")]
+    InvalidHoldConfig,
+    #[msg("Hold is longer than the platform allows")]
+    HoldTooLong,
+    #[msg("Wallet placed a hold too recently")]
+    HoldRateLimited,
+    #[msg("Slot is held by another buyer")]
+    SlotHeld,
+    #[msg("Hold has not expired yet")]
+    HoldStillActive,
+    #[msg("Receiver does not match the platform's SOL receivers")]
+    InvalidSolReceiver,
+    #[msg("Every other booking request must be refunded when approving")]
+    RequestsOutstanding,
+    #[msg("Remaining account is not a refundable booking request")]
+    InvalidBookingRequestAccount,
+    #[msg("Account is not in a pre-upgrade layout this program can migrate")]
+    InvalidMigration,
+    #[msg("This instruction does not support the slot's payment kind")]
+    UnsupportedPaymentKind,
+    #[msg("Seller cannot buy their own listing")]
+    OwnListing,
 }
 
 // ===================== CPI helpers =====================
@@ -478,6 +1077,16 @@ pub fn mul_bps_u64(amount: u64, bps: u64) -> Result<u64> {
     Ok(v as u64)
 }
 
+// Slot PDA seeds. Current slots use the creator's slot index; slots created before
+// the counter existed were seeded by start_ts and can still be located that way.
+pub fn slot_address(profile: &Pubkey, index: u64) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[b"slot", profile.as_ref(), &index.to_le_bytes()], &crate::ID)
+}
+
+pub fn legacy_slot_address(profile: &Pubkey, start_ts: i64) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[b"slot", profile.as_ref(), &start_ts.to_le_bytes()], &crate::ID)
+}
+
 pub fn t0_ts(slot: &TimeSlot) -> i64 {
     match slot.mode {
         Mode::Stable => slot.start_ts,
@@ -485,12 +1094,56 @@ pub fn t0_ts(slot: &TimeSlot) -> i64 {
     }
 }
 
+// Slots published with a schedule only sell from publish_at on
+pub fn check_on_sale(slot: &TimeSlot) -> Result<()> {
+    if let Some(at) = slot.publish_at {
+        require!(Clock::get()?.unix_timestamp >= at, ErrorCode::NotYetOnSale);
+    }
+    Ok(())
+}
+
+// Validate a cancellation policy: bounded length, bps in range, tiers strictly
+// ordered by lead time and refunds never increasing as the start approaches
+pub fn validate_cancel_policy(tiers: &[CancelTier]) -> Result<()> {
+    require!(tiers.len() <= MAX_CANCEL_TIERS, ErrorCode::InvalidCancelPolicy);
+    for (i, t) in tiers.iter().enumerate() {
+        require!(t.refund_bps as u64 <= BPS_DENOM, ErrorCode::InvalidBps);
+        if i > 0 {
+            let prev = &tiers[i - 1];
+            require!(t.secs_before_start < prev.secs_before_start, ErrorCode::InvalidCancelPolicy);
+            require!(t.refund_bps <= prev.refund_bps, ErrorCode::InvalidCancelPolicy);
+        }
+    }
+    Ok(())
+}
+
+// Refund share (bps) in effect at `now`; without a policy the buyer gets everything back
+pub fn cancel_refund_bps(slot: &TimeSlot, now: i64) -> u64 {
+    if slot.cancel_policy.is_empty() {
+        return BPS_DENOM;
+    }
+    let lead = slot.start_ts.saturating_sub(now);
+    slot.cancel_policy
+        .iter()
+        .find(|t| lead > t.secs_before_start as i64)
+        .map(|t| t.refund_bps as u64)
+        .unwrap_or(0)
+}
+
 // ===================== Events =====================
 
 #[event]
 pub struct ReservedEvent {
     pub slot: Pubkey,
     pub buyer: Pubkey,
+    pub payer: Pubkey,
+    pub amount: u64,
+}
+
+#[event]
+pub struct UsdPriceQuotedEvent {
+    pub slot: Pubkey,
+    pub usd_cents: u64,
     pub amount: u64,
 }
 
@@ -501,12 +1154,31 @@ pub struct RefundedEvent {
     pub amount: u64,
 }
 
+#[event]
+pub struct CancelledEvent {
+    pub slot: Pubkey,
+    pub buyer: Pubkey,
+    pub refund_bps: u16,
+    pub refunded: u64,
+    pub creator_amount: u64,
+    pub fee: u64,
+}
+
 #[event]
 pub struct CheckinEvent {
     pub slot: Pubkey,
     pub by: Pubkey,
 }
 
+#[event]
+pub struct AttendanceRecordedEvent {
+    pub creator_profile: Pubkey,
+    pub attendee: Pubkey,
+    pub slot: Pubkey,
+    pub sessions: u32,
+    pub seconds_attended: u64,
+}
+
 #[event]
 pub struct SettledT0Event {
     pub slot: Pubkey,
@@ -587,9 +1259,13 @@ pub struct RevealAcceptedEvent {
 
 #[derive(Accounts)]
 pub struct StableReserve<'info> {
+    /// Token authority for the price; refunds go back to this wallet
     pub buyer: Signer<'info>,
+    /// Pays rent for accounts created here; a sponsor may sign as payer for a buyer without SOL
+    #[account(mut)]
+    pub payer: Signer<'info>,
     pub platform: Account<'info, Platform>,
-    #[account(constraint = mint.key() == platform.mint)]
+    #[account(constraint = mint.key() == slot.mint)]
     pub mint: InterfaceAccount<'info, Mint>,
     #[account(mut)]
     pub slot: Account<'info, TimeSlot>,
@@ -599,18 +1275,45 @@ pub struct StableReserve<'info> {
         bump = escrow.bump
     )]
     pub escrow: Account<'info, Escrow>,
-    #[account(mut)]
+    #[account(mut, address = escrow.token_acc @ ErrorCode::InvalidEscrowVault)]
     pub escrow_vault: InterfaceAccount<'info, TokenAccount>,
     #[account(mut, constraint = buyer_token.owner == buyer.key() && buyer_token.mint == mint.key())]
     pub buyer_token: InterfaceAccount<'info, TokenAccount>,
     pub token_program: Interface<'info, TokenInterface>,
+    /// CHECK: Pyth-style price account; must match slot.usd_quote.price_feed (USD-priced slots only)
+    pub price_feed: Option<UncheckedAccount<'info>>,
+    /// CHECK: Only the key is recorded as the attendee; defaults to the paying buyer
+    pub beneficiary: Option<UncheckedAccount<'info>>,
+    // Ticket mode only: the ticket is minted to the beneficiary's token account
+    #[account(mut)]
+    pub nft_mint: Option<InterfaceAccount<'info, Mint>>,
+    #[account(mut)]
+    pub beneficiary_ticket: Option<InterfaceAccount<'info, TokenAccount>>,
+    /// CHECK: PDA authority to mint NFTs for this slot
+    #[account(seeds = [b"nft_auth", slot.key().as_ref()], bump)]
+    pub nft_auth: Option<UncheckedAccount<'info>>,
+    // Token-gated slots only: the beneficiary's token account (and its NFT metadata for collection gates)
+    pub gate_token: Option<InterfaceAccount<'info, TokenAccount>>,
+    /// CHECK: Metaplex metadata of gate_token's mint; owner and PDA are checked in gate::check_gate
+    pub gate_metadata: Option<UncheckedAccount<'info>>,
+    // Allowlist entries with a quantity cap: bookings made so far for the beneficiary
+    #[account(
+        init_if_needed,
+        payer = payer,
+        space = 8 + AllowlistClaim::LEN,
+        seeds = [b"allowlist", slot.key().as_ref(), beneficiary.as_ref().map_or(buyer.key(), |b| b.key()).as_ref()],
+        bump
+    )]
+    pub allowlist_claim: Option<Account<'info, AllowlistClaim>>,
+    pub system_program: Program<'info, System>,
 }
 
 #[derive(Accounts)]
 pub struct StableCancel<'info> {
+    #[account(mut)]
     pub buyer: Signer<'info>,
     pub platform: Account<'info, Platform>,
-    #[account(constraint = mint.key() == platform.mint)]
+    #[account(constraint = mint.key() == slot.mint)]
     pub mint: InterfaceAccount<'info, Mint>,
     #[account(mut)]
     pub slot: Account<'info, TimeSlot>,
@@ -620,11 +1323,47 @@ pub struct StableCancel<'info> {
         bump = escrow.bump
     )]
     pub escrow: Account<'info, Escrow>,
-    #[account(mut)]
+    #[account(mut, address = escrow.token_acc @ ErrorCode::InvalidEscrowVault)]
     pub escrow_vault: InterfaceAccount<'info, TokenAccount>,
-    #[account(mut, constraint = buyer_token.owner == buyer.key() && buyer_token.mint == mint.key())]
+    /// Refund lands with whoever paid for the booking
+    #[account(
+        mut,
+        constraint = Some(buyer_token.owner) == escrow.refund_to() @ ErrorCode::UnauthorizedBuyer,
+        constraint = buyer_token.mint == mint.key()
+    )]
     pub buyer_token: InterfaceAccount<'info, TokenAccount>,
+    /// Creator receives the retained share under the cancellation policy
+    #[account(
+        seeds = [b"creator", slot.creator_authority.as_ref(), platform.key().as_ref()],
+        bump = profile.bump
+    )]
+    pub profile: Account<'info, CreatorProfile>,
+    #[account(address = profile.payout_wallet)]
+    /// CHECK: Address is constrained to `profile.payout_wallet` above.
+    /// No further data access is performed on this account.
+    pub profile_payout_wallet: UncheckedAccount<'info>,
+    #[account(
+        init_if_needed,
+        payer = buyer,
+        associated_token::mint = mint,
+        associated_token::authority = profile_payout_wallet,
+        associated_token::token_program = token_program
+    )]
+    pub creator_payout_ata: InterfaceAccount<'info, TokenAccount>,
+    /// Registry entry for the slot's mint (fee and dispute vaults)
+    #[account(seeds = [b"accepted_mint", platform.key().as_ref(), slot.mint.as_ref()], bump = accepted_mint.bump)]
+    pub accepted_mint: Account<'info, AcceptedMint>,
+    /// Fee vault (platform fees)
+    #[account(mut, address = accepted_mint.fee_vault)]
+    pub fee_vault: InterfaceAccount<'info, TokenAccount>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
     pub token_program: Interface<'info, TokenInterface>,
+    pub system_program: Program<'info, System>,
+    // Ticket mode only: the canceller hands back (burns) the ticket
+    #[account(mut)]
+    pub nft_mint: Option<InterfaceAccount<'info, Mint>>,
+    #[account(mut)]
+    pub ticket: Option<InterfaceAccount<'info, TokenAccount>>,
 }
 
 #[derive(Accounts)]
@@ -632,7 +1371,7 @@ pub struct StableCheckin<'info> {
     #[account(mut)]
     pub signer: Signer<'info>, // buyer or creator authority
     pub platform: Account<'info, Platform>,
-    #[account(constraint = mint.key() == platform.mint)]
+    #[account(constraint = slot.payment_kind == PaymentKind::Sol || mint.key() == slot.mint)]
     pub mint: InterfaceAccount<'info, Mint>,
     #[account(mut)]
     pub slot: Account<'info, TimeSlot>,
@@ -644,12 +1383,15 @@ pub struct StableCheckin<'info> {
     // Optional NFT minting if slot.nft_mint != default
     #[account(mut)]
     pub nft_mint: InterfaceAccount<'info, Mint>,
-    /// Buyer will receive the NFT upon check-in
+    /// CHECK: Address is constrained to the escrow's beneficiary; only owns the NFT ATA
+    #[account(address = escrow.buyer.unwrap_or_default() @ ErrorCode::NotReserved)]
+    pub beneficiary: UncheckedAccount<'info>,
+    /// Beneficiary will receive the NFT upon check-in
     #[account(
         init_if_needed,
         payer = signer,
         associated_token::mint = nft_mint,
-        associated_token::authority = signer,
+        associated_token::authority = beneficiary,
         associated_token::token_program = token_program
     )]
     pub buyer_nft_ata: InterfaceAccount<'info, TokenAccount>,
@@ -660,6 +1402,19 @@ pub struct StableCheckin<'info> {
     pub associated_token_program: Program<'info, AssociatedToken>,
     pub token_program: Interface<'info, TokenInterface>,
     pub system_program: Program<'info, System>,
+    /// Ticket mode only: signer's ticket, burned on check-in
+    #[account(mut)]
+    pub ticket: Option<InterfaceAccount<'info, TokenAccount>>,
+    /// CHECK: Checked in the handler against the booking's attendee
+    pub attendee: UncheckedAccount<'info>,
+    #[account(
+        init_if_needed,
+        payer = signer,
+        space = 8 + Attendance::LEN,
+        seeds = [b"attendance", slot.creator_profile.as_ref(), attendee.key().as_ref()],
+        bump
+    )]
+    pub attendance: Account<'info, Attendance>,
 }
 
 #[derive(Accounts)]
@@ -668,7 +1423,7 @@ pub struct StableSettle<'info> {
     #[account(mut)]
     pub authority: Signer<'info>,
     pub platform: Account<'info, Platform>,
-    #[account(constraint = mint.key() == platform.mint)]
+    #[account(constraint = mint.key() == slot.mint)]
     pub mint: InterfaceAccount<'info, Mint>,
     #[account(mut)]
     pub slot: Account<'info, TimeSlot>,
@@ -678,7 +1433,7 @@ pub struct StableSettle<'info> {
         bump = escrow.bump
     )]
     pub escrow: Account<'info, Escrow>,
-    #[account(mut)]
+    #[account(mut, address = escrow.token_acc @ ErrorCode::InvalidEscrowVault)]
     pub escrow_vault: InterfaceAccount<'info, TokenAccount>,
     /// Creator payout ATA; will be created if missing
     /// NOTE: payout_wallet is stored on profile
@@ -700,22 +1455,55 @@ pub struct StableSettle<'info> {
         associated_token::token_program = token_program
     )]
     pub creator_payout_ata: InterfaceAccount<'info, TokenAccount>,
+    /// Registry entry for the slot's mint (fee and dispute vaults)
+    #[account(seeds = [b"accepted_mint", platform.key().as_ref(), slot.mint.as_ref()], bump = accepted_mint.bump)]
+    pub accepted_mint: Account<'info, AcceptedMint>,
     /// Fee vault (platform fees)
-    #[account(mut, address = platform.fee_vault)]
+    #[account(mut, address = accepted_mint.fee_vault)]
     pub fee_vault: InterfaceAccount<'info, TokenAccount>,
     /// Dispute vault (retained policy holds)
-    #[account(mut, address = platform.dispute_vault)]
+    #[account(mut, address = accepted_mint.dispute_vault)]
     pub dispute_vault: InterfaceAccount<'info, TokenAccount>,
     pub associated_token_program: Program<'info, AssociatedToken>,
     pub token_program: Interface<'info, TokenInterface>,
     pub system_program: Program<'info, System>,
 }
 
+#[derive(Accounts)]
+#[instruction(new_holder: Pubkey)]
+pub struct TransferReservation<'info> {
+    /// Current holder (escrow.buyer)
+    #[account(mut)]
+    pub holder: Signer<'info>,
+    pub slot: Account<'info, TimeSlot>,
+    #[account(mut, seeds = [b"escrow", slot.key().as_ref()], bump = escrow.bump)]
+    pub escrow: Account<'info, Escrow>,
+    // Token-gated slots only: the new holder's token account (and its NFT metadata for collection gates)
+    pub gate_token: Option<InterfaceAccount<'info, TokenAccount>>,
+    /// CHECK: Metaplex metadata of gate_token's mint; owner and PDA are checked in gate::check_gate
+    pub gate_metadata: Option<UncheckedAccount<'info>>,
+    // Allowlist entries with a quantity cap: bookings held so far by the new holder
+    #[account(
+        init_if_needed,
+        payer = holder,
+        space = 8 + AllowlistClaim::LEN,
+        seeds = [b"allowlist", slot.key().as_ref(), new_holder.as_ref()],
+        bump
+    )]
+    pub allowlist_claim: Option<Account<'info, AllowlistClaim>>,
+    pub system_program: Program<'info, System>,
+}
+
 // ===================== Stable SOL Accounts (MVP) =====================
 
 #[derive(Accounts)]
 pub struct StableReserveSol<'info> {
+    /// Funds the price; refunds go back to this wallet
+    #[account(mut)]
     pub buyer: Signer<'info>,
+    /// Pays rent for accounts created here; a sponsor may sign as payer for a buyer without SOL
+    #[account(mut)]
+    pub payer: Signer<'info>,
     pub platform: Account<'info, Platform>,
     #[account(mut)]
     pub slot: Account<'info, TimeSlot>,
@@ -726,10 +1514,26 @@ pub struct StableReserveSol<'info> {
     )]
     pub escrow: Account<'info, Escrow>,
     pub system_program: Program<'info, System>,
+    /// CHECK: Only the key is recorded as the attendee; defaults to the paying buyer
+    pub beneficiary: Option<UncheckedAccount<'info>>,
+    // Token-gated slots only: the beneficiary's token account (and its NFT metadata for collection gates)
+    pub gate_token: Option<InterfaceAccount<'info, TokenAccount>>,
+    /// CHECK: Metaplex metadata of gate_token's mint; owner and PDA are checked in gate::check_gate
+    pub gate_metadata: Option<UncheckedAccount<'info>>,
+    // Allowlist entries with a quantity cap: bookings made so far for the beneficiary
+    #[account(
+        init_if_needed,
+        payer = payer,
+        space = 8 + AllowlistClaim::LEN,
+        seeds = [b"allowlist", slot.key().as_ref(), beneficiary.as_ref().map_or(buyer.key(), |b| b.key()).as_ref()],
+        bump
+    )]
+    pub allowlist_claim: Option<Account<'info, AllowlistClaim>>,
 }
 
 #[derive(Accounts)]
 pub struct StableCancelSol<'info> {
+    #[account(mut)]
     pub buyer: Signer<'info>,
     pub platform: Account<'info, Platform>,
     #[account(mut)]
@@ -740,6 +1544,22 @@ pub struct StableCancelSol<'info> {
         bump = escrow.bump
     )]
     pub escrow: Account<'info, Escrow>,
+    #[account(
+        seeds = [b"creator", slot.creator_authority.as_ref(), platform.key().as_ref()],
+        bump = profile.bump
+    )]
+    pub profile: Account<'info, CreatorProfile>,
+    #[account(mut, address = profile.payout_wallet)]
+    pub creator_payout: SystemAccount<'info>,
+    /// Platform's configured SOL fee and dispute wallets
+    #[account(seeds = [b"sol_receivers", slot.platform.as_ref()], bump = sol_receivers.bump)]
+    pub sol_receivers: Account<'info, SolReceivers>,
+    /// SOL fee receiver (platform)
+    #[account(mut, address = sol_receivers.fee_receiver @ ErrorCode::InvalidSolReceiver)]
+    pub fee_receiver: SystemAccount<'info>,
+    /// Wallet that paid for the booking; receives the refund
+    #[account(mut, address = escrow.refund_to().unwrap_or_default() @ ErrorCode::UnauthorizedBuyer)]
+    pub refund_receiver: SystemAccount<'info>,
     pub system_program: Program<'info, System>,
 }
 
@@ -764,11 +1584,14 @@ pub struct StableSettleSol<'info> {
     pub profile: Account<'info, CreatorProfile>,
     #[account(mut, address = profile.payout_wallet)]
     pub creator_payout: SystemAccount<'info>,
+    /// Platform's configured SOL fee and dispute wallets
+    #[account(seeds = [b"sol_receivers", slot.platform.as_ref()], bump = sol_receivers.bump)]
+    pub sol_receivers: Account<'info, SolReceivers>,
     /// SOL fee receiver (platform)
-    #[account(mut)]
+    #[account(mut, address = sol_receivers.fee_receiver @ ErrorCode::InvalidSolReceiver)]
     pub fee_receiver: SystemAccount<'info>,
     /// SOL retained/withhold receiver (dispute)
-    #[account(mut)]
+    #[account(mut, address = sol_receivers.dispute_receiver @ ErrorCode::InvalidSolReceiver)]
     pub dispute_receiver: SystemAccount<'info>,
     pub system_program: Program<'info, System>,
 }
@@ -779,7 +1602,7 @@ pub struct StableSettleSol<'info> {
 pub struct AuctionStart<'info> {
     pub creator: Signer<'info>,
     pub platform: Account<'info, Platform>,
-    #[account(constraint = mint.key() == platform.mint)]
+    #[account(constraint = slot.payment_kind == PaymentKind::Sol || mint.key() == slot.mint)]
     pub mint: InterfaceAccount<'info, Mint>,
     #[account(mut)]
     pub slot: Account<'info, TimeSlot>,
@@ -792,7 +1615,7 @@ pub struct AuctionStart<'info> {
 pub struct InitAutoBidStore<'info> {
     pub authority: Signer<'info>,
     pub platform: Account<'info, Platform>,
-    #[account(constraint = mint.key() == platform.mint)]
+    #[account(constraint = slot.payment_kind == PaymentKind::Sol || mint.key() == slot.mint)]
     pub mint: InterfaceAccount<'info, Mint>,
     #[account(mut)]
     pub slot: Account<'info, TimeSlot>,
@@ -809,9 +1632,10 @@ pub struct InitAutoBidStore<'info> {
 
 #[derive(Accounts)]
 pub struct BidPlace<'info> {
+    // Creates no accounts, so a sponsor only has to be the transaction fee payer
     pub bidder: Signer<'info>,
     pub platform: Account<'info, Platform>,
-    #[account(constraint = mint.key() == platform.mint)]
+    #[account(constraint = mint.key() == slot.mint)]
     pub mint: InterfaceAccount<'info, Mint>,
     #[account(mut)]
     pub slot: Account<'info, TimeSlot>,
@@ -823,17 +1647,23 @@ pub struct BidPlace<'info> {
     pub refund_queue: Account<'info, RefundQueue>,
     #[account(mut, seeds = [b"autobid", slot.key().as_ref()], bump = auto_bid_store.bump)]
     pub auto_bid_store: Account<'info, AutoBidStore>,
-    #[account(mut)]
+    #[account(mut, address = escrow.token_acc @ ErrorCode::InvalidEscrowVault)]
     pub escrow_vault: InterfaceAccount<'info, TokenAccount>,
     #[account(mut, constraint = bidder_token.owner == bidder.key() && bidder_token.mint == mint.key())]
     pub bidder_token: InterfaceAccount<'info, TokenAccount>,
     pub token_program: Interface<'info, TokenInterface>,
+    /// CHECK: Only the key is recorded as the attendee if this bid wins; defaults to the bidder
+    pub beneficiary: Option<UncheckedAccount<'info>>,
+    // Token-gated slots only: the beneficiary's token account (and its NFT metadata for collection gates)
+    pub gate_token: Option<InterfaceAccount<'info, TokenAccount>>,
+    /// CHECK: Metaplex metadata of gate_token's mint; owner and PDA are checked in gate::check_gate
+    pub gate_metadata: Option<UncheckedAccount<'info>>,
 }
 
 #[derive(Accounts)]
 pub struct BidOutbidRefund<'info> {
     pub platform: Account<'info, Platform>,
-    #[account(constraint = mint.key() == platform.mint)]
+    #[account(constraint = mint.key() == slot.mint)]
     pub mint: InterfaceAccount<'info, Mint>,
     #[account(mut)]
     pub slot: Account<'info, TimeSlot>,
@@ -843,7 +1673,7 @@ pub struct BidOutbidRefund<'info> {
     pub escrow: Account<'info, Escrow>,
     #[account(mut, seeds = [b"refund", slot.key().as_ref()], bump = refund_queue.bump)]
     pub refund_queue: Account<'info, RefundQueue>,
-    #[account(mut)]
+    #[account(mut, address = escrow.token_acc @ ErrorCode::InvalidEscrowVault)]
     pub escrow_vault: InterfaceAccount<'info, TokenAccount>,
     /// CHECK: just used for key check
     pub prev_bidder: UncheckedAccount<'info>,
@@ -857,7 +1687,7 @@ pub struct AuctionEnd<'info> {
     #[account(mut)]
     pub authority: Signer<'info>,
     pub platform: Account<'info, Platform>,
-    #[account(constraint = mint.key() == platform.mint)]
+    #[account(constraint = mint.key() == slot.mint)]
     pub mint: InterfaceAccount<'info, Mint>,
     #[account(mut)]
     pub slot: Account<'info, TimeSlot>,
@@ -865,7 +1695,7 @@ pub struct AuctionEnd<'info> {
     pub bidbook: Account<'info, BidBook>,
     #[account(mut, seeds = [b"escrow", slot.key().as_ref()], bump = escrow.bump)]
     pub escrow: Account<'info, Escrow>,
-    #[account(mut)]
+    #[account(mut, address = escrow.token_acc @ ErrorCode::InvalidEscrowVault)]
     pub escrow_vault: InterfaceAccount<'info, TokenAccount>,
     #[account(
         mut,
@@ -885,8 +1715,11 @@ pub struct AuctionEnd<'info> {
         associated_token::token_program = token_program
     )]
     pub creator_payout_ata: InterfaceAccount<'info, TokenAccount>,
+    /// Registry entry for the slot's mint (fee and dispute vaults)
+    #[account(seeds = [b"accepted_mint", platform.key().as_ref(), slot.mint.as_ref()], bump = accepted_mint.bump)]
+    pub accepted_mint: Account<'info, AcceptedMint>,
     /// Fee vault (platform fees)
-    #[account(mut, address = platform.fee_vault)]
+    #[account(mut, address = accepted_mint.fee_vault)]
     pub fee_vault: InterfaceAccount<'info, TokenAccount>,
     pub associated_token_program: Program<'info, AssociatedToken>,
     pub token_program: Interface<'info, TokenInterface>,
@@ -905,13 +1738,20 @@ pub struct AuctionEnd<'info> {
     /// CHECK: PDA authority to mint NFTs for this slot
     #[account(seeds = [b"nft_auth", slot.key().as_ref()], bump)]
     pub nft_auth: UncheckedAccount<'info>,
+    /// Ticket mode only: winning beneficiary's token account that receives the ticket
+    #[account(mut)]
+    pub winner_ticket: Option<InterfaceAccount<'info, TokenAccount>>,
 }
 
 #[derive(Accounts)]
 pub struct BuyNow<'info> {
+    /// Token authority for the price; refunds go back to this wallet
     pub bidder: Signer<'info>,
+    /// Pays rent for accounts created here; a sponsor may sign as payer for a buyer without SOL
+    #[account(mut)]
+    pub payer: Signer<'info>,
     pub platform: Account<'info, Platform>,
-    #[account(constraint = mint.key() == platform.mint)]
+    #[account(constraint = mint.key() == slot.mint)]
     pub mint: InterfaceAccount<'info, Mint>,
     #[account(mut)]
     pub slot: Account<'info, TimeSlot>,
@@ -919,7 +1759,7 @@ pub struct BuyNow<'info> {
     pub bidbook: Account<'info, BidBook>,
     #[account(mut, seeds = [b"escrow", slot.key().as_ref()], bump = escrow.bump)]
     pub escrow: Account<'info, Escrow>,
-    #[account(mut)]
+    #[account(mut, address = escrow.token_acc @ ErrorCode::InvalidEscrowVault)]
     pub escrow_vault: InterfaceAccount<'info, TokenAccount>,
     #[account(mut, constraint = bidder_token.owner == bidder.key() && bidder_token.mint == mint.key())]
     pub bidder_token: InterfaceAccount<'info, TokenAccount>,
@@ -934,32 +1774,53 @@ pub struct BuyNow<'info> {
     pub profile_payout_wallet: UncheckedAccount<'info>,
     #[account(
         init_if_needed,
-        payer = bidder,
+        payer = payer,
         associated_token::mint = mint,
         associated_token::authority = profile_payout_wallet,
         associated_token::token_program = token_program
     )]
     pub creator_payout_ata: InterfaceAccount<'info, TokenAccount>,
+    /// Registry entry for the slot's mint (fee and dispute vaults)
+    #[account(seeds = [b"accepted_mint", platform.key().as_ref(), slot.mint.as_ref()], bump = accepted_mint.bump)]
+    pub accepted_mint: Account<'info, AcceptedMint>,
     /// Fee vault (platform fees)
-    #[account(mut, address = platform.fee_vault)]
+    #[account(mut, address = accepted_mint.fee_vault)]
     pub fee_vault: InterfaceAccount<'info, TokenAccount>,
     pub associated_token_program: Program<'info, AssociatedToken>,
     pub token_program: Interface<'info, TokenInterface>,
     pub system_program: Program<'info, System>,
+    /// CHECK: Only the key is recorded as the attendee; pass the bidder to buy for themselves.
+    /// Required rather than optional because the NFT account below is derived from it.
+    pub beneficiary: UncheckedAccount<'info>,
     // NFT minting
     #[account(mut)]
     pub nft_mint: InterfaceAccount<'info, Mint>,
     #[account(
         init_if_needed,
-        payer = bidder,
+        payer = payer,
         associated_token::mint = nft_mint,
-        associated_token::authority = bidder,
+        associated_token::authority = beneficiary,
         associated_token::token_program = token_program
     )]
     pub buyer_nft_ata: InterfaceAccount<'info, TokenAccount>,
     /// CHECK: PDA authority to mint NFTs for this slot
     #[account(seeds = [b"nft_auth", slot.key().as_ref()], bump)]
     pub nft_auth: UncheckedAccount<'info>,
+    /// CHECK: Pyth-style price account; must match slot.usd_quote.price_feed (USD-priced slots only)
+    pub price_feed: Option<UncheckedAccount<'info>>,
+    // Token-gated slots only: the beneficiary's token account (and its NFT metadata for collection gates)
+    pub gate_token: Option<InterfaceAccount<'info, TokenAccount>>,
+    /// CHECK: Metaplex metadata of gate_token's mint; owner and PDA are checked in gate::check_gate
+    pub gate_metadata: Option<UncheckedAccount<'info>>,
+    // Allowlist entries with a quantity cap: bookings made so far for the beneficiary
+    #[account(
+        init_if_needed,
+        payer = payer,
+        space = 8 + AllowlistClaim::LEN,
+        seeds = [b"allowlist", slot.key().as_ref(), beneficiary.key().as_ref()],
+        bump
+    )]
+    pub allowlist_claim: Option<Account<'info, AllowlistClaim>>,
 }
 
 #[derive(Accounts)]
@@ -967,19 +1828,36 @@ pub struct AuctionCheckin<'info> {
     #[account(mut)]
     pub signer: Signer<'info>, // winner or creator
     pub platform: Account<'info, Platform>,
-    #[account(constraint = mint.key() == platform.mint)]
+    #[account(constraint = slot.payment_kind == PaymentKind::Sol || mint.key() == slot.mint)]
     pub mint: InterfaceAccount<'info, Mint>,
     #[account(mut)]
     pub slot: Account<'info, TimeSlot>,
     #[account(seeds = [b"escrow", slot.key().as_ref()], bump = escrow.bump)]
     pub escrow: Account<'info, Escrow>,
+    // Ticket mode only: signer's ticket, burned on check-in
+    #[account(mut)]
+    pub nft_mint: Option<InterfaceAccount<'info, Mint>>,
+    #[account(mut)]
+    pub ticket: Option<InterfaceAccount<'info, TokenAccount>>,
+    pub token_program: Option<Interface<'info, TokenInterface>>,
+    /// CHECK: Checked in the handler against the booking's attendee
+    pub attendee: UncheckedAccount<'info>,
+    #[account(
+        init_if_needed,
+        payer = signer,
+        space = 8 + Attendance::LEN,
+        seeds = [b"attendance", slot.creator_profile.as_ref(), attendee.key().as_ref()],
+        bump
+    )]
+    pub attendance: Account<'info, Attendance>,
+    pub system_program: Program<'info, System>,
 }
 
 #[derive(Accounts)]
 pub struct AuctionUpdateEnd<'info> {
     pub creator: Signer<'info>,
     pub platform: Account<'info, Platform>,
-    #[account(constraint = mint.key() == platform.mint)]
+    #[account(constraint = slot.payment_kind == PaymentKind::Sol || mint.key() == slot.mint)]
     pub mint: InterfaceAccount<'info, Mint>,
     #[account(mut, has_one = creator_authority @ ErrorCode::Unauthorized)]
     pub slot: Account<'info, TimeSlot>,
@@ -991,13 +1869,13 @@ pub struct SealedAuctionEnd<'info> {
     #[account(mut)]
     pub authority: Signer<'info>,
     pub platform: Account<'info, Platform>,
-    #[account(constraint = mint.key() == platform.mint)]
+    #[account(constraint = mint.key() == slot.mint)]
     pub mint: InterfaceAccount<'info, Mint>,
     #[account(mut)]
     pub slot: Account<'info, TimeSlot>,
     #[account(mut, seeds = [b"escrow", slot.key().as_ref()], bump = escrow.bump)]
     pub escrow: Account<'info, Escrow>,
-    #[account(mut)]
+    #[account(mut, address = escrow.token_acc @ ErrorCode::InvalidEscrowVault)]
     pub escrow_vault: InterfaceAccount<'info, TokenAccount>,
     #[account(mut, seeds = [b"commit", slot.key().as_ref()], bump = commit_store.bump)]
     pub commit_store: Account<'info, CommitStore>,
@@ -1018,8 +1896,11 @@ pub struct SealedAuctionEnd<'info> {
         associated_token::token_program = token_program
     )]
     pub creator_payout_ata: InterfaceAccount<'info, TokenAccount>,
+    /// Registry entry for the slot's mint (fee and dispute vaults)
+    #[account(seeds = [b"accepted_mint", platform.key().as_ref(), slot.mint.as_ref()], bump = accepted_mint.bump)]
+    pub accepted_mint: Account<'info, AcceptedMint>,
     /// Fee vault (platform fees)
-    #[account(mut, address = platform.fee_vault)]
+    #[account(mut, address = accepted_mint.fee_vault)]
     pub fee_vault: InterfaceAccount<'info, TokenAccount>,
     pub associated_token_program: Program<'info, AssociatedToken>,
     pub token_program: Interface<'info, TokenInterface>,
@@ -1046,13 +1927,13 @@ pub struct SealedAuctionSettle<'info> {
     #[account(mut)]
     pub authority: Signer<'info>,
     pub platform: Account<'info, Platform>,
-    #[account(constraint = mint.key() == platform.mint)]
+    #[account(constraint = mint.key() == slot.mint)]
     pub mint: InterfaceAccount<'info, Mint>,
     #[account(mut)]
     pub slot: Account<'info, TimeSlot>,
     #[account(mut, seeds = [b"escrow", slot.key().as_ref()], bump = escrow.bump)]
     pub escrow: Account<'info, Escrow>,
-    #[account(mut)]
+    #[account(mut, address = escrow.token_acc @ ErrorCode::InvalidEscrowVault)]
     pub escrow_vault: InterfaceAccount<'info, TokenAccount>,
     #[account(
         mut,
@@ -1071,11 +1952,14 @@ pub struct SealedAuctionSettle<'info> {
         associated_token::token_program = token_program
     )]
     pub creator_payout_ata: InterfaceAccount<'info, TokenAccount>,
+    /// Registry entry for the slot's mint (fee and dispute vaults)
+    #[account(seeds = [b"accepted_mint", platform.key().as_ref(), slot.mint.as_ref()], bump = accepted_mint.bump)]
+    pub accepted_mint: Account<'info, AcceptedMint>,
     /// Fee vault (platform fees)
-    #[account(mut, address = platform.fee_vault)]
+    #[account(mut, address = accepted_mint.fee_vault)]
     pub fee_vault: InterfaceAccount<'info, TokenAccount>,
     /// Dispute vault (retained policy holds)
-    #[account(mut, address = platform.dispute_vault)]
+    #[account(mut, address = accepted_mint.dispute_vault)]
     pub dispute_vault: InterfaceAccount<'info, TokenAccount>,
     pub associated_token_program: Program<'info, AssociatedToken>,
     pub token_program: Interface<'info, TokenInterface>,
@@ -1086,7 +1970,7 @@ pub struct AuctionSettle<'info> {
     #[account(mut)]
     pub authority: Signer<'info>,
     pub platform: Account<'info, Platform>,
-    #[account(constraint = mint.key() == platform.mint)]
+    #[account(constraint = mint.key() == slot.mint)]
     pub mint: InterfaceAccount<'info, Mint>,
     #[account(mut)]
     pub slot: Account<'info, TimeSlot>,
@@ -1094,7 +1978,7 @@ pub struct AuctionSettle<'info> {
     pub bidbook: Account<'info, BidBook>,
     #[account(mut, seeds = [b"escrow", slot.key().as_ref()], bump = escrow.bump)]
     pub escrow: Account<'info, Escrow>,
-    #[account(mut)]
+    #[account(mut, address = escrow.token_acc @ ErrorCode::InvalidEscrowVault)]
     pub escrow_vault: InterfaceAccount<'info, TokenAccount>,
     #[account(
         mut,
@@ -1114,11 +1998,14 @@ pub struct AuctionSettle<'info> {
         associated_token::token_program = token_program
     )]
     pub creator_payout_ata: InterfaceAccount<'info, TokenAccount>,
+    /// Registry entry for the slot's mint (fee and dispute vaults)
+    #[account(seeds = [b"accepted_mint", platform.key().as_ref(), slot.mint.as_ref()], bump = accepted_mint.bump)]
+    pub accepted_mint: Account<'info, AcceptedMint>,
     /// Fee vault (platform fees)
-    #[account(mut, address = platform.fee_vault)]
+    #[account(mut, address = accepted_mint.fee_vault)]
     pub fee_vault: InterfaceAccount<'info, TokenAccount>,
     /// Dispute vault (retained policy holds)
-    #[account(mut, address = platform.dispute_vault)]
+    #[account(mut, address = accepted_mint.dispute_vault)]
     pub dispute_vault: InterfaceAccount<'info, TokenAccount>,
     pub associated_token_program: Program<'info, AssociatedToken>,
     pub token_program: Interface<'info, TokenInterface>,
@@ -1129,12 +2016,14 @@ pub struct AuctionSettle<'info> {
 pub struct RaiseDispute<'info> {
     pub raiser: Signer<'info>,
     pub platform: Account<'info, Platform>,
-    #[account(constraint = mint.key() == platform.mint)]
+    #[account(constraint = slot.payment_kind == PaymentKind::Sol || mint.key() == slot.mint)]
     pub mint: InterfaceAccount<'info, Mint>,
     #[account(mut)]
     pub slot: Account<'info, TimeSlot>,
     #[account(seeds = [b"escrow", slot.key().as_ref()], bump = escrow.bump)]
     pub escrow: Account<'info, Escrow>,
+    /// Ticket mode only: raiser's ticket proving holder rights
+    pub ticket: Option<InterfaceAccount<'info, TokenAccount>>,
 }
 
 #[derive(Accounts)]
@@ -1143,13 +2032,13 @@ pub struct ResolveDispute<'info> {
     pub admin: Signer<'info>,
     #[account(has_one = admin)]
     pub platform: Account<'info, Platform>,
-    #[account(constraint = mint.key() == platform.mint)]
+    #[account(constraint = mint.key() == slot.mint)]
     pub mint: InterfaceAccount<'info, Mint>,
     #[account(mut)]
     pub slot: Account<'info, TimeSlot>,
     #[account(mut, seeds = [b"escrow", slot.key().as_ref()], bump = escrow.bump)]
     pub escrow: Account<'info, Escrow>,
-    #[account(mut)]
+    #[account(mut, address = escrow.token_acc @ ErrorCode::InvalidEscrowVault)]
     pub escrow_vault: InterfaceAccount<'info, TokenAccount>,
     #[account(
         mut,
@@ -1169,8 +2058,8 @@ pub struct ResolveDispute<'info> {
         associated_token::token_program = token_program
     )]
     pub creator_payout_ata: InterfaceAccount<'info, TokenAccount>,
-    /// Buyer token account to receive refund portion
-    #[account(mut)]
+    /// Payer token account to receive refund portion
+    #[account(mut, constraint = Some(buyer_token.owner) == escrow.refund_to() @ ErrorCode::UnauthorizedBuyer)]
     pub buyer_token: InterfaceAccount<'info, TokenAccount>,
     pub associated_token_program: Program<'info, AssociatedToken>,
     pub token_program: Interface<'info, TokenInterface>,
@@ -1183,15 +2072,15 @@ pub struct CloseSlot<'info> {
     #[account(mut)]
     pub authority: Signer<'info>, // creator authority or platform admin
     pub platform: Account<'info, Platform>,
-    #[account(constraint = mint.key() == platform.mint)]
+    #[account(constraint = mint.key() == slot.mint)]
     pub mint: InterfaceAccount<'info, Mint>,
     #[account(mut)]
     pub slot: Account<'info, TimeSlot>,
     #[account(mut, seeds = [b"escrow", slot.key().as_ref()], bump = escrow.bump)]
     pub escrow: Account<'info, Escrow>,
-    #[account(mut)]
+    #[account(mut, address = escrow.token_acc @ ErrorCode::InvalidEscrowVault)]
     pub escrow_vault: InterfaceAccount<'info, TokenAccount>,
-    /// Buyer token account to receive refund if any
+    /// Payer token account to receive refund if any
     #[account(mut, constraint = buyer_token.mint == mint.key())]
     pub buyer_token: InterfaceAccount<'info, TokenAccount>,
     pub token_program: Interface<'info, TokenInterface>,
@@ -1206,7 +2095,7 @@ pub struct CloseSlotSol<'info> {
     pub slot: Account<'info, TimeSlot>,
     #[account(mut, seeds = [b"escrow", slot.key().as_ref()], bump = escrow.bump)]
     pub escrow: Account<'info, Escrow>,
-    /// Buyer system account to receive lamports refund (if any)
+    /// Payer system account to receive lamports refund (if any)
     #[account(mut)]
     pub buyer: SystemAccount<'info>,
     pub system_program: Program<'info, System>,
@@ -1243,6 +2132,94 @@ pub mod timemarket {
         market::create_time_slot(ctx, params)
     }
 
+    pub fn init_slot_nft(ctx: Context<InitSlotNft>, params: SlotNftParams) -> Result<()> {
+        nft::init_slot_nft(ctx, params)
+    }
+
+    pub fn set_allowlist_root(ctx: Context<SetAllowlistRoot>, root: Option<[u8; 32]>) -> Result<()> {
+        allowlist::set_allowlist_root(ctx, root)
+    }
+
+    pub fn update_draft_slot(ctx: Context<UpdateDraftSlot>, params: UpdateDraftParams) -> Result<()> {
+        draft::update_draft_slot(ctx, params)
+    }
+
+    pub fn publish_slot(ctx: Context<PublishSlot>, publish_at: Option<i64>) -> Result<()> {
+        draft::publish_slot(ctx, publish_at)
+    }
+
+    pub fn update_slot(ctx: Context<UpdateSlot>, params: UpdateSlotParams) -> Result<()> {
+        market::update_slot(ctx, params)
+    }
+
+    pub fn set_hold_config(
+        ctx: Context<SetHoldConfig>,
+        max_hold_sec: u32,
+        deposit_lamports: u64,
+        cooldown_sec: u32,
+    ) -> Result<()> {
+        hold::set_hold_config(ctx, max_hold_sec, deposit_lamports, cooldown_sec)
+    }
+
+    pub fn hold_slot(ctx: Context<HoldSlot>, duration_sec: u32) -> Result<()> {
+        hold::hold_slot(ctx, duration_sec)
+    }
+
+    pub fn release_hold(ctx: Context<ReleaseHold>) -> Result<()> {
+        hold::release_hold(ctx)
+    }
+
+    pub fn migrate_account(ctx: Context<MigrateAccount>) -> Result<()> {
+        migrate::migrate_account(ctx)
+    }
+
+    pub fn migrate_slot(ctx: Context<MigrateSlot>, payment_kind: PaymentKind) -> Result<()> {
+        migrate::migrate_slot(ctx, payment_kind)
+    }
+
+    pub fn request_booking<'info>(
+        ctx: Context<'_, '_, 'info, 'info, RequestBooking<'info>>,
+        message_hash: Option<[u8; 32]>,
+        allowlist: Option<AllowlistProof>,
+    ) -> Result<()> {
+        booking::request_booking(ctx, message_hash, allowlist)
+    }
+
+    pub fn request_booking_sol(
+        ctx: Context<RequestBookingSol>,
+        message_hash: Option<[u8; 32]>,
+        allowlist: Option<AllowlistProof>,
+    ) -> Result<()> {
+        booking::request_booking_sol(ctx, message_hash, allowlist)
+    }
+
+    pub fn approve_booking<'info>(ctx: Context<'_, '_, 'info, 'info, ApproveBooking<'info>>, superseded: u8) -> Result<()> {
+        booking::approve_booking(ctx, superseded)
+    }
+
+    pub fn approve_booking_sol<'info>(ctx: Context<'_, '_, 'info, 'info, ApproveBookingSol<'info>>) -> Result<()> {
+        booking::approve_booking_sol(ctx)
+    }
+
+    pub fn decline_booking(ctx: Context<DeclineBooking>) -> Result<()> {
+        booking::decline_booking(ctx)
+    }
+
+    pub fn refund_booking_request<'info>(ctx: Context<'_, '_, 'info, 'info, RefundBookingRequest<'info>>) -> Result<()> {
+        booking::refund_booking_request(ctx)
+    }
+
+    pub fn refund_booking_request_sol(ctx: Context<RefundBookingRequestSol>) -> Result<()> {
+        booking::refund_booking_request_sol(ctx)
+    }
+
+    pub fn create_time_slots_batch<'info>(
+        ctx: Context<'_, '_, 'info, 'info, CreateTimeSlotsBatch<'info>>,
+        params: Vec<CreateSlotParams>,
+    ) -> Result<()> {
+        market::create_time_slots_batch(ctx, params)
+    }
+
     pub fn init_bid_book(ctx: Context<InitBidBook>) -> Result<()> {
         market::init_bid_book(ctx)
     }
@@ -1259,8 +2236,12 @@ pub mod timemarket {
         market::init_auto_bid_store(ctx, max_entries)
     }
 
-    pub fn bid_commit(ctx: Context<BidCommit>, commitment_hash: [u8; 32]) -> Result<()> {
-        market::bid_commit(ctx, commitment_hash)
+    pub fn bid_commit(
+        ctx: Context<BidCommit>,
+        commitment_hash: [u8; 32],
+        allowlist: Option<AllowlistProof>,
+    ) -> Result<()> {
+        market::bid_commit(ctx, commitment_hash, allowlist)
     }
 
     pub fn bid_reveal(ctx: Context<BidReveal>, bid_amount: u64, salt: [u8; 32]) -> Result<()> {
@@ -1271,23 +2252,36 @@ pub mod timemarket {
         market::auction_start(ctx)
     }
 
-    pub fn buy_now(ctx: Context<BuyNow>) -> Result<()> {
-        market::buy_now(ctx)
+    pub fn buy_now<'info>(
+        ctx: Context<'_, '_, 'info, 'info, BuyNow<'info>>,
+        max_payment: Option<u64>,
+        allowlist: Option<AllowlistProof>,
+    ) -> Result<()> {
+        market::buy_now(ctx, max_payment, allowlist)
     }
 
     pub fn auction_update_end(ctx: Context<AuctionUpdateEnd>, new_end_ts: i64) -> Result<()> {
         market::auction_update_end(ctx, new_end_ts)
     }
 
-    pub fn bid_place(ctx: Context<BidPlace>, bid_amount: u64, max_auto_bid: Option<u64>) -> Result<()> {
-        market::bid_place(ctx, bid_amount, max_auto_bid)
+    pub fn bid_place<'info>(
+        ctx: Context<'_, '_, 'info, 'info, BidPlace<'info>>,
+        bid_amount: u64,
+        max_auto_bid: Option<u64>,
+        allowlist: Option<AllowlistProof>,
+    ) -> Result<()> {
+        market::bid_place(ctx, bid_amount, max_auto_bid, allowlist)
     }
 
-    pub fn bid_outbid_refund(ctx: Context<BidOutbidRefund>) -> Result<()> {
+    pub fn bid_outbid_refund<'info>(
+        ctx: Context<'_, '_, 'info, 'info, BidOutbidRefund<'info>>,
+    ) -> Result<()> {
         market::bid_outbid_refund(ctx)
     }
 
-    pub fn auction_end(ctx: Context<AuctionEnd>) -> Result<()> {
+    pub fn auction_end<'info>(
+        ctx: Context<'_, '_, 'info, 'info, AuctionEnd<'info>>,
+    ) -> Result<()> {
         market::auction_end(ctx)
     }
 
@@ -1295,15 +2289,21 @@ pub mod timemarket {
         market::auction_checkin(ctx)
     }
 
-    pub fn sealed_auction_end(ctx: Context<SealedAuctionEnd>) -> Result<()> {
+    pub fn sealed_auction_end<'info>(
+        ctx: Context<'_, '_, 'info, 'info, SealedAuctionEnd<'info>>,
+    ) -> Result<()> {
         market::sealed_auction_end(ctx)
     }
 
-    pub fn sealed_auction_settle(ctx: Context<SealedAuctionSettle>) -> Result<()> {
+    pub fn sealed_auction_settle<'info>(
+        ctx: Context<'_, '_, 'info, 'info, SealedAuctionSettle<'info>>,
+    ) -> Result<()> {
         market::sealed_auction_settle(ctx)
     }
 
-    pub fn auction_settle(ctx: Context<AuctionSettle>) -> Result<()> {
+    pub fn auction_settle<'info>(
+        ctx: Context<'_, '_, 'info, 'info, AuctionSettle<'info>>,
+    ) -> Result<()> {
         market::auction_settle(ctx)
     }
 
@@ -1311,7 +2311,10 @@ pub mod timemarket {
         market::raise_dispute(ctx, reason_code)
     }
 
-    pub fn resolve_dispute(ctx: Context<ResolveDispute>, payout_split_bps_to_creator: u16) -> Result<()> {
+    pub fn resolve_dispute<'info>(
+        ctx: Context<'_, '_, 'info, 'info, ResolveDispute<'info>>,
+        payout_split_bps_to_creator: u16,
+    ) -> Result<()> {
         market::resolve_dispute(ctx, payout_split_bps_to_creator)
     }
 
@@ -1320,11 +2323,18 @@ pub mod timemarket {
         escrow::init_escrow(ctx)
     }
 
-    pub fn stable_reserve(ctx: Context<StableReserve>, amount: u64) -> Result<()> {
-        escrow::stable_reserve(ctx, amount)
+    // For USD-priced slots `amount` is the buyer's max payment in mint units
+    pub fn stable_reserve<'info>(
+        ctx: Context<'_, '_, 'info, 'info, StableReserve<'info>>,
+        amount: u64,
+        allowlist: Option<AllowlistProof>,
+    ) -> Result<()> {
+        escrow::stable_reserve(ctx, amount, allowlist)
     }
 
-    pub fn stable_cancel(ctx: Context<StableCancel>) -> Result<()> {
+    pub fn stable_cancel<'info>(
+        ctx: Context<'_, '_, 'info, 'info, StableCancel<'info>>,
+    ) -> Result<()> {
         escrow::stable_cancel(ctx)
     }
 
@@ -1332,13 +2342,41 @@ pub mod timemarket {
         escrow::stable_checkin(ctx)
     }
 
-    pub fn stable_settle(ctx: Context<StableSettle>) -> Result<()> {
+    pub fn transfer_reservation(
+        ctx: Context<TransferReservation>,
+        new_holder: Pubkey,
+        with_refund_rights: bool,
+        allowlist: Option<AllowlistProof>,
+    ) -> Result<()> {
+        escrow::transfer_reservation(ctx, new_holder, with_refund_rights, allowlist)
+    }
+
+    // Secondary market for held bookings (SPL)
+    pub fn list_reservation(ctx: Context<ListReservation>, price: u64) -> Result<()> {
+        resale::list_reservation(ctx, price)
+    }
+
+    pub fn cancel_listing(ctx: Context<CancelListing>) -> Result<()> {
+        resale::cancel_listing(ctx)
+    }
+
+    pub fn buy_listing<'info>(
+        ctx: Context<'_, '_, 'info, 'info, BuyListing<'info>>,
+        max_price: u64,
+        allowlist: Option<AllowlistProof>,
+    ) -> Result<()> {
+        resale::buy_listing(ctx, max_price, allowlist)
+    }
+
+    pub fn stable_settle<'info>(
+        ctx: Context<'_, '_, 'info, 'info, StableSettle<'info>>,
+    ) -> Result<()> {
         escrow::stable_settle(ctx)
     }
 
     // Stable SOL variants (MVP)
-    pub fn stable_reserve_sol(ctx: Context<StableReserveSol>) -> Result<()> {
-        escrow::stable_reserve_sol(ctx)
+    pub fn stable_reserve_sol(ctx: Context<StableReserveSol>, allowlist: Option<AllowlistProof>) -> Result<()> {
+        escrow::stable_reserve_sol(ctx, allowlist)
     }
 
     pub fn stable_cancel_sol(ctx: Context<StableCancelSol>) -> Result<()> {
@@ -1350,7 +2388,9 @@ pub mod timemarket {
     }
 
     // Close/Cancel slot by creator/admin (P2)
-    pub fn close_slot(ctx: Context<CloseSlot>) -> Result<()> {
+    pub fn close_slot<'info>(
+        ctx: Context<'_, '_, 'info, 'info, CloseSlot<'info>>,
+    ) -> Result<()> {
         escrow::close_slot(ctx)
     }
 
@@ -1358,8 +2398,107 @@ pub mod timemarket {
         escrow::close_slot_sol(ctx)
     }
 
+    // Accepted mint registry (admin)
+    pub fn add_accepted_mint(ctx: Context<AddAcceptedMint>) -> Result<()> {
+        market::add_accepted_mint(ctx)
+    }
+
+    pub fn set_sol_receivers(
+        ctx: Context<SetSolReceivers>,
+        fee_receiver: Pubkey,
+        dispute_receiver: Pubkey,
+    ) -> Result<()> {
+        market::set_sol_receivers(ctx, fee_receiver, dispute_receiver)
+    }
+
+    pub fn set_accepted_mint_enabled(ctx: Context<SetAcceptedMintEnabled>, enabled: bool) -> Result<()> {
+        market::set_accepted_mint_enabled(ctx, enabled)
+    }
+
+    // Native SOL auction flows
+    pub fn bid_place_sol(
+        ctx: Context<BidPlaceSol>,
+        bid_amount: u64,
+        max_auto_bid: Option<u64>,
+        allowlist: Option<AllowlistProof>,
+    ) -> Result<()> {
+        auction_sol::bid_place_sol(ctx, bid_amount, max_auto_bid, allowlist)
+    }
+
+    pub fn bid_outbid_refund_sol(ctx: Context<BidOutbidRefundSol>) -> Result<()> {
+        auction_sol::bid_outbid_refund_sol(ctx)
+    }
+
+    pub fn buy_now_sol(ctx: Context<BuyNowSol>, allowlist: Option<AllowlistProof>) -> Result<()> {
+        auction_sol::buy_now_sol(ctx, allowlist)
+    }
+
+    pub fn auction_end_sol(ctx: Context<AuctionEndSol>) -> Result<()> {
+        auction_sol::auction_end_sol(ctx)
+    }
+
+    pub fn auction_settle_sol(ctx: Context<AuctionSettleSol>) -> Result<()> {
+        auction_sol::auction_settle_sol(ctx)
+    }
+
+    pub fn sealed_auction_end_sol(ctx: Context<SealedAuctionEndSol>) -> Result<()> {
+        auction_sol::sealed_auction_end_sol(ctx)
+    }
+
+    pub fn sealed_auction_settle_sol(ctx: Context<SealedAuctionSettleSol>) -> Result<()> {
+        auction_sol::sealed_auction_settle_sol(ctx)
+    }
+
+    // Creator-initiated reschedule
+    pub fn propose_reschedule(
+        ctx: Context<ProposeReschedule>,
+        new_start_ts: i64,
+        new_end_ts: i64,
+        respond_by: i64,
+        default_accept: bool,
+    ) -> Result<()> {
+        reschedule::propose_reschedule(ctx, new_start_ts, new_end_ts, respond_by, default_accept)
+    }
+
+    pub fn accept_reschedule(ctx: Context<AcceptReschedule>) -> Result<()> {
+        reschedule::accept_reschedule(ctx)
+    }
+
+    pub fn reject_reschedule<'info>(
+        ctx: Context<'_, '_, 'info, 'info, RejectReschedule<'info>>,
+    ) -> Result<()> {
+        reschedule::reject_reschedule(ctx)
+    }
+
+    pub fn reject_reschedule_sol(ctx: Context<RejectRescheduleSol>) -> Result<()> {
+        reschedule::reject_reschedule_sol(ctx)
+    }
+
+    // Recurring slot series
+    pub fn create_slot_series(ctx: Context<CreateSlotSeries>, params: CreateSeriesParams) -> Result<()> {
+        series::create_slot_series(ctx, params)
+    }
+
+    pub fn update_slot_series<'info>(
+        ctx: Context<'_, '_, 'info, 'info, UpdateSlotSeries<'info>>,
+        params: UpdateSeriesParams,
+    ) -> Result<()> {
+        series::update_slot_series(ctx, params)
+    }
+
+    pub fn materialize_series<'info>(
+        ctx: Context<'_, '_, 'info, 'info, MaterializeSeries<'info>>,
+        count: u16,
+    ) -> Result<()> {
+        series::materialize_series(ctx, count)
+    }
+
     // Tipping system
-    pub fn tip_creator_spl(ctx: Context<TipCreatorSpl>, amount: u64, message_hash: Option<[u8; 32]>) -> Result<()> {
+    pub fn tip_creator_spl<'info>(
+        ctx: Context<'_, '_, 'info, 'info, TipCreatorSpl<'info>>,
+        amount: u64,
+        message_hash: Option<[u8; 32]>,
+    ) -> Result<()> {
         tipping::tip_creator_spl(ctx, amount, message_hash)
     }
 
@@ -1367,9 +2506,26 @@ pub mod timemarket {
         tipping::tip_creator_sol(ctx, amount, message_hash)
     }
 
-    pub fn tip_for_session_spl(ctx: Context<TipForSessionSpl>, amount: u64, message_hash: Option<[u8; 32]>) -> Result<()> {
+    pub fn tip_for_session_spl<'info>(
+        ctx: Context<'_, '_, 'info, 'info, TipForSessionSpl<'info>>,
+        amount: u64,
+        message_hash: Option<[u8; 32]>,
+    ) -> Result<()> {
         tipping::tip_for_session_spl(ctx, amount, message_hash)
     }
+
+    // Escrow health check (permissionless) and admin surplus sweep
+    pub fn audit_escrow(ctx: Context<AuditEscrow>) -> Result<()> {
+        audit::audit_escrow(ctx)
+    }
+
+    pub fn reconcile<'info>(ctx: Context<'_, '_, 'info, 'info, Reconcile<'info>>) -> Result<()> {
+        audit::reconcile(ctx)
+    }
+
+    pub fn reconcile_sol(ctx: Context<ReconcileSol>) -> Result<()> {
+        audit::reconcile_sol(ctx)
+    }
 }
 
 // ===================== Tipping Context Accounts =====================
@@ -1385,7 +2541,12 @@ pub struct TipCreatorSpl<'info> {
     )]
     pub creator_profile: Account<'info, CreatorProfile>,
     pub platform: Account<'info, Platform>,
-    #[account(constraint = mint.key() == platform.mint)]
+    #[account(
+        seeds = [b"accepted_mint", platform.key().as_ref(), mint.key().as_ref()],
+        bump = accepted_mint.bump,
+        constraint = accepted_mint.enabled @ ErrorCode::MintNotAccepted
+    )]
+    pub accepted_mint: Account<'info, AcceptedMint>,
     pub mint: InterfaceAccount<'info, Mint>,
     #[account(mut)]
     pub tipper_token: InterfaceAccount<'info, TokenAccount>,
@@ -1436,7 +2597,12 @@ pub struct TipForSessionSpl<'info> {
     )]
     pub creator_profile: Account<'info, CreatorProfile>,
     pub platform: Account<'info, Platform>,
-    #[account(constraint = mint.key() == platform.mint)]
+    #[account(
+        seeds = [b"accepted_mint", platform.key().as_ref(), mint.key().as_ref()],
+        bump = accepted_mint.bump,
+        constraint = accepted_mint.enabled @ ErrorCode::MintNotAccepted
+    )]
+    pub accepted_mint: Account<'info, AcceptedMint>,
     pub mint: InterfaceAccount<'info, Mint>,
     #[account(mut)]
     pub tipper_token: InterfaceAccount<'info, TokenAccount>,
@@ -1474,4 +2640,1061 @@ pub struct SessionTipEvent {
     pub amount: u64,
 }
 
+// ===================== Reschedule Context Accounts =====================
+
+#[derive(Accounts)]
+pub struct ProposeReschedule<'info> {
+    pub creator: Signer<'info>,
+    #[account(mut, constraint = slot.creator_authority == creator.key() @ ErrorCode::Unauthorized)]
+    pub slot: Account<'info, TimeSlot>,
+    #[account(seeds = [b"escrow", slot.key().as_ref()], bump = escrow.bump)]
+    pub escrow: Account<'info, Escrow>,
+}
+
+#[derive(Accounts)]
+pub struct AcceptReschedule<'info> {
+    /// Buyer, or anyone once the deadline passed with a default of accept
+    pub signer: Signer<'info>,
+    #[account(mut)]
+    pub slot: Account<'info, TimeSlot>,
+    #[account(seeds = [b"escrow", slot.key().as_ref()], bump = escrow.bump)]
+    pub escrow: Account<'info, Escrow>,
+}
+
+#[derive(Accounts)]
+pub struct RejectReschedule<'info> {
+    /// Buyer, or anyone once the deadline passed with a default of refund
+    pub signer: Signer<'info>,
+    pub platform: Account<'info, Platform>,
+    #[account(constraint = mint.key() == slot.mint)]
+    pub mint: InterfaceAccount<'info, Mint>,
+    #[account(mut)]
+    pub slot: Account<'info, TimeSlot>,
+    #[account(mut, seeds = [b"escrow", slot.key().as_ref()], bump = escrow.bump)]
+    pub escrow: Account<'info, Escrow>,
+    #[account(mut, address = escrow.token_acc @ ErrorCode::InvalidEscrowVault)]
+    pub escrow_vault: InterfaceAccount<'info, TokenAccount>,
+    /// Payer token account to receive the full refund
+    #[account(mut, constraint = buyer_token.mint == mint.key() && Some(buyer_token.owner) == escrow.refund_to() @ ErrorCode::UnauthorizedBuyer)]
+    pub buyer_token: InterfaceAccount<'info, TokenAccount>,
+    pub token_program: Interface<'info, TokenInterface>,
+}
+
+#[derive(Accounts)]
+pub struct RejectRescheduleSol<'info> {
+    /// Buyer, or anyone once the deadline passed with a default of refund
+    pub signer: Signer<'info>,
+    #[account(mut)]
+    pub slot: Account<'info, TimeSlot>,
+    #[account(mut, seeds = [b"escrow", slot.key().as_ref()], bump = escrow.bump)]
+    pub escrow: Account<'info, Escrow>,
+    /// Wallet that paid for the booking; receives the lamports refund
+    #[account(mut, address = escrow.refund_to().unwrap_or_default() @ ErrorCode::UnauthorizedBuyer)]
+    pub refund_receiver: SystemAccount<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+// ===================== Reschedule Events =====================
+
+#[event]
+pub struct RescheduleProposedEvent {
+    pub slot: Pubkey,
+    pub new_start_ts: i64,
+    pub new_end_ts: i64,
+    pub respond_by: i64,
+    pub default_accept: bool,
+}
+
+#[event]
+pub struct RescheduleResolvedEvent {
+    pub slot: Pubkey,
+    pub accepted: bool,
+    pub by: Pubkey,
+    pub start_ts: i64,
+    pub end_ts: i64,
+}
+
+// ===================== Slot Series Context Accounts =====================
+
+#[derive(Accounts)]
+pub struct CreateSlotSeries<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    pub platform: Account<'info, Platform>,
+    /// Registry entry for params.mint; omitted for SOL slots
+    pub accepted_mint: Option<Account<'info, AcceptedMint>>,
+    #[account(
+        mut,
+        seeds = [b"creator", authority.key().as_ref(), platform.key().as_ref()],
+        bump = profile.bump
+    )]
+    pub profile: Account<'info, CreatorProfile>,
+    #[account(
+        init,
+        payer = authority,
+        space = 8 + SlotSeries::LEN,
+        seeds = [b"series", profile.key().as_ref(), &profile.series_count.to_le_bytes()],
+        bump
+    )]
+    pub series: Account<'info, SlotSeries>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct UpdateSlotSeries<'info> {
+    pub authority: Signer<'info>,
+    #[account(mut, constraint = series.creator_authority == authority.key() @ ErrorCode::Unauthorized)]
+    pub series: Account<'info, SlotSeries>,
+    // remaining_accounts: (slot, escrow PDA, commit store PDA) per materialized occurrence to bring
+    // in line; occurrences with any activity are skipped
+}
+
+#[derive(Accounts)]
+pub struct MaterializeSeries<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    pub platform: Account<'info, Platform>,
+    #[account(
+        mut,
+        seeds = [b"creator", authority.key().as_ref(), platform.key().as_ref()],
+        bump = profile.bump
+    )]
+    pub profile: Account<'info, CreatorProfile>,
+    #[account(
+        mut,
+        seeds = [b"series", profile.key().as_ref(), &series.index.to_le_bytes()],
+        bump = series.bump
+    )]
+    pub series: Account<'info, SlotSeries>,
+    /// Registry entry for series.mint; omitted for SOL series
+    pub accepted_mint: Option<Account<'info, AcceptedMint>>,
+    pub system_program: Program<'info, System>,
+    // remaining_accounts: the next `count` slot PDAs at profile.slot_count, +1, ...
+}
 
+// ===================== Slot Series Events =====================
+
+#[event]
+pub struct SeriesMaterializedEvent {
+    pub series: Pubkey,
+    pub first_slot_index: u64,
+    pub count: u16,
+    pub materialized: u16,
+}
+
+#[event]
+pub struct SeriesUpdatedEvent {
+    pub series: Pubkey,
+    pub slots_updated: u16,
+}
+
+// ===================== Auction SOL Accounts =====================
+
+#[derive(Accounts)]
+pub struct BidPlaceSol<'info> {
+    // Creates no accounts, so a sponsor only has to be the transaction fee payer
+    #[account(mut)]
+    pub bidder: Signer<'info>,
+    #[account(mut)]
+    pub slot: Account<'info, TimeSlot>,
+    #[account(mut, seeds = [b"bidbook", slot.key().as_ref()], bump = bidbook.bump)]
+    pub bidbook: Account<'info, BidBook>,
+    #[account(mut, seeds = [b"escrow", slot.key().as_ref()], bump = escrow.bump)]
+    pub escrow: Account<'info, Escrow>,
+    #[account(mut, seeds = [b"refund", slot.key().as_ref()], bump = refund_queue.bump)]
+    pub refund_queue: Account<'info, RefundQueue>,
+    #[account(mut, seeds = [b"autobid", slot.key().as_ref()], bump = auto_bid_store.bump)]
+    pub auto_bid_store: Account<'info, AutoBidStore>,
+    pub system_program: Program<'info, System>,
+    /// CHECK: Only the key is recorded as the attendee if this bid wins; defaults to the bidder
+    pub beneficiary: Option<UncheckedAccount<'info>>,
+    // Token-gated slots only: the beneficiary's token account (and its NFT metadata for collection gates)
+    pub gate_token: Option<InterfaceAccount<'info, TokenAccount>>,
+    /// CHECK: Metaplex metadata of gate_token's mint; owner and PDA are checked in gate::check_gate
+    pub gate_metadata: Option<UncheckedAccount<'info>>,
+}
+
+#[derive(Accounts)]
+pub struct BidOutbidRefundSol<'info> {
+    pub slot: Account<'info, TimeSlot>,
+    #[account(mut, seeds = [b"escrow", slot.key().as_ref()], bump = escrow.bump)]
+    pub escrow: Account<'info, Escrow>,
+    #[account(mut, seeds = [b"refund", slot.key().as_ref()], bump = refund_queue.bump)]
+    pub refund_queue: Account<'info, RefundQueue>,
+    /// Outbid bidder receiving lamports back
+    #[account(mut)]
+    pub prev_bidder: SystemAccount<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct BuyNowSol<'info> {
+    /// Funds the price; refunds go back to this wallet
+    #[account(mut)]
+    pub bidder: Signer<'info>,
+    /// Pays rent for accounts created here; a sponsor may sign as payer for a buyer without SOL
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    pub platform: Account<'info, Platform>,
+    #[account(mut)]
+    pub slot: Account<'info, TimeSlot>,
+    #[account(mut, seeds = [b"bidbook", slot.key().as_ref()], bump = bidbook.bump)]
+    pub bidbook: Account<'info, BidBook>,
+    #[account(mut, seeds = [b"escrow", slot.key().as_ref()], bump = escrow.bump)]
+    pub escrow: Account<'info, Escrow>,
+    #[account(mut, seeds = [b"refund", slot.key().as_ref()], bump = refund_queue.bump)]
+    pub refund_queue: Account<'info, RefundQueue>,
+    #[account(
+        seeds = [b"creator", slot.creator_authority.as_ref(), platform.key().as_ref()],
+        bump = profile.bump
+    )]
+    pub profile: Account<'info, CreatorProfile>,
+    #[account(mut, address = profile.payout_wallet)]
+    pub creator_payout: SystemAccount<'info>,
+    /// Platform's configured SOL fee and dispute wallets
+    #[account(seeds = [b"sol_receivers", slot.platform.as_ref()], bump = sol_receivers.bump)]
+    pub sol_receivers: Account<'info, SolReceivers>,
+    /// SOL fee receiver (platform)
+    #[account(mut, address = sol_receivers.fee_receiver @ ErrorCode::InvalidSolReceiver)]
+    pub fee_receiver: SystemAccount<'info>,
+    pub system_program: Program<'info, System>,
+    /// CHECK: Only the key is recorded as the attendee; defaults to the paying bidder
+    pub beneficiary: Option<UncheckedAccount<'info>>,
+    // Token-gated slots only: the beneficiary's token account (and its NFT metadata for collection gates)
+    pub gate_token: Option<InterfaceAccount<'info, TokenAccount>>,
+    /// CHECK: Metaplex metadata of gate_token's mint; owner and PDA are checked in gate::check_gate
+    pub gate_metadata: Option<UncheckedAccount<'info>>,
+    // Allowlist entries with a quantity cap: bookings made so far for the beneficiary
+    #[account(
+        init_if_needed,
+        payer = payer,
+        space = 8 + AllowlistClaim::LEN,
+        seeds = [b"allowlist", slot.key().as_ref(), beneficiary.as_ref().map_or(bidder.key(), |b| b.key()).as_ref()],
+        bump
+    )]
+    pub allowlist_claim: Option<Account<'info, AllowlistClaim>>,
+}
+
+#[derive(Accounts)]
+pub struct AuctionEndSol<'info> {
+    /// anyone can trigger once the auction window closed
+    pub authority: Signer<'info>,
+    pub platform: Account<'info, Platform>,
+    #[account(mut)]
+    pub slot: Account<'info, TimeSlot>,
+    #[account(mut, seeds = [b"bidbook", slot.key().as_ref()], bump = bidbook.bump)]
+    pub bidbook: Account<'info, BidBook>,
+    #[account(mut, seeds = [b"escrow", slot.key().as_ref()], bump = escrow.bump)]
+    pub escrow: Account<'info, Escrow>,
+    #[account(
+        seeds = [b"creator", slot.creator_authority.as_ref(), platform.key().as_ref()],
+        bump = profile.bump
+    )]
+    pub profile: Account<'info, CreatorProfile>,
+    #[account(mut, address = profile.payout_wallet)]
+    pub creator_payout: SystemAccount<'info>,
+    /// Platform's configured SOL fee and dispute wallets
+    #[account(seeds = [b"sol_receivers", slot.platform.as_ref()], bump = sol_receivers.bump)]
+    pub sol_receivers: Account<'info, SolReceivers>,
+    /// SOL fee receiver (platform)
+    #[account(mut, address = sol_receivers.fee_receiver @ ErrorCode::InvalidSolReceiver)]
+    pub fee_receiver: SystemAccount<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct AuctionSettleSol<'info> {
+    pub authority: Signer<'info>,
+    pub platform: Account<'info, Platform>,
+    #[account(mut)]
+    pub slot: Account<'info, TimeSlot>,
+    #[account(seeds = [b"bidbook", slot.key().as_ref()], bump = bidbook.bump)]
+    pub bidbook: Account<'info, BidBook>,
+    #[account(mut, seeds = [b"escrow", slot.key().as_ref()], bump = escrow.bump)]
+    pub escrow: Account<'info, Escrow>,
+    #[account(
+        seeds = [b"creator", slot.creator_authority.as_ref(), platform.key().as_ref()],
+        bump = profile.bump
+    )]
+    pub profile: Account<'info, CreatorProfile>,
+    #[account(mut, address = profile.payout_wallet)]
+    pub creator_payout: SystemAccount<'info>,
+    /// Platform's configured SOL fee and dispute wallets
+    #[account(seeds = [b"sol_receivers", slot.platform.as_ref()], bump = sol_receivers.bump)]
+    pub sol_receivers: Account<'info, SolReceivers>,
+    /// SOL fee receiver (platform)
+    #[account(mut, address = sol_receivers.fee_receiver @ ErrorCode::InvalidSolReceiver)]
+    pub fee_receiver: SystemAccount<'info>,
+    /// SOL retained/withhold receiver (dispute)
+    #[account(mut, address = sol_receivers.dispute_receiver @ ErrorCode::InvalidSolReceiver)]
+    pub dispute_receiver: SystemAccount<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct SealedAuctionEndSol<'info> {
+    pub authority: Signer<'info>,
+    pub platform: Account<'info, Platform>,
+    #[account(mut)]
+    pub slot: Account<'info, TimeSlot>,
+    #[account(mut, seeds = [b"escrow", slot.key().as_ref()], bump = escrow.bump)]
+    pub escrow: Account<'info, Escrow>,
+    #[account(seeds = [b"commit", slot.key().as_ref()], bump = commit_store.bump)]
+    pub commit_store: Account<'info, CommitStore>,
+    #[account(
+        seeds = [b"creator", slot.creator_authority.as_ref(), platform.key().as_ref()],
+        bump = profile.bump
+    )]
+    pub profile: Account<'info, CreatorProfile>,
+    #[account(mut, address = profile.payout_wallet)]
+    pub creator_payout: SystemAccount<'info>,
+    /// Platform's configured SOL fee and dispute wallets
+    #[account(seeds = [b"sol_receivers", slot.platform.as_ref()], bump = sol_receivers.bump)]
+    pub sol_receivers: Account<'info, SolReceivers>,
+    /// SOL fee receiver (platform)
+    #[account(mut, address = sol_receivers.fee_receiver @ ErrorCode::InvalidSolReceiver)]
+    pub fee_receiver: SystemAccount<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct SealedAuctionSettleSol<'info> {
+    pub authority: Signer<'info>,
+    pub platform: Account<'info, Platform>,
+    #[account(mut)]
+    pub slot: Account<'info, TimeSlot>,
+    #[account(mut, seeds = [b"escrow", slot.key().as_ref()], bump = escrow.bump)]
+    pub escrow: Account<'info, Escrow>,
+    #[account(
+        seeds = [b"creator", slot.creator_authority.as_ref(), platform.key().as_ref()],
+        bump = profile.bump
+    )]
+    pub profile: Account<'info, CreatorProfile>,
+    #[account(mut, address = profile.payout_wallet)]
+    pub creator_payout: SystemAccount<'info>,
+    /// Platform's configured SOL fee and dispute wallets
+    #[account(seeds = [b"sol_receivers", slot.platform.as_ref()], bump = sol_receivers.bump)]
+    pub sol_receivers: Account<'info, SolReceivers>,
+    /// SOL fee receiver (platform)
+    #[account(mut, address = sol_receivers.fee_receiver @ ErrorCode::InvalidSolReceiver)]
+    pub fee_receiver: SystemAccount<'info>,
+    /// SOL retained/withhold receiver (dispute)
+    #[account(mut, address = sol_receivers.dispute_receiver @ ErrorCode::InvalidSolReceiver)]
+    pub dispute_receiver: SystemAccount<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+// ===================== Accepted Mint Accounts =====================
+
+#[derive(Accounts)]
+pub struct AddAcceptedMint<'info> {
+    #[account(mut)]
+    pub admin: Signer<'info>,
+    #[account(has_one = admin)]
+    pub platform: Account<'info, Platform>,
+    pub mint: InterfaceAccount<'info, Mint>,
+    #[account(constraint = fee_vault.mint == mint.key())]
+    pub fee_vault: InterfaceAccount<'info, TokenAccount>,
+    #[account(constraint = dispute_vault.mint == mint.key())]
+    pub dispute_vault: InterfaceAccount<'info, TokenAccount>,
+    #[account(
+        init,
+        payer = admin,
+        space = 8 + AcceptedMint::LEN,
+        seeds = [b"accepted_mint", platform.key().as_ref(), mint.key().as_ref()],
+        bump
+    )]
+    pub accepted_mint: Account<'info, AcceptedMint>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct SetAcceptedMintEnabled<'info> {
+    pub admin: Signer<'info>,
+    #[account(has_one = admin)]
+    pub platform: Account<'info, Platform>,
+    #[account(mut, has_one = platform)]
+    pub accepted_mint: Account<'info, AcceptedMint>,
+}
+
+#[derive(Accounts)]
+pub struct SetSolReceivers<'info> {
+    #[account(mut)]
+    pub admin: Signer<'info>,
+    #[account(has_one = admin)]
+    pub platform: Account<'info, Platform>,
+    #[account(
+        init_if_needed,
+        payer = admin,
+        space = 8 + SolReceivers::LEN,
+        seeds = [b"sol_receivers", platform.key().as_ref()],
+        bump
+    )]
+    pub sol_receivers: Account<'info, SolReceivers>,
+    pub system_program: Program<'info, System>,
+}
+
+// ===================== Accepted Mint Events =====================
+
+#[event]
+pub struct AcceptedMintUpdatedEvent {
+    pub platform: Pubkey,
+    pub mint: Pubkey,
+    pub enabled: bool,
+}
+
+#[event]
+pub struct SolReceiversSetEvent {
+    pub platform: Pubkey,
+    pub fee_receiver: Pubkey,
+    pub dispute_receiver: Pubkey,
+}
+
+// ===================== Escrow Audit Accounts =====================
+
+#[derive(Accounts)]
+pub struct AuditEscrow<'info> {
+    pub slot: Account<'info, TimeSlot>,
+    #[account(seeds = [b"escrow", slot.key().as_ref()], bump = escrow.bump)]
+    pub escrow: Account<'info, Escrow>,
+    /// Token vault for SPL slots; omitted for SOL slots
+    #[account(address = escrow.token_acc @ ErrorCode::InvalidEscrowVault)]
+    pub escrow_vault: Option<InterfaceAccount<'info, TokenAccount>>,
+    /// Required for auction slots so queued refunds are counted
+    #[account(seeds = [b"refund", slot.key().as_ref()], bump = refund_queue.bump)]
+    pub refund_queue: Option<Account<'info, RefundQueue>>,
+}
+
+#[derive(Accounts)]
+pub struct Reconcile<'info> {
+    pub admin: Signer<'info>,
+    #[account(has_one = admin)]
+    pub platform: Account<'info, Platform>,
+    #[account(constraint = slot.platform == platform.key() @ ErrorCode::Unauthorized)]
+    pub slot: Account<'info, TimeSlot>,
+    #[account(seeds = [b"escrow", slot.key().as_ref()], bump = escrow.bump)]
+    pub escrow: Account<'info, Escrow>,
+    #[account(mut, address = escrow.token_acc @ ErrorCode::InvalidEscrowVault)]
+    pub escrow_vault: InterfaceAccount<'info, TokenAccount>,
+    #[account(constraint = mint.key() == slot.mint)]
+    pub mint: InterfaceAccount<'info, Mint>,
+    #[account(seeds = [b"accepted_mint", platform.key().as_ref(), slot.mint.as_ref()], bump = accepted_mint.bump)]
+    pub accepted_mint: Account<'info, AcceptedMint>,
+    /// Surplus lands in the dispute vault of the slot's mint
+    #[account(mut, address = accepted_mint.dispute_vault)]
+    pub dispute_vault: InterfaceAccount<'info, TokenAccount>,
+    #[account(seeds = [b"refund", slot.key().as_ref()], bump = refund_queue.bump)]
+    pub refund_queue: Option<Account<'info, RefundQueue>>,
+    pub token_program: Interface<'info, TokenInterface>,
+}
+
+#[derive(Accounts)]
+pub struct ReconcileSol<'info> {
+    pub admin: Signer<'info>,
+    #[account(has_one = admin)]
+    pub platform: Account<'info, Platform>,
+    #[account(constraint = slot.platform == platform.key() @ ErrorCode::Unauthorized)]
+    pub slot: Account<'info, TimeSlot>,
+    #[account(mut, seeds = [b"escrow", slot.key().as_ref()], bump = escrow.bump)]
+    pub escrow: Account<'info, Escrow>,
+    /// Platform's configured SOL fee and dispute wallets
+    #[account(seeds = [b"sol_receivers", slot.platform.as_ref()], bump = sol_receivers.bump)]
+    pub sol_receivers: Account<'info, SolReceivers>,
+    /// SOL retained/withhold receiver (dispute)
+    #[account(mut, address = sol_receivers.dispute_receiver @ ErrorCode::InvalidSolReceiver)]
+    pub dispute_receiver: SystemAccount<'info>,
+    #[account(seeds = [b"refund", slot.key().as_ref()], bump = refund_queue.bump)]
+    pub refund_queue: Option<Account<'info, RefundQueue>>,
+    pub system_program: Program<'info, System>,
+}
+
+// ===================== Escrow Audit Events =====================
+
+#[event]
+pub struct EscrowAuditEvent {
+    pub slot: Pubkey,
+    pub escrow: Pubkey,
+    // Vault balance (SPL) or lamports above rent (SOL)
+    pub held: u64,
+    pub amount_locked: u64,
+    pub pending_refunds: u64,
+    // held minus what is owed; positive is surplus, negative is shortfall
+    pub delta: i64,
+}
+
+#[event]
+pub struct EscrowReconciledEvent {
+    pub slot: Pubkey,
+    pub to: Pubkey,
+    pub amount: u64,
+}
+
+// ===================== Reservation Transfer Events =====================
+
+#[event]
+pub struct ReservationTransferredEvent {
+    pub slot: Pubkey,
+    pub from: Pubkey,
+    pub to: Pubkey,
+    // Wallet refunds now go to
+    pub refund_to: Pubkey,
+}
+
+// ===================== Resale Accounts =====================
+
+#[derive(Accounts)]
+pub struct ListReservation<'info> {
+    #[account(mut)]
+    pub seller: Signer<'info>,
+    pub slot: Account<'info, TimeSlot>,
+    #[account(seeds = [b"escrow", slot.key().as_ref()], bump = escrow.bump)]
+    pub escrow: Account<'info, Escrow>,
+    #[account(
+        init,
+        payer = seller,
+        space = 8 + Listing::LEN,
+        seeds = [b"listing", slot.key().as_ref()],
+        bump
+    )]
+    pub listing: Account<'info, Listing>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct CancelListing<'info> {
+    /// The seller, or anyone once the listing is stale
+    pub caller: Signer<'info>,
+    pub slot: Account<'info, TimeSlot>,
+    #[account(seeds = [b"escrow", slot.key().as_ref()], bump = escrow.bump)]
+    pub escrow: Account<'info, Escrow>,
+    #[account(
+        mut,
+        close = seller,
+        has_one = seller,
+        seeds = [b"listing", slot.key().as_ref()],
+        bump = listing.bump
+    )]
+    pub listing: Account<'info, Listing>,
+    /// Seller receives the listing rent back
+    #[account(mut)]
+    pub seller: SystemAccount<'info>,
+}
+
+#[derive(Accounts)]
+pub struct BuyListing<'info> {
+    #[account(mut)]
+    pub buyer: Signer<'info>,
+    pub platform: Account<'info, Platform>,
+    #[account(constraint = mint.key() == slot.mint)]
+    pub mint: InterfaceAccount<'info, Mint>,
+    #[account(constraint = slot.platform == platform.key() @ ErrorCode::Unauthorized)]
+    pub slot: Account<'info, TimeSlot>,
+    #[account(mut, seeds = [b"escrow", slot.key().as_ref()], bump = escrow.bump)]
+    pub escrow: Account<'info, Escrow>,
+    #[account(
+        mut,
+        close = seller,
+        has_one = seller,
+        seeds = [b"listing", slot.key().as_ref()],
+        bump = listing.bump
+    )]
+    pub listing: Account<'info, Listing>,
+    /// Seller receives the listing rent back
+    #[account(mut)]
+    pub seller: SystemAccount<'info>,
+    #[account(mut, constraint = seller_token.owner == listing.seller && seller_token.mint == mint.key())]
+    pub seller_token: InterfaceAccount<'info, TokenAccount>,
+    #[account(mut, constraint = buyer_token.owner == buyer.key() && buyer_token.mint == mint.key())]
+    pub buyer_token: InterfaceAccount<'info, TokenAccount>,
+    /// Creator receives the resale royalty
+    #[account(
+        seeds = [b"creator", slot.creator_authority.as_ref(), platform.key().as_ref()],
+        bump = profile.bump
+    )]
+    pub profile: Account<'info, CreatorProfile>,
+    #[account(address = profile.payout_wallet)]
+    /// CHECK: Address is constrained to `profile.payout_wallet` above.
+    /// No further data access is performed on this account.
+    pub profile_payout_wallet: UncheckedAccount<'info>,
+    #[account(
+        init_if_needed,
+        payer = buyer,
+        associated_token::mint = mint,
+        associated_token::authority = profile_payout_wallet,
+        associated_token::token_program = token_program
+    )]
+    pub creator_payout_ata: InterfaceAccount<'info, TokenAccount>,
+    /// Registry entry for the slot's mint (fee and dispute vaults)
+    #[account(seeds = [b"accepted_mint", platform.key().as_ref(), slot.mint.as_ref()], bump = accepted_mint.bump)]
+    pub accepted_mint: Account<'info, AcceptedMint>,
+    /// Fee vault (platform fees)
+    #[account(mut, address = accepted_mint.fee_vault)]
+    pub fee_vault: InterfaceAccount<'info, TokenAccount>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub token_program: Interface<'info, TokenInterface>,
+    pub system_program: Program<'info, System>,
+    // Token-gated slots only: the buyer's token account (and its NFT metadata for collection gates)
+    pub gate_token: Option<InterfaceAccount<'info, TokenAccount>>,
+    /// CHECK: Metaplex metadata of gate_token's mint; owner and PDA are checked in gate::check_gate
+    pub gate_metadata: Option<UncheckedAccount<'info>>,
+    // Allowlist entries with a quantity cap: bookings made so far by the buyer
+    #[account(
+        init_if_needed,
+        payer = buyer,
+        space = 8 + AllowlistClaim::LEN,
+        seeds = [b"allowlist", slot.key().as_ref(), buyer.key().as_ref()],
+        bump
+    )]
+    pub allowlist_claim: Option<Account<'info, AllowlistClaim>>,
+}
+
+// ===================== Resale Events =====================
+
+#[event]
+pub struct ReservationListedEvent {
+    pub slot: Pubkey,
+    pub seller: Pubkey,
+    pub price: u64,
+}
+
+#[event]
+pub struct ListingCancelledEvent {
+    pub slot: Pubkey,
+    pub seller: Pubkey,
+}
+
+#[event]
+pub struct ReservationResoldEvent {
+    pub slot: Pubkey,
+    pub seller: Pubkey,
+    pub buyer: Pubkey,
+    pub price: u64,
+    pub royalty: u64,
+    pub fee: u64,
+}
+
+// ===================== Slot NFT Accounts =====================
+
+#[derive(Accounts)]
+pub struct InitSlotNft<'info> {
+    #[account(mut)]
+    pub creator: Signer<'info>,
+    #[account(mut, constraint = slot.creator_authority == creator.key() @ ErrorCode::Unauthorized)]
+    pub slot: Account<'info, TimeSlot>,
+    /// CHECK: PDA authority to mint NFTs for this slot
+    #[account(seeds = [b"nft_auth", slot.key().as_ref()], bump)]
+    pub nft_auth: UncheckedAccount<'info>,
+    #[account(
+        init,
+        payer = creator,
+        seeds = [b"nft_mint", slot.key().as_ref()],
+        bump,
+        mint::decimals = 0,
+        mint::authority = nft_auth,
+        mint::freeze_authority = nft_auth,
+        mint::token_program = token_program
+    )]
+    pub nft_mint: InterfaceAccount<'info, Mint>,
+    /// CHECK: Metaplex metadata PDA for nft_mint; created by the metadata program
+    #[account(
+        mut,
+        seeds = [b"metadata", metadata_program.key().as_ref(), nft_mint.key().as_ref()],
+        seeds::program = metadata_program.key(),
+        bump
+    )]
+    pub metadata: UncheckedAccount<'info>,
+    pub metadata_program: Program<'info, Metadata>,
+    pub token_program: Interface<'info, TokenInterface>,
+    pub system_program: Program<'info, System>,
+    pub rent: Sysvar<'info, Rent>,
+}
+
+// ===================== Ticket Events =====================
+
+#[event]
+pub struct TicketMintedEvent {
+    pub slot: Pubkey,
+    pub mint: Pubkey,
+    pub holder: Pubkey,
+}
+
+// ===================== Slot NFT Events =====================
+
+#[event]
+pub struct SlotNftInitializedEvent {
+    pub slot: Pubkey,
+    pub mint: Pubkey,
+    pub metadata: Pubkey,
+}
+
+// ===================== Allowlist Accounts =====================
+
+#[derive(Accounts)]
+pub struct SetAllowlistRoot<'info> {
+    pub creator: Signer<'info>,
+    #[account(mut, constraint = slot.creator_authority == creator.key() @ ErrorCode::Unauthorized)]
+    pub slot: Account<'info, TimeSlot>,
+}
+
+// ===================== Allowlist Events =====================
+
+#[event]
+pub struct AllowlistRootSetEvent {
+    pub slot: Pubkey,
+    pub root: Option<[u8; 32]>,
+}
+
+// ===================== Booking Request Accounts =====================
+
+#[derive(Accounts)]
+pub struct RequestBooking<'info> {
+    #[account(mut)]
+    pub requester: Signer<'info>,
+    #[account(constraint = mint.key() == slot.mint)]
+    pub mint: InterfaceAccount<'info, Mint>,
+    pub slot: Account<'info, TimeSlot>,
+    #[account(mut, seeds = [b"escrow", slot.key().as_ref()], bump = escrow.bump)]
+    pub escrow: Account<'info, Escrow>,
+    #[account(mut, address = escrow.token_acc @ ErrorCode::InvalidEscrowVault)]
+    pub escrow_vault: InterfaceAccount<'info, TokenAccount>,
+    #[account(mut, constraint = requester_token.owner == requester.key() && requester_token.mint == mint.key())]
+    pub requester_token: InterfaceAccount<'info, TokenAccount>,
+    #[account(
+        init,
+        payer = requester,
+        space = 8 + BookingRequest::LEN,
+        seeds = [b"booking_request", slot.key().as_ref(), requester.key().as_ref()],
+        bump
+    )]
+    pub booking_request: Account<'info, BookingRequest>,
+    pub token_program: Interface<'info, TokenInterface>,
+    pub system_program: Program<'info, System>,
+    // Token-gated slots only: the signer's token account (and its NFT metadata for collection gates)
+    pub gate_token: Option<InterfaceAccount<'info, TokenAccount>>,
+    /// CHECK: Metaplex metadata of gate_token's mint; owner and PDA are checked in gate::check_gate
+    pub gate_metadata: Option<UncheckedAccount<'info>>,
+}
+
+#[derive(Accounts)]
+pub struct RequestBookingSol<'info> {
+    #[account(mut)]
+    pub requester: Signer<'info>,
+    pub slot: Account<'info, TimeSlot>,
+    #[account(mut, seeds = [b"escrow", slot.key().as_ref()], bump = escrow.bump)]
+    pub escrow: Account<'info, Escrow>,
+    #[account(
+        init,
+        payer = requester,
+        space = 8 + BookingRequest::LEN,
+        seeds = [b"booking_request", slot.key().as_ref(), requester.key().as_ref()],
+        bump
+    )]
+    pub booking_request: Account<'info, BookingRequest>,
+    pub system_program: Program<'info, System>,
+    // Token-gated slots only: the signer's token account (and its NFT metadata for collection gates)
+    pub gate_token: Option<InterfaceAccount<'info, TokenAccount>>,
+    /// CHECK: Metaplex metadata of gate_token's mint; owner and PDA are checked in gate::check_gate
+    pub gate_metadata: Option<UncheckedAccount<'info>>,
+}
+
+#[derive(Accounts)]
+pub struct ApproveBooking<'info> {
+    pub creator: Signer<'info>,
+    #[account(constraint = mint.key() == slot.mint)]
+    pub mint: InterfaceAccount<'info, Mint>,
+    #[account(mut, constraint = slot.creator_authority == creator.key() @ ErrorCode::Unauthorized)]
+    pub slot: Account<'info, TimeSlot>,
+    #[account(mut, seeds = [b"escrow", slot.key().as_ref()], bump = escrow.bump)]
+    pub escrow: Account<'info, Escrow>,
+    #[account(mut, address = escrow.token_acc @ ErrorCode::InvalidEscrowVault)]
+    pub escrow_vault: InterfaceAccount<'info, TokenAccount>,
+    /// Approved request is closed; its rent goes back to the requester
+    #[account(
+        mut,
+        close = requester,
+        seeds = [b"booking_request", slot.key().as_ref(), booking_request.requester.as_ref()],
+        bump = booking_request.bump
+    )]
+    pub booking_request: Account<'info, BookingRequest>,
+    #[account(mut, address = booking_request.requester)]
+    pub requester: SystemAccount<'info>,
+    pub token_program: Interface<'info, TokenInterface>,
+    // remaining_accounts: (booking_request, requester, requester_token) for every other
+    // outstanding request on the slot, then any transfer-hook accounts
+}
+
+#[derive(Accounts)]
+pub struct ApproveBookingSol<'info> {
+    pub creator: Signer<'info>,
+    #[account(mut, constraint = slot.creator_authority == creator.key() @ ErrorCode::Unauthorized)]
+    pub slot: Account<'info, TimeSlot>,
+    #[account(mut, seeds = [b"escrow", slot.key().as_ref()], bump = escrow.bump)]
+    pub escrow: Account<'info, Escrow>,
+    /// Approved request is closed; its rent goes back to the requester
+    #[account(
+        mut,
+        close = requester,
+        seeds = [b"booking_request", slot.key().as_ref(), booking_request.requester.as_ref()],
+        bump = booking_request.bump
+    )]
+    pub booking_request: Account<'info, BookingRequest>,
+    #[account(mut, address = booking_request.requester)]
+    pub requester: SystemAccount<'info>,
+    pub system_program: Program<'info, System>,
+    // remaining_accounts: (booking_request, requester) for every other outstanding request
+}
+
+#[derive(Accounts)]
+pub struct DeclineBooking<'info> {
+    pub creator: Signer<'info>,
+    #[account(constraint = slot.creator_authority == creator.key() @ ErrorCode::Unauthorized)]
+    pub slot: Account<'info, TimeSlot>,
+    #[account(
+        mut,
+        seeds = [b"booking_request", slot.key().as_ref(), booking_request.requester.as_ref()],
+        bump = booking_request.bump
+    )]
+    pub booking_request: Account<'info, BookingRequest>,
+}
+
+#[derive(Accounts)]
+pub struct RefundBookingRequest<'info> {
+    #[account(constraint = mint.key() == slot.mint)]
+    pub mint: InterfaceAccount<'info, Mint>,
+    pub slot: Account<'info, TimeSlot>,
+    #[account(mut, seeds = [b"escrow", slot.key().as_ref()], bump = escrow.bump)]
+    pub escrow: Account<'info, Escrow>,
+    #[account(mut, address = escrow.token_acc @ ErrorCode::InvalidEscrowVault)]
+    pub escrow_vault: InterfaceAccount<'info, TokenAccount>,
+    #[account(
+        mut,
+        close = requester,
+        seeds = [b"booking_request", slot.key().as_ref(), booking_request.requester.as_ref()],
+        bump = booking_request.bump
+    )]
+    pub booking_request: Account<'info, BookingRequest>,
+    #[account(mut, address = booking_request.requester)]
+    pub requester: SystemAccount<'info>,
+    #[account(mut, constraint = requester_token.owner == requester.key() && requester_token.mint == mint.key())]
+    pub requester_token: InterfaceAccount<'info, TokenAccount>,
+    pub token_program: Interface<'info, TokenInterface>,
+}
+
+#[derive(Accounts)]
+pub struct RefundBookingRequestSol<'info> {
+    pub slot: Account<'info, TimeSlot>,
+    #[account(mut, seeds = [b"escrow", slot.key().as_ref()], bump = escrow.bump)]
+    pub escrow: Account<'info, Escrow>,
+    #[account(
+        mut,
+        close = requester,
+        seeds = [b"booking_request", slot.key().as_ref(), booking_request.requester.as_ref()],
+        bump = booking_request.bump
+    )]
+    pub booking_request: Account<'info, BookingRequest>,
+    #[account(mut, address = booking_request.requester)]
+    pub requester: SystemAccount<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+// ===================== Booking Request Events =====================
+
+#[event]
+pub struct BookingRequestedEvent {
+    pub slot: Pubkey,
+    pub requester: Pubkey,
+    pub amount: u64,
+    pub message_hash: Option<[u8; 32]>,
+    pub expires_at: i64,
+}
+
+#[event]
+pub struct BookingApprovedEvent {
+    pub slot: Pubkey,
+    pub requester: Pubkey,
+    pub amount: u64,
+}
+
+#[event]
+pub struct BookingDeclinedEvent {
+    pub slot: Pubkey,
+    pub requester: Pubkey,
+}
+
+#[event]
+pub struct BookingRequestRefundedEvent {
+    pub slot: Pubkey,
+    pub requester: Pubkey,
+    pub amount: u64,
+}
+
+// ===================== Draft Accounts =====================
+
+#[derive(Accounts)]
+pub struct UpdateDraftSlot<'info> {
+    pub creator: Signer<'info>,
+    #[account(mut, constraint = slot.creator_authority == creator.key() @ ErrorCode::Unauthorized)]
+    pub slot: Account<'info, TimeSlot>,
+}
+
+#[derive(Accounts)]
+pub struct PublishSlot<'info> {
+    pub creator: Signer<'info>,
+    #[account(mut, constraint = slot.creator_authority == creator.key() @ ErrorCode::Unauthorized)]
+    pub slot: Account<'info, TimeSlot>,
+}
+
+#[derive(Accounts)]
+pub struct UpdateSlot<'info> {
+    pub creator: Signer<'info>,
+    #[account(mut, constraint = slot.creator_authority == creator.key() @ ErrorCode::Unauthorized)]
+    pub slot: Account<'info, TimeSlot>,
+    /// CHECK: Escrow PDA, possibly not initialized yet; read in market::update_slot
+    #[account(seeds = [b"escrow", slot.key().as_ref()], bump)]
+    pub escrow: UncheckedAccount<'info>,
+    /// CHECK: Commit store PDA, possibly not initialized yet; sealed bids commit while still Open
+    #[account(seeds = [b"commit", slot.key().as_ref()], bump)]
+    pub commit_store: UncheckedAccount<'info>,
+}
+
+// ===================== Draft Events =====================
+
+#[event]
+pub struct DraftSlotUpdatedEvent {
+    pub slot: Pubkey,
+}
+
+#[event]
+pub struct SlotPublishedEvent {
+    pub slot: Pubkey,
+    pub publish_at: Option<i64>,
+}
+
+#[event]
+pub struct SlotUpdatedEvent {
+    pub slot: Pubkey,
+    pub changed: Vec<SlotField>,
+}
+
+// ===================== Hold Accounts =====================
+
+#[derive(Accounts)]
+pub struct SetHoldConfig<'info> {
+    #[account(mut)]
+    pub admin: Signer<'info>,
+    #[account(has_one = admin)]
+    pub platform: Account<'info, Platform>,
+    #[account(
+        init_if_needed,
+        payer = admin,
+        space = 8 + HoldConfig::LEN,
+        seeds = [b"hold_config", platform.key().as_ref()],
+        bump
+    )]
+    pub hold_config: Account<'info, HoldConfig>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct HoldSlot<'info> {
+    #[account(mut)]
+    pub holder: Signer<'info>,
+    #[account(mut)]
+    pub slot: Account<'info, TimeSlot>,
+    #[account(seeds = [b"hold_config", slot.platform.as_ref()], bump = hold_config.bump)]
+    pub hold_config: Account<'info, HoldConfig>,
+    #[account(
+        init_if_needed,
+        payer = holder,
+        space = 8 + SlotHold::LEN,
+        seeds = [b"hold", slot.key().as_ref()],
+        bump
+    )]
+    pub hold: Account<'info, SlotHold>,
+    /// CHECK: Only when replacing an expired hold; must be its holder and receives its deposit and rent
+    #[account(mut)]
+    pub previous_holder: Option<UncheckedAccount<'info>>,
+    #[account(
+        init_if_needed,
+        payer = holder,
+        space = 8 + HoldQuota::LEN,
+        seeds = [b"hold_quota", slot.platform.as_ref(), holder.key().as_ref()],
+        bump
+    )]
+    pub hold_quota: Account<'info, HoldQuota>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct ReleaseHold<'info> {
+    pub caller: Signer<'info>,
+    #[account(mut)]
+    pub slot: Account<'info, TimeSlot>,
+    #[account(
+        mut,
+        close = holder,
+        seeds = [b"hold", slot.key().as_ref()],
+        bump = hold.bump
+    )]
+    pub hold: Account<'info, SlotHold>,
+    /// CHECK: Receives the deposit and rent; must be the recorded holder
+    #[account(mut, address = hold.holder @ ErrorCode::Unauthorized)]
+    pub holder: UncheckedAccount<'info>,
+}
+
+// ===================== Hold Events =====================
+
+#[event]
+pub struct HoldConfigSetEvent {
+    pub platform: Pubkey,
+    pub max_hold_sec: u32,
+    pub deposit_lamports: u64,
+    pub cooldown_sec: u32,
+}
+
+#[event]
+pub struct SlotHeldEvent {
+    pub slot: Pubkey,
+    pub holder: Pubkey,
+    pub deposit: u64,
+    pub expires_at: i64,
+}
+
+#[event]
+pub struct HoldReleasedEvent {
+    pub slot: Pubkey,
+    pub holder: Pubkey,
+    pub deposit: u64,
+}
+
+// ===================== Migration Accounts =====================
+
+#[derive(Accounts)]
+pub struct MigrateAccount<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    /// CHECK: Type and pre-upgrade size are checked in the handler
+    #[account(mut, owner = crate::ID)]
+    pub account: UncheckedAccount<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct MigrateSlot<'info> {
+    /// Slot's creator authority; pays the extra rent
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    /// CHECK: Type, pre-upgrade size and creator are checked in the handler
+    #[account(mut, owner = crate::ID)]
+    pub slot: UncheckedAccount<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+// ===================== Migration Events =====================
+
+#[event]
+pub struct AccountMigratedEvent {
+    pub account: Pubkey,
+    pub from_len: u32,
+    pub to_len: u32,
+}
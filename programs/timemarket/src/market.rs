@@ -1,7 +1,9 @@
 use anchor_lang::prelude::*;
-use anchor_spl::token_interface::{transfer_checked, mint_to, Mint, TokenAccount, TokenInterface, TransferChecked, MintTo};
+use anchor_lang::system_program;
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
 
 use crate::*;
+use crate::escrow::{pay_auction_t0, pay_auction_t1, EscrowPayment, Payout};
 use crate::ErrorCode;
 // Qualify error enum to avoid conflicts with anchor_lang::error::ErrorCode
 
@@ -16,6 +18,49 @@ pub fn init_platform(ctx: Context<InitPlatform>, platform_fee_bps: u16) -> Resul
     platform.fee_vault = ctx.accounts.fee_vault.key();
     platform.dispute_vault = ctx.accounts.dispute_vault.key();
     platform.bump = ctx.bumps.platform;
+
+    // The initial mint is the first registry entry
+    let accepted = &mut ctx.accounts.accepted_mint;
+    accepted.platform = platform.key();
+    accepted.mint = platform.mint;
+    accepted.fee_vault = platform.fee_vault;
+    accepted.dispute_vault = platform.dispute_vault;
+    accepted.enabled = true;
+    accepted.bump = ctx.bumps.accepted_mint;
+    Ok(())
+}
+
+pub fn add_accepted_mint(ctx: Context<AddAcceptedMint>) -> Result<()> {
+    let accepted = &mut ctx.accounts.accepted_mint;
+    accepted.platform = ctx.accounts.platform.key();
+    accepted.mint = ctx.accounts.mint.key();
+    accepted.fee_vault = ctx.accounts.fee_vault.key();
+    accepted.dispute_vault = ctx.accounts.dispute_vault.key();
+    accepted.enabled = true;
+    accepted.bump = ctx.bumps.accepted_mint;
+    emit!(AcceptedMintUpdatedEvent { platform: accepted.platform, mint: accepted.mint, enabled: true });
+    Ok(())
+}
+
+// Native SOL slots pay fees and dispute withholds to these wallets
+pub fn set_sol_receivers(ctx: Context<SetSolReceivers>, fee_receiver: Pubkey, dispute_receiver: Pubkey) -> Result<()> {
+    require!(
+        fee_receiver != Pubkey::default() && dispute_receiver != Pubkey::default(),
+        crate::ErrorCode::InvalidSolReceiver
+    );
+    let receivers = &mut ctx.accounts.sol_receivers;
+    receivers.platform = ctx.accounts.platform.key();
+    receivers.fee_receiver = fee_receiver;
+    receivers.dispute_receiver = dispute_receiver;
+    receivers.bump = ctx.bumps.sol_receivers;
+    emit!(SolReceiversSetEvent { platform: receivers.platform, fee_receiver, dispute_receiver });
+    Ok(())
+}
+
+pub fn set_accepted_mint_enabled(ctx: Context<SetAcceptedMintEnabled>, enabled: bool) -> Result<()> {
+    let accepted = &mut ctx.accounts.accepted_mint;
+    accepted.enabled = enabled;
+    emit!(AcceptedMintUpdatedEvent { platform: accepted.platform, mint: accepted.mint, enabled });
     Ok(())
 }
 
@@ -33,6 +78,8 @@ pub fn init_creator_profile(
     profile.payout_wallet = payout_wallet;
     profile.fee_bps_override = fee_bps_override;
     profile.platform = ctx.accounts.platform.key();
+    profile.slot_count = 0;
+    profile.series_count = 0;
     profile.bump = ctx.bumps.profile;
     Ok(())
 }
@@ -56,9 +103,72 @@ pub fn update_creator_profile(
 }
 
 pub fn create_time_slot(ctx: Context<CreateTimeSlot>, params: CreateSlotParams) -> Result<()> {
+    validate_slot_params(&params)?;
+    check_slot_mint(
+        params.mint,
+        params.payment_kind,
+        ctx.accounts.platform.key(),
+        ctx.accounts.accepted_mint.as_deref(),
+    )?;
+
+    let profile = &mut ctx.accounts.profile;
+    let profile_key = profile.key();
+    let slot = &mut ctx.accounts.slot;
+    write_slot(
+        slot,
+        profile,
+        profile_key,
+        ctx.accounts.platform.key(),
+        params,
+        ctx.bumps.slot,
+    );
+    profile.slot_count = profile.slot_count.checked_add(1).ok_or(ErrorCode::Overflow)?;
+    Ok(())
+}
+
+// Create several slots in one transaction; the whole batch is validated up front
+// and any failure reverts every slot in it.
+pub fn create_time_slots_batch<'info>(
+    ctx: Context<'_, '_, 'info, 'info, CreateTimeSlotsBatch<'info>>,
+    params: Vec<CreateSlotParams>,
+) -> Result<()> {
+    require!(!params.is_empty(), ErrorCode::InvalidCapacity);
+    require!(ctx.remaining_accounts.len() == params.len(), ErrorCode::InvalidSlotAccount);
+    let platform_key = ctx.accounts.platform.key();
+    for p in params.iter() {
+        validate_slot_params(p)?;
+        check_slot_mint(p.mint, p.payment_kind, platform_key, ctx.accounts.accepted_mint.as_deref())?;
+    }
+
+    let profile = &mut ctx.accounts.profile;
+    let profile_key = profile.key();
+    let payer = ctx.accounts.authority.to_account_info();
+    let system_program = ctx.accounts.system_program.to_account_info();
+    for (info, p) in ctx.remaining_accounts.iter().zip(params.into_iter()) {
+        let bump = create_slot_account(info, &payer, &system_program, &profile_key, profile.slot_count)?;
+        let mut slot: Account<TimeSlot> = Account::try_from_unchecked(info)?;
+        write_slot(&mut slot, profile, profile_key, platform_key, p, bump);
+        slot.exit(&crate::ID)?;
+        profile.slot_count = profile.slot_count.checked_add(1).ok_or(ErrorCode::Overflow)?;
+    }
+    Ok(())
+}
+
+// Shared validation for every path that creates a TimeSlot
+pub fn validate_slot_params(params: &CreateSlotParams) -> Result<()> {
     require!(params.start_ts < params.end_ts, ErrorCode::InvalidTimes);
     require!(params.capacity > 0, ErrorCode::InvalidCapacity);
     require!(params.min_increment_bps <= 10_000, ErrorCode::InvalidBps);
+    require!(params.resale_royalty_bps <= 10_000, ErrorCode::InvalidBps);
+    require!(params.max_resale_price != Some(0), ErrorCode::InvalidPrice);
+    if params.nft_ticket {
+        // One ticket per booking, minted through the SPL token program the slot pays with.
+        // The mint may come later from init_slot_nft; reservations fail until it exists.
+        require!(params.payment_kind == PaymentKind::Spl, ErrorCode::WrongPaymentKind);
+        require!(params.capacity == 1, ErrorCode::MultiCapacityUnsupported);
+        require!(params.mode != Mode::SealedBid, ErrorCode::WrongMode);
+    }
+    validate_cancel_policy(&params.cancel_policy)?;
 
     match params.mode {
         Mode::Stable => {
@@ -75,18 +185,57 @@ pub fn create_time_slot(ctx: Context<CreateTimeSlot>, params: CreateSlotParams)
         }
     }
 
-    let slot = &mut ctx.accounts.slot;
-    slot.creator_profile = ctx.accounts.profile.key();
-    slot.creator_authority = ctx.accounts.profile.authority;
-    slot.platform = ctx.accounts.platform.key();
-    slot.mint = ctx.accounts.mint.key();
+    if let Some(quote) = &params.usd_quote {
+        require!(params.payment_kind == PaymentKind::Spl, ErrorCode::WrongPaymentKind);
+        require!(quote.max_conf_bps <= 10_000, ErrorCode::InvalidBps);
+        match params.mode {
+            Mode::Stable => require!(quote.price_usd_cents > 0, ErrorCode::InvalidPrice),
+            Mode::EnglishAuction => require!(quote.buy_now_usd_cents.is_some(), ErrorCode::InvalidPrice),
+            // Sealed bids are placed in mint units; there is nothing to quote
+            Mode::SealedBid => return err!(ErrorCode::WrongMode),
+        }
+    }
+
+    if let Some(gate) = &params.gate {
+        require!(gate.key != Pubkey::default(), ErrorCode::InvalidGate);
+        require!(gate.min_amount > 0, ErrorCode::InvalidGate);
+        // Tickets change hands outside the program, where the gate cannot be re-checked
+        require!(!params.nft_ticket, ErrorCode::NftTicketMode);
+    }
+    // Same for allowlists: a traded ticket would skip the list
+    require!(!(params.nft_ticket && params.allowlist_root.is_some()), ErrorCode::NftTicketMode);
+
+    if let Some(window) = params.approval_window_sec {
+        // Requests hold the list price until answered, so only fixed-price Stable slots qualify
+        require!(window > 0, ErrorCode::InvalidTimes);
+        require!(params.mode == Mode::Stable, ErrorCode::WrongMode);
+        require!(params.usd_quote.is_none(), ErrorCode::InvalidPrice);
+        require!(!params.nft_ticket, ErrorCode::NftTicketMode);
+    }
+    Ok(())
+}
+
+// Populate a freshly initialized slot; index is the profile's current slot_count
+pub fn write_slot(
+    slot: &mut TimeSlot,
+    profile: &CreatorProfile,
+    profile_key: Pubkey,
+    platform: Pubkey,
+    params: CreateSlotParams,
+    bump: u8,
+) {
+    slot.creator_profile = profile_key;
+    slot.index = profile.slot_count;
+    slot.creator_authority = profile.authority;
+    slot.platform = platform;
+    slot.mint = params.mint;
     slot.start_ts = params.start_ts;
     slot.end_ts = params.end_ts;
     slot.tz_offset_min = params.tz_offset_min;
     slot.subject_hash = params.subject_hash;
     slot.venue_hash = params.venue_hash;
     slot.mode = params.mode;
-    slot.state = SlotState::Open;
+    slot.state = if params.draft { SlotState::Draft } else { SlotState::Open };
     slot.frozen = false;
     slot.buyer_checked_in = false;
     slot.capacity_total = params.capacity;
@@ -98,15 +247,212 @@ pub fn create_time_slot(ctx: Context<CreateTimeSlot>, params: CreateSlotParams)
     slot.auction_start_ts = params.auction_start_ts;
     slot.auction_end_ts = params.auction_end_ts;
     slot.anti_sniping_sec = params.anti_sniping_sec;
-    slot.bump = ctx.bumps.slot;
+    slot.cancel_policy = params.cancel_policy;
+    slot.payment_kind = params.payment_kind;
+    slot.usd_quote = params.usd_quote;
+    slot.transfers_disabled = params.transfers_disabled;
+    slot.resale_royalty_bps = params.resale_royalty_bps;
+    slot.max_resale_price = params.max_resale_price;
+    slot.nft_ticket = params.nft_ticket;
+    slot.gate = params.gate;
+    slot.allowlist_root = params.allowlist_root;
+    slot.approval_window_sec = params.approval_window_sec;
+    slot.booking_round = 0;
+    slot.publish_at = None;
+    slot.hold = None;
+    slot.reschedule = None;
+    slot.series = Pubkey::default();
+    slot.bump = bump;
+}
+
+// The slot's current terms in creation form, so edited slots go through the same validation
+pub fn slot_params(slot: &TimeSlot) -> CreateSlotParams {
+    CreateSlotParams {
+        start_ts: slot.start_ts,
+        end_ts: slot.end_ts,
+        tz_offset_min: slot.tz_offset_min,
+        subject_hash: slot.subject_hash,
+        venue_hash: slot.venue_hash,
+        mode: slot.mode,
+        capacity: slot.capacity_total,
+        nft_mint: (slot.nft_mint != Pubkey::default()).then_some(slot.nft_mint),
+        price: slot.price,
+        min_increment_bps: slot.min_increment_bps,
+        buy_now: slot.buy_now,
+        auction_start_ts: slot.auction_start_ts,
+        auction_end_ts: slot.auction_end_ts,
+        anti_sniping_sec: slot.anti_sniping_sec,
+        cancel_policy: slot.cancel_policy.clone(),
+        payment_kind: slot.payment_kind,
+        mint: slot.mint,
+        usd_quote: slot.usd_quote,
+        transfers_disabled: slot.transfers_disabled,
+        resale_royalty_bps: slot.resale_royalty_bps,
+        max_resale_price: slot.max_resale_price,
+        nft_ticket: slot.nft_ticket,
+        gate: slot.gate,
+        allowlist_root: slot.allowlist_root,
+        approval_window_sec: slot.approval_window_sec,
+        draft: slot.state == SlotState::Draft,
+    }
+}
+
+// Change the terms of an Open slot before anything has been sold, bid or requested on it.
+// The result must pass the same checks as creation.
+// Whether an Open slot's terms may still change: nothing sold, no buyer, bids, sealed commits,
+// booking requests or live checkout hold. `escrow_info` and `commit_info` are the slot's escrow
+// and commit store PDAs; both are created lazily and an uninitialized one has seen no activity.
+pub fn is_untouched(slot: &TimeSlot, escrow_info: &AccountInfo, commit_info: &AccountInfo, now: i64) -> Result<bool> {
+    if slot.state != SlotState::Open || slot.capacity_sold > 0 {
+        return Ok(false);
+    }
+    // Terms stay fixed while a buyer is mid-checkout
+    if slot.hold.is_some_and(|h| now < h.expires_at) {
+        return Ok(false);
+    }
+    if !escrow_info.data_is_empty() {
+        let escrow = Escrow::try_deserialize(&mut &escrow_info.try_borrow_data()?[..])?;
+        if escrow.buyer.is_some() || escrow.amount_locked > 0 || escrow.requests_locked > 0 {
+            return Ok(false);
+        }
+    }
+    if !commit_info.data_is_empty() {
+        let store = CommitStore::try_deserialize(&mut &commit_info.try_borrow_data()?[..])?;
+        if store.count > 0 {
+            return Ok(false);
+        }
+    }
+    Ok(true)
+}
+
+pub fn update_slot(ctx: Context<UpdateSlot>, params: UpdateSlotParams) -> Result<()> {
+    let slot = &mut ctx.accounts.slot;
+    require!(slot.state == SlotState::Open, ErrorCode::InvalidState);
+    let now = Clock::get()?.unix_timestamp;
+    require!(slot.hold.map_or(true, |h| now >= h.expires_at), ErrorCode::SlotHeld);
+    require!(
+        is_untouched(slot, &ctx.accounts.escrow, &ctx.accounts.commit_store, now)?,
+        ErrorCode::SlotHasActivity
+    );
+
+    let mut changed = Vec::new();
+    if let Some(v) = params.price.filter(|v| *v != slot.price) {
+        slot.price = v;
+        changed.push(SlotField::Price);
+    }
+    if let Some(v) = params.buy_now.filter(|v| *v != slot.buy_now) {
+        slot.buy_now = v;
+        changed.push(SlotField::BuyNow);
+    }
+    if let Some(v) = params.min_increment_bps.filter(|v| *v != slot.min_increment_bps) {
+        slot.min_increment_bps = v;
+        changed.push(SlotField::MinIncrementBps);
+    }
+    if let Some(v) = params.anti_sniping_sec.filter(|v| *v != slot.anti_sniping_sec) {
+        slot.anti_sniping_sec = v;
+        changed.push(SlotField::AntiSnipingSec);
+    }
+    if let Some(v) = params.subject_hash.filter(|v| *v != slot.subject_hash) {
+        slot.subject_hash = v;
+        changed.push(SlotField::SubjectHash);
+    }
+    if let Some(v) = params.venue_hash.filter(|v| *v != slot.venue_hash) {
+        slot.venue_hash = v;
+        changed.push(SlotField::VenueHash);
+    }
+    validate_slot_params(&slot_params(slot))?;
+    emit!(SlotUpdatedEvent { slot: slot.key(), changed });
+    Ok(())
+}
+
+// SPL slots must be priced in an enabled registry mint; SOL slots carry no mint
+pub fn check_slot_mint(
+    mint: Pubkey,
+    kind: PaymentKind,
+    platform: Pubkey,
+    accepted: Option<&AcceptedMint>,
+) -> Result<()> {
+    match kind {
+        PaymentKind::Spl => {
+            let accepted = accepted.ok_or(ErrorCode::MintNotAccepted)?;
+            require_keys_eq!(accepted.platform, platform, ErrorCode::MintNotAccepted);
+            require_keys_eq!(accepted.mint, mint, ErrorCode::MintNotAccepted);
+            require!(accepted.enabled, ErrorCode::MintNotAccepted);
+        }
+        PaymentKind::Sol => require_keys_eq!(mint, Pubkey::default(), ErrorCode::MintNotAccepted),
+    }
     Ok(())
 }
 
+// Create the slot PDA at the profile's next index from an account passed in
+// remaining_accounts (used by multi-slot instructions). Mirrors Anchor's `init`.
+pub fn create_slot_account<'info>(
+    slot_info: &AccountInfo<'info>,
+    payer: &AccountInfo<'info>,
+    system_program: &AccountInfo<'info>,
+    profile_key: &Pubkey,
+    index: u64,
+) -> Result<u8> {
+    let (expected, bump) = slot_address(profile_key, index);
+    require_keys_eq!(slot_info.key(), expected, ErrorCode::InvalidSlotAccount);
+    require!(slot_info.data_is_empty(), ErrorCode::InvalidSlotAccount);
+
+    let index_bytes = index.to_le_bytes();
+    let bump_seed = [bump];
+    let seeds: &[&[u8]] = &[b"slot", profile_key.as_ref(), &index_bytes, &bump_seed];
+    let signer: &[&[&[u8]]] = &[seeds];
+    let space = 8 + TimeSlot::LEN;
+    let required = Rent::get()?.minimum_balance(space);
+    let current = slot_info.lamports();
+    if current == 0 {
+        system_program::create_account(
+            CpiContext::new_with_signer(
+                system_program.clone(),
+                system_program::CreateAccount { from: payer.clone(), to: slot_info.clone() },
+                signer,
+            ),
+            required,
+            space as u64,
+            &crate::ID,
+        )?;
+    } else {
+        // Address was pre-funded: top up, then allocate and assign
+        let top_up = required.saturating_sub(current);
+        if top_up > 0 {
+            system_program::transfer(
+                CpiContext::new(
+                    system_program.clone(),
+                    system_program::Transfer { from: payer.clone(), to: slot_info.clone() },
+                ),
+                top_up,
+            )?;
+        }
+        system_program::allocate(
+            CpiContext::new_with_signer(
+                system_program.clone(),
+                system_program::Allocate { account_to_allocate: slot_info.clone() },
+                signer,
+            ),
+            space as u64,
+        )?;
+        system_program::assign(
+            CpiContext::new_with_signer(
+                system_program.clone(),
+                system_program::Assign { account_to_assign: slot_info.clone() },
+                signer,
+            ),
+            &crate::ID,
+        )?;
+    }
+    Ok(bump)
+}
+
 pub fn init_bid_book(ctx: Context<InitBidBook>) -> Result<()> {
     let bidbook = &mut ctx.accounts.bidbook;
     bidbook.slot = ctx.accounts.slot.key();
     bidbook.highest_bid = 0;
     bidbook.highest_bidder = Pubkey::default();
+    bidbook.highest_beneficiary = Pubkey::default();
     bidbook.next_min_bid = 0;
     bidbook.last_bid_ts = 0;
     bidbook.bump = ctx.bumps.bidbook;
@@ -147,9 +493,17 @@ pub fn init_auto_bid_store(ctx: Context<InitAutoBidStore>, max_entries: u16) ->
     Ok(())
 }
 
-pub fn bid_commit(ctx: Context<BidCommit>, commitment_hash: [u8; 32]) -> Result<()> {
+pub fn bid_commit(ctx: Context<BidCommit>, commitment_hash: [u8; 32], allowlist: Option<AllowlistProof>) -> Result<()> {
     let slot = &ctx.accounts.slot;
     require!(slot.mode == Mode::SealedBid, ErrorCode::WrongMode);
+    check_on_sale(slot)?;
+    crate::gate::check_gate(
+        slot,
+        ctx.accounts.bidder.key(),
+        ctx.accounts.gate_token.as_ref(),
+        ctx.accounts.gate_metadata.as_deref(),
+    )?;
+    crate::allowlist::check_allowlist(slot, ctx.accounts.bidder.key(), allowlist.as_ref())?;
     let store = &mut ctx.accounts.commit_store;
     // Use persisted max_entries instead of Vec capacity (capacity is not serialized across txns)
     require!(store.count < store.max_entries, ErrorCode::InvalidCapacity);
@@ -211,30 +565,53 @@ pub fn auction_start(ctx: Context<AuctionStart>) -> Result<()> {
     Ok(())
 }
 
-pub fn buy_now(ctx: Context<BuyNow>) -> Result<()> {
+pub fn buy_now<'info>(ctx: Context<'_, '_, 'info, 'info, BuyNow<'info>>, max_payment: Option<u64>, allowlist: Option<AllowlistProof>) -> Result<()> {
+    let payment = EscrowPayment::spl(
+        &ctx.accounts.slot,
+        &ctx.accounts.escrow,
+        ctx.accounts.token_program.to_account_info(),
+        ctx.accounts.escrow_vault.to_account_info(),
+        ctx.accounts.mint.to_account_info(),
+        ctx.accounts.mint.decimals,
+        ctx.remaining_accounts,
+    )?;
     let slot = &mut ctx.accounts.slot;
     require!(!slot.frozen, ErrorCode::Frozen);
+    let beneficiary = ctx.accounts.beneficiary.key();
+    crate::gate::check_gate(
+        slot,
+        beneficiary,
+        ctx.accounts.gate_token.as_ref(),
+        ctx.accounts.gate_metadata.as_deref(),
+    )?;
+    let allowlist_cap = crate::allowlist::check_allowlist(slot, beneficiary, allowlist.as_ref())?;
+    crate::allowlist::consume_allowlist(
+        ctx.accounts.allowlist_claim.as_mut(),
+        slot.key(),
+        beneficiary,
+        allowlist_cap,
+        ctx.bumps.allowlist_claim,
+    )?;
     let book = &mut ctx.accounts.bidbook;
     require!(slot.mode == Mode::EnglishAuction, ErrorCode::WrongMode);
     require!(slot.capacity_total == 1, ErrorCode::MultiCapacityUnsupported);
     require!(slot.state == SlotState::Open || slot.state == SlotState::AuctionLive, ErrorCode::InvalidState);
-    // ensure buy_now price is set
-    let price = slot.buy_now.ok_or(ErrorCode::InvalidPrice)?;
-    // transfer bidder -> escrow vault
+    check_on_sale(slot)?;
     let decimals = ctx.accounts.mint.decimals;
-    transfer_checked(
-        CpiContext::new(
-            ctx.accounts.token_program.to_account_info(),
-            TransferChecked {
-                from: ctx.accounts.bidder_token.to_account_info(),
-                mint: ctx.accounts.mint.to_account_info(),
-                to: ctx.accounts.escrow_vault.to_account_info(),
-                authority: ctx.accounts.bidder.to_account_info(),
-            },
-        ),
-        price,
-        decimals,
-    )?;
+    // ensure buy_now price is set; USD-priced slots convert it at the feed's current price
+    let price = match slot.usd_quote {
+        Some(quote) => {
+            let usd_cents = quote.buy_now_usd_cents.ok_or(ErrorCode::InvalidPrice)?;
+            let feed = ctx.accounts.price_feed.as_deref();
+            let price = crate::oracle::usd_to_mint_units(&quote, usd_cents, feed, decimals)?;
+            emit!(UsdPriceQuotedEvent { slot: slot.key(), usd_cents, amount: price });
+            price
+        }
+        None => slot.buy_now.ok_or(ErrorCode::InvalidPrice)?,
+    };
+    crate::oracle::check_max_payment(price, max_payment, slot.usd_quote.is_some())?;
+    // transfer bidder -> escrow vault
+    payment.collect(&ctx.accounts.bidder_token.to_account_info(), &ctx.accounts.bidder.to_account_info(), price)?;
 
     // bind escrow to buyer and set highest
     let escrow = &mut ctx.accounts.escrow;
@@ -242,78 +619,43 @@ pub fn buy_now(ctx: Context<BuyNow>) -> Result<()> {
         .amount_locked
         .checked_add(price)
         .ok_or(ErrorCode::Overflow)?;
-    escrow.buyer = Some(ctx.accounts.bidder.key());
+    escrow.buyer = Some(beneficiary);
+    escrow.payer = Some(ctx.accounts.bidder.key());
     book.highest_bid = price;
     book.highest_bidder = ctx.accounts.bidder.key();
+    book.highest_beneficiary = beneficiary;
 
     // Payout T0 immediately like auction_end
-    let slot_key = slot.key();
-    let escrow_bump = escrow.bump;
-    let token_program = ctx.accounts.token_program.to_account_info();
-    let escrow_vault = ctx.accounts.escrow_vault.to_account_info();
-    let creator_payout = ctx.accounts.creator_payout_ata.to_account_info();
-    let fee_vault = ctx.accounts.fee_vault.to_account_info();
-    let mint = ctx.accounts.mint.to_account_info();
-    let escrow_info = ctx.accounts.escrow.to_account_info();
-    let bump_seed = [escrow_bump];
-    let seeds: &[&[u8]] = &[b"escrow", slot_key.as_ref(), &bump_seed];
-    let signer: &[&[&[u8]]] = &[seeds];
-
     let eff_bps = effective_fee_bps(&ctx.accounts.platform, &ctx.accounts.profile);
-    let total_fee = mul_bps_u64(price, eff_bps as u64)?;
-    let t0_base = mul_bps_u64(price, AUCTION_T0_BPS)?;
-    let t0_fee = mul_bps_u64(total_fee, AUCTION_T0_BPS)?;
-    let t0_creator = t0_base.checked_sub(t0_fee).ok_or(ErrorCode::Overflow)?;
-    transfer_checked(
-        CpiContext::new_with_signer(
-            token_program.clone(),
-            TransferChecked {
-                from: escrow_vault.clone(),
-                mint: mint.clone(),
-                to: creator_payout.clone(),
-                authority: escrow_info.clone(),
-            },
-            signer,
-        ),
-        t0_creator,
-        decimals,
-    )?;
-    transfer_checked(
-        CpiContext::new_with_signer(
-            token_program,
-            TransferChecked {
-                from: escrow_vault,
-                mint,
-                to: fee_vault,
-                authority: escrow_info,
-            },
-            signer,
-        ),
-        t0_fee,
-        decimals,
+    pay_auction_t0(
+        &payment,
+        escrow,
+        &ctx.accounts.creator_payout_ata.to_account_info(),
+        &ctx.accounts.fee_vault.to_account_info(),
+        price,
+        eff_bps,
     )?;
-    escrow.amount_locked = escrow.amount_locked.checked_sub(t0_creator + t0_fee).ok_or(ErrorCode::Overflow)?;
     slot.state = SlotState::Locked;
-    // Mint NFT to buyer if configured
-    if slot.nft_mint != Pubkey::default() && ctx.accounts.nft_mint.key() == slot.nft_mint {
-        let slot_key = slot.key();
-        let (_pda, bump) = Pubkey::find_program_address(&[b"nft_auth", slot_key.as_ref()], &crate::ID);
-        let seeds: &[&[u8]] = &[b"nft_auth", slot_key.as_ref(), &[bump]];
-        let signer: &[&[&[u8]]] = &[seeds];
-        mint_to(
-            CpiContext::new_with_signer(
-                ctx.accounts.token_program.to_account_info(),
-                MintTo {
-                    mint: ctx.accounts.nft_mint.to_account_info(),
-                    to: ctx.accounts.buyer_nft_ata.to_account_info(),
-                    authority: ctx.accounts.nft_auth.to_account_info(),
-                },
-                signer,
-            ),
-            1,
+    // Mint NFT to the beneficiary if configured; in ticket mode this is the ticket
+    if slot.nft_ticket {
+        crate::ticket::mint_ticket(
+            slot,
+            &ctx.accounts.token_program.to_account_info(),
+            Some(&ctx.accounts.nft_mint),
+            Some(&ctx.accounts.buyer_nft_ata),
+            Some(&ctx.accounts.nft_auth.to_account_info()),
+            beneficiary,
+        )?;
+    } else if slot.nft_mint != Pubkey::default() && ctx.accounts.nft_mint.key() == slot.nft_mint {
+        crate::nft::mint_slot_nft(
+            slot,
+            &ctx.accounts.token_program.to_account_info(),
+            &ctx.accounts.nft_mint.to_account_info(),
+            &ctx.accounts.buyer_nft_ata.to_account_info(),
+            &ctx.accounts.nft_auth.to_account_info(),
         )?;
     }
-    emit!(AuctionEndedEvent { slot: slot_key, winner: book.highest_bidder, winning_bid: book.highest_bid });
+    emit!(AuctionEndedEvent { slot: slot.key(), winner: book.highest_bidder, winning_bid: book.highest_bid });
     Ok(())
 }
 
@@ -346,14 +688,51 @@ pub fn auction_update_end(ctx: Context<AuctionUpdateEnd>, new_end_ts: i64) -> Re
     Ok(())
 }
 
-pub fn bid_place(ctx: Context<BidPlace>, bid_amount: u64, _max_auto_bid: Option<u64>) -> Result<()> {
-    let slot = &mut ctx.accounts.slot;
+pub fn bid_place<'info>(ctx: Context<'_, '_, 'info, 'info, BidPlace<'info>>, bid_amount: u64, _max_auto_bid: Option<u64>, allowlist: Option<AllowlistProof>) -> Result<()> {
+    let payment = EscrowPayment::spl(
+        &ctx.accounts.slot,
+        &ctx.accounts.escrow,
+        ctx.accounts.token_program.to_account_info(),
+        ctx.accounts.escrow_vault.to_account_info(),
+        ctx.accounts.mint.to_account_info(),
+        ctx.accounts.mint.decimals,
+        ctx.remaining_accounts,
+    )?;
+    let beneficiary = ctx.accounts.beneficiary.as_ref().map_or(ctx.accounts.bidder.key(), |b| b.key());
+    crate::gate::check_gate(
+        &ctx.accounts.slot,
+        beneficiary,
+        ctx.accounts.gate_token.as_ref(),
+        ctx.accounts.gate_metadata.as_deref(),
+    )?;
+    crate::allowlist::check_allowlist(&ctx.accounts.slot, beneficiary, allowlist.as_ref())?;
+    let min_required = check_bid(&ctx.accounts.slot, &ctx.accounts.bidbook, ctx.accounts.bidder.key(), bid_amount)?;
+
+    // Transfer bidder -> escrow vault (full bid amount)
+    payment.collect(&ctx.accounts.bidder_token.to_account_info(), &ctx.accounts.bidder.to_account_info(), bid_amount)?;
+
+    record_bid(
+        &mut ctx.accounts.slot,
+        &mut ctx.accounts.bidbook,
+        &mut ctx.accounts.escrow,
+        &mut ctx.accounts.refund_queue,
+        &mut ctx.accounts.auto_bid_store,
+        ctx.accounts.bidder.key(),
+        beneficiary,
+        bid_amount,
+        min_required,
+        _max_auto_bid,
+    )
+}
+
+// English auction bid validation shared by the SPL and SOL paths; returns the minimum accepted bid
+pub fn check_bid(slot: &TimeSlot, book: &BidBook, bidder: Pubkey, bid_amount: u64) -> Result<u64> {
     require!(!slot.frozen, ErrorCode::Frozen);
-    let book = &mut ctx.accounts.bidbook;
     require!(slot.mode == Mode::EnglishAuction, ErrorCode::WrongMode);
     require!(slot.capacity_total == 1, ErrorCode::MultiCapacityUnsupported);
     require!(slot.state == SlotState::AuctionLive, ErrorCode::InvalidState);
-    require!(ctx.accounts.bidder.key() != slot.creator_authority, ErrorCode::Unauthorized);
+    check_on_sale(slot)?;
+    require!(bidder != slot.creator_authority, ErrorCode::Unauthorized);
     // Refunds are handled via queue; no need to block new bids.
 
     // Enforce min increment
@@ -364,49 +743,48 @@ pub fn bid_place(ctx: Context<BidPlace>, bid_amount: u64, _max_auto_bid: Option<
         book.highest_bid.checked_add(inc).ok_or(ErrorCode::Overflow)?
     };
     require!(bid_amount >= min_required, ErrorCode::BidTooLow);
+    Ok(min_required)
+}
 
+// Book-keeping after the bid funds reached escrow (SPL vault or escrow lamports)
+#[allow(clippy::too_many_arguments)]
+pub fn record_bid(
+    slot: &mut Account<TimeSlot>,
+    book: &mut Account<BidBook>,
+    escrow: &mut Account<Escrow>,
+    refund_queue: &mut Account<RefundQueue>,
+    store: &mut Account<AutoBidStore>,
+    bidder: Pubkey,
+    beneficiary: Pubkey,
+    bid_amount: u64,
+    min_required: u64,
+    _max_auto_bid: Option<u64>,
+) -> Result<()> {
     // Register/Update auto-bid max for this bidder if provided
     if let Some(max) = _max_auto_bid {
-        let store = &mut ctx.accounts.auto_bid_store;
         // ensure capacity
         if store
             .entries
             .iter()
-            .find(|e| e.bidder == ctx.accounts.bidder.key())
+            .find(|e| e.bidder == bidder)
             .is_none()
         {
             require!(store.count < store.max_entries, ErrorCode::InvalidCapacity);
-            store.entries.push(AutoBidEntry { bidder: ctx.accounts.bidder.key(), max_bid: max });
+            store.entries.push(AutoBidEntry { bidder, max_bid: max });
             store.count = store.count.saturating_add(1);
         } else {
             // update existing
             for e in store.entries.iter_mut() {
-                if e.bidder == ctx.accounts.bidder.key() {
+                if e.bidder == bidder {
                     e.max_bid = max;
                 }
             }
         }
     }
 
-    // Transfer bidder -> escrow vault (full bid amount)
-    let decimals = ctx.accounts.mint.decimals;
-    transfer_checked(
-        CpiContext::new(
-            ctx.accounts.token_program.to_account_info(),
-            TransferChecked {
-                from: ctx.accounts.bidder_token.to_account_info(),
-                mint: ctx.accounts.mint.to_account_info(),
-                to: ctx.accounts.escrow_vault.to_account_info(),
-                authority: ctx.accounts.bidder.to_account_info(),
-            },
-        ),
-        bid_amount,
-        decimals,
-    )?;
-
     // Enqueue refund for previous highest (if any)
     if book.highest_bid > 0 {
-        let q = &mut ctx.accounts.refund_queue;
+        let q = &mut *refund_queue;
         require!(q.count < q.max_entries, ErrorCode::InvalidCapacity);
         q.entries.push(RefundEntry { bidder: book.highest_bidder, amount: book.highest_bid });
         q.count = q.count.saturating_add(1);
@@ -423,11 +801,11 @@ pub fn bid_place(ctx: Context<BidPlace>, bid_amount: u64, _max_auto_bid: Option<
 
     // Update highest with current bid
     book.highest_bid = bid_amount;
-    book.highest_bidder = ctx.accounts.bidder.key();
+    book.highest_bidder = bidder;
+    book.highest_beneficiary = beneficiary;
     book.next_min_bid = min_required; // for display; next call recomputes
     book.last_bid_ts = now;
     // Keep escrow.amount_locked equal to highest bid + any pending refund
-    let escrow = &mut ctx.accounts.escrow;
     escrow.amount_locked = escrow
         .amount_locked
         .checked_add(bid_amount)
@@ -436,7 +814,6 @@ pub fn bid_place(ctx: Context<BidPlace>, bid_amount: u64, _max_auto_bid: Option<
 
     // After placing this bid, see if any other auto-bidder can outbid up to their max
     // Simple loop: find competitor with highest max that exceeds next min, place a synthetic outbid
-    let store = &mut ctx.accounts.auto_bid_store;
     let mut next_min = {
         if book.highest_bid == 0 { slot.price } else { let inc = core::cmp::max(1u64, mul_bps_u64(book.highest_bid, slot.min_increment_bps as u64)?); book.highest_bid.checked_add(inc).ok_or(ErrorCode::Overflow)? }
     };
@@ -457,7 +834,7 @@ pub fn bid_place(ctx: Context<BidPlace>, bid_amount: u64, _max_auto_bid: Option<
         let counter = next_min.min(comp_max);
         // mark refund for previous highest
         if book.highest_bid > 0 {
-            let q = &mut ctx.accounts.refund_queue;
+            let q = &mut *refund_queue;
             require!(q.count < q.max_entries, ErrorCode::InvalidCapacity);
             q.entries.push(RefundEntry { bidder: book.highest_bidder, amount: book.highest_bid });
             q.count = q.count.saturating_add(1);
@@ -465,6 +842,7 @@ pub fn bid_place(ctx: Context<BidPlace>, bid_amount: u64, _max_auto_bid: Option<
         // update highest to competitor
         book.highest_bid = counter;
         book.highest_bidder = comp_bidder;
+        book.highest_beneficiary = comp_bidder;
         book.last_bid_ts = now;
         // recompute next_min
         next_min = {
@@ -482,66 +860,46 @@ pub fn bid_place(ctx: Context<BidPlace>, bid_amount: u64, _max_auto_bid: Option<
     Ok(())
 }
 
-pub fn bid_outbid_refund(ctx: Context<BidOutbidRefund>) -> Result<()> {
-    let slot = &ctx.accounts.slot;
-    require!(!slot.frozen, ErrorCode::Frozen);
+pub fn bid_outbid_refund<'info>(ctx: Context<'_, '_, 'info, 'info, BidOutbidRefund<'info>>) -> Result<()> {
+    let payment = EscrowPayment::spl(
+        &ctx.accounts.slot,
+        &ctx.accounts.escrow,
+        ctx.accounts.token_program.to_account_info(),
+        ctx.accounts.escrow_vault.to_account_info(),
+        ctx.accounts.mint.to_account_info(),
+        ctx.accounts.mint.decimals,
+        ctx.remaining_accounts,
+    )?;
+    require!(!ctx.accounts.slot.frozen, ErrorCode::Frozen);
     let q = &mut ctx.accounts.refund_queue;
     require!(q.count > 0, ErrorCode::NothingToRefund);
     let idx = q.cursor as usize;
     let entry = q.entries.get(idx).cloned().ok_or(ErrorCode::NothingToRefund)?;
     require_keys_eq!(entry.bidder, ctx.accounts.prev_bidder.key(), ErrorCode::Unauthorized);
     // Transfer escrow -> prev bidder token
-    let decimals = ctx.accounts.mint.decimals;
-    let slot_key = ctx.accounts.slot.key();
-    let escrow_bump = ctx.accounts.escrow.bump;
-    let token_program = ctx.accounts.token_program.to_account_info();
-    let escrow_vault = ctx.accounts.escrow_vault.to_account_info();
-    let mint = ctx.accounts.mint.to_account_info();
-    let prev_bidder_token = ctx.accounts.prev_bidder_token.to_account_info();
-    let escrow_info = ctx.accounts.escrow.to_account_info();
-    let prev_bidder_key = ctx.accounts.prev_bidder.key();
-    let bump_seed = [escrow_bump];
-    let seeds: &[&[u8]] = &[b"escrow", slot_key.as_ref(), &bump_seed];
-    let signer: &[&[&[u8]]] = &[seeds];
-    let escrow = &mut ctx.accounts.escrow;
     let amt = entry.amount;
-    transfer_checked(
-        CpiContext::new_with_signer(
-            token_program,
-            TransferChecked {
-                from: escrow_vault,
-                mint,
-                to: prev_bidder_token,
-                authority: escrow_info.clone(),
-            },
-            signer,
-        ),
-        amt,
-        decimals,
-    )?;
+    payment.pay(Payout { to: &ctx.accounts.prev_bidder_token.to_account_info(), amount: amt })?;
     // Adjust escrow locked sum
+    let escrow = &mut ctx.accounts.escrow;
     escrow.amount_locked = escrow.amount_locked.checked_sub(amt).ok_or(ErrorCode::Overflow)?;
-    emit!(OutbidRefundedEvent { slot: slot_key, to: prev_bidder_key, amount: amt });
+    emit!(OutbidRefundedEvent { slot: ctx.accounts.slot.key(), to: ctx.accounts.prev_bidder.key(), amount: amt });
     // Advance queue
     q.cursor = q.cursor.saturating_add(1);
     q.count = q.count.saturating_sub(1);
     Ok(())
 }
 
-pub fn auction_end(ctx: Context<AuctionEnd>) -> Result<()> {
-    let decimals = ctx.accounts.mint.decimals;
+pub fn auction_end<'info>(ctx: Context<'_, '_, 'info, 'info, AuctionEnd<'info>>) -> Result<()> {
+    let payment = EscrowPayment::spl(
+        &ctx.accounts.slot,
+        &ctx.accounts.escrow,
+        ctx.accounts.token_program.to_account_info(),
+        ctx.accounts.escrow_vault.to_account_info(),
+        ctx.accounts.mint.to_account_info(),
+        ctx.accounts.mint.decimals,
+        ctx.remaining_accounts,
+    )?;
     let slot_key = ctx.accounts.slot.key();
-    let escrow_bump = ctx.accounts.escrow.bump;
-    let token_program = ctx.accounts.token_program.to_account_info();
-    let escrow_vault = ctx.accounts.escrow_vault.to_account_info();
-    let creator_payout = ctx.accounts.creator_payout_ata.to_account_info();
-    let fee_vault = ctx.accounts.fee_vault.to_account_info();
-    let mint = ctx.accounts.mint.to_account_info();
-    let escrow_info = ctx.accounts.escrow.to_account_info();
-    let bump_seed = [escrow_bump];
-    let seeds: &[&[u8]] = &[b"escrow", slot_key.as_ref(), &bump_seed];
-    let signer: &[&[&[u8]]] = &[seeds];
-
     let slot = &mut ctx.accounts.slot;
     require!(!slot.frozen, ErrorCode::Frozen);
     let book = &mut ctx.accounts.bidbook;
@@ -554,67 +912,42 @@ pub fn auction_end(ctx: Context<AuctionEnd>) -> Result<()> {
     require!(now >= end, ErrorCode::TooEarly);
     require!(book.highest_bid > 0, ErrorCode::NoBids);
 
-    // Bind escrow to winner
+    // Bind escrow to winner: the beneficiary attends, the bidder who funded it gets any refund
     let escrow = &mut ctx.accounts.escrow;
-    escrow.buyer = Some(book.highest_bidder);
+    escrow.buyer = Some(book.highest_beneficiary);
+    escrow.payer = Some(book.highest_bidder);
     // Enforce locked equals highest
     require!(escrow.amount_locked == book.highest_bid, ErrorCode::InvalidEscrowBalance);
 
     // T0 payout (40%) to creator, fee pro-rata
     let eff_bps = effective_fee_bps(&ctx.accounts.platform, &ctx.accounts.profile);
-    let total_fee = mul_bps_u64(book.highest_bid, eff_bps as u64)?;
-    let t0_base = mul_bps_u64(book.highest_bid, AUCTION_T0_BPS)?;
-    let t0_fee = mul_bps_u64(total_fee, AUCTION_T0_BPS)?;
-    let t0_creator = t0_base.checked_sub(t0_fee).ok_or(ErrorCode::Overflow)?;
-    // creator payout
-    transfer_checked(
-        CpiContext::new_with_signer(
-            token_program.clone(),
-            TransferChecked {
-                from: escrow_vault.clone(),
-                mint: mint.clone(),
-                to: creator_payout.clone(),
-                authority: escrow_info.clone(),
-            },
-            signer,
-        ),
-        t0_creator,
-        decimals,
+    pay_auction_t0(
+        &payment,
+        escrow,
+        &ctx.accounts.creator_payout_ata.to_account_info(),
+        &ctx.accounts.fee_vault.to_account_info(),
+        book.highest_bid,
+        eff_bps,
     )?;
-    // fee
-    transfer_checked(
-        CpiContext::new_with_signer(
-            token_program,
-            TransferChecked {
-                from: escrow_vault,
-                mint,
-                to: fee_vault,
-                authority: escrow_info,
-            },
-            signer,
-        ),
-        t0_fee,
-        decimals,
-    )?;
-    escrow.amount_locked = escrow.amount_locked.checked_sub(t0_creator + t0_fee).ok_or(ErrorCode::Overflow)?;
     slot.state = SlotState::Locked;
+    // Ticket mode hands the ticket to the winning beneficiary
+    if slot.nft_ticket {
+        crate::ticket::mint_ticket(
+            slot,
+            &ctx.accounts.token_program.to_account_info(),
+            Some(&ctx.accounts.nft_mint),
+            ctx.accounts.winner_ticket.as_ref(),
+            Some(&ctx.accounts.nft_auth.to_account_info()),
+            book.highest_beneficiary,
+        )?;
     // Mint NFT to winner (to escrow authority ATA for MVP) if configured
-    if slot.nft_mint != Pubkey::default() && ctx.accounts.nft_mint.key() == slot.nft_mint {
-        let slot_key = slot.key();
-        let (_pda, bump) = Pubkey::find_program_address(&[b"nft_auth", slot_key.as_ref()], &crate::ID);
-        let seeds: &[&[u8]] = &[b"nft_auth", slot_key.as_ref(), &[bump]];
-        let signer: &[&[&[u8]]] = &[seeds];
-        mint_to(
-            CpiContext::new_with_signer(
-                ctx.accounts.token_program.to_account_info(),
-                MintTo {
-                    mint: ctx.accounts.nft_mint.to_account_info(),
-                    to: ctx.accounts.winner_nft_ata.to_account_info(),
-                    authority: ctx.accounts.nft_auth.to_account_info(),
-                },
-                signer,
-            ),
-            1,
+    } else if slot.nft_mint != Pubkey::default() && ctx.accounts.nft_mint.key() == slot.nft_mint {
+        crate::nft::mint_slot_nft(
+            slot,
+            &ctx.accounts.token_program.to_account_info(),
+            &ctx.accounts.nft_mint.to_account_info(),
+            &ctx.accounts.winner_nft_ata.to_account_info(),
+            &ctx.accounts.nft_auth.to_account_info(),
         )?;
     }
     emit!(AuctionEndedEvent { slot: slot_key, winner: book.highest_bidder, winning_bid: book.highest_bid });
@@ -627,32 +960,47 @@ pub fn auction_checkin(ctx: Context<AuctionCheckin>) -> Result<()> {
     require!(matches!(slot.mode, Mode::EnglishAuction | Mode::SealedBid), ErrorCode::WrongMode);
     if slot.mode != Mode::Stable { require!(slot.capacity_total == 1, ErrorCode::MultiCapacityUnsupported); }
     require!(slot.state == SlotState::Locked || slot.state == SlotState::Reserved, ErrorCode::InvalidState);
-    // Only winner (buyer) or creator authority may mark check-in
-    let buyer = ctx.accounts.escrow.buyer.ok_or(ErrorCode::NotReserved)?;
-    require!(
-        ctx.accounts.signer.key() == buyer || ctx.accounts.signer.key() == slot.creator_authority,
-        ErrorCode::Unauthorized
-    );
+    if slot.nft_ticket {
+        // Ticket mode: whoever holds the ticket checks in, and the ticket is used up
+        require!(
+            crate::ticket::is_holder(slot, &ctx.accounts.escrow, ctx.accounts.ticket.as_ref(), ctx.accounts.signer.key())?,
+            ErrorCode::InvalidTicket
+        );
+        crate::ticket::burn_ticket(
+            slot,
+            ctx.accounts.token_program.as_ref().map(|p| p.as_ref()),
+            ctx.accounts.nft_mint.as_ref(),
+            ctx.accounts.ticket.as_ref(),
+            &ctx.accounts.signer.to_account_info(),
+        )?;
+    } else {
+        // Only winner (buyer) or creator authority may mark check-in
+        let buyer = ctx.accounts.escrow.buyer.ok_or(ErrorCode::NotReserved)?;
+        require!(
+            ctx.accounts.signer.key() == buyer || ctx.accounts.signer.key() == slot.creator_authority,
+            ErrorCode::Unauthorized
+        );
+    }
     slot.buyer_checked_in = true;
     slot.state = SlotState::Completed;
+    let attendee = crate::attendance::attendee_of(slot, &ctx.accounts.escrow, ctx.accounts.signer.key())?;
+    require_keys_eq!(ctx.accounts.attendee.key(), attendee, ErrorCode::Unauthorized);
+    crate::attendance::record_attendance(&mut ctx.accounts.attendance, slot, attendee, ctx.bumps.attendance)?;
     emit!(CheckinEvent { slot: slot.key(), by: ctx.accounts.signer.key() });
     Ok(())
 }
 
-pub fn sealed_auction_end(ctx: Context<SealedAuctionEnd>) -> Result<()> {
-    let decimals = ctx.accounts.mint.decimals;
+pub fn sealed_auction_end<'info>(ctx: Context<'_, '_, 'info, 'info, SealedAuctionEnd<'info>>) -> Result<()> {
+    let payment = EscrowPayment::spl(
+        &ctx.accounts.slot,
+        &ctx.accounts.escrow,
+        ctx.accounts.token_program.to_account_info(),
+        ctx.accounts.escrow_vault.to_account_info(),
+        ctx.accounts.mint.to_account_info(),
+        ctx.accounts.mint.decimals,
+        ctx.remaining_accounts,
+    )?;
     let slot_key = ctx.accounts.slot.key();
-    let escrow_bump = ctx.accounts.escrow.bump;
-    let token_program = ctx.accounts.token_program.to_account_info();
-    let escrow_vault = ctx.accounts.escrow_vault.to_account_info();
-    let fee_vault = ctx.accounts.fee_vault.to_account_info();
-    let creator_payout = ctx.accounts.creator_payout_ata.to_account_info();
-    let mint = ctx.accounts.mint.to_account_info();
-    let escrow_info = ctx.accounts.escrow.to_account_info();
-    let bump_seed = [escrow_bump];
-    let seeds: &[&[u8]] = &[b"escrow", slot_key.as_ref(), &bump_seed];
-    let signer: &[&[&[u8]]] = &[seeds];
-
     let slot = &mut ctx.accounts.slot;
     require!(!slot.frozen, ErrorCode::Frozen);
     require!(slot.mode == Mode::SealedBid, ErrorCode::WrongMode);
@@ -683,83 +1031,45 @@ pub fn sealed_auction_end(ctx: Context<SealedAuctionEnd>) -> Result<()> {
     // Bind escrow to winner and enforce balance equals winning_bid.
     let escrow = &mut ctx.accounts.escrow;
     escrow.buyer = Some(winner);
+    escrow.payer = Some(winner);
     require!(escrow.amount_locked == winning_bid, ErrorCode::InvalidEscrowBalance);
 
     // T0 payout (same as English auction: 40% base), fee pro-rata
     let eff_bps = super::effective_fee_bps(&ctx.accounts.platform, &ctx.accounts.profile);
-    let total_fee = super::mul_bps_u64(winning_bid, eff_bps as u64)?;
-    let t0_base = super::mul_bps_u64(winning_bid, super::AUCTION_T0_BPS)?;
-    let t0_fee = super::mul_bps_u64(total_fee, super::AUCTION_T0_BPS)?;
-    let t0_creator = t0_base.checked_sub(t0_fee).ok_or(ErrorCode::Overflow)?;
-
-    // transfer from escrow to creator and platform
-    transfer_checked(
-        CpiContext::new_with_signer(
-            token_program.clone(),
-            TransferChecked {
-                from: escrow_vault.clone(),
-                mint: mint.clone(),
-                to: creator_payout.clone(),
-                authority: escrow_info.clone(),
-            },
-            signer,
-        ),
-        t0_creator,
-        decimals,
+    pay_auction_t0(
+        &payment,
+        escrow,
+        &ctx.accounts.creator_payout_ata.to_account_info(),
+        &ctx.accounts.fee_vault.to_account_info(),
+        winning_bid,
+        eff_bps,
     )?;
-    transfer_checked(
-        CpiContext::new_with_signer(
-            token_program.clone(),
-            TransferChecked {
-                from: escrow_vault.clone(),
-                mint: mint.clone(),
-                to: fee_vault.clone(),
-                authority: escrow_info.clone(),
-            },
-            signer,
-        ),
-        t0_fee,
-        decimals,
-    )?;
-    escrow.amount_locked = escrow.amount_locked.checked_sub(t0_creator + t0_fee).ok_or(ErrorCode::Overflow)?;
     slot.state = SlotState::Locked;
     // Mint NFT to winner (to escrow authority ATA for MVP) if configured
     if slot.nft_mint != Pubkey::default() && ctx.accounts.nft_mint.key() == slot.nft_mint {
-        let slot_key = slot.key();
-        let (_pda, bump) = Pubkey::find_program_address(&[b"nft_auth", slot_key.as_ref()], &crate::ID);
-        let seeds: &[&[u8]] = &[b"nft_auth", slot_key.as_ref(), &[bump]];
-        let signer: &[&[&[u8]]] = &[seeds];
-        mint_to(
-            CpiContext::new_with_signer(
-                ctx.accounts.token_program.to_account_info(),
-                MintTo {
-                    mint: ctx.accounts.nft_mint.to_account_info(),
-                    to: ctx.accounts.winner_nft_ata.to_account_info(),
-                    authority: ctx.accounts.nft_auth.to_account_info(),
-                },
-                signer,
-            ),
-            1,
+        crate::nft::mint_slot_nft(
+            slot,
+            &ctx.accounts.token_program.to_account_info(),
+            &ctx.accounts.nft_mint.to_account_info(),
+            &ctx.accounts.winner_nft_ata.to_account_info(),
+            &ctx.accounts.nft_auth.to_account_info(),
         )?;
     }
     emit!(AuctionEndedEvent { slot: slot_key, winner, winning_bid });
     Ok(())
 }
 
-pub fn sealed_auction_settle(ctx: Context<SealedAuctionSettle>) -> Result<()> {
-    let decimals = ctx.accounts.mint.decimals;
+pub fn sealed_auction_settle<'info>(ctx: Context<'_, '_, 'info, 'info, SealedAuctionSettle<'info>>) -> Result<()> {
+    let payment = EscrowPayment::spl(
+        &ctx.accounts.slot,
+        &ctx.accounts.escrow,
+        ctx.accounts.token_program.to_account_info(),
+        ctx.accounts.escrow_vault.to_account_info(),
+        ctx.accounts.mint.to_account_info(),
+        ctx.accounts.mint.decimals,
+        ctx.remaining_accounts,
+    )?;
     let slot_key = ctx.accounts.slot.key();
-    let escrow_bump = ctx.accounts.escrow.bump;
-    let token_program = ctx.accounts.token_program.to_account_info();
-    let escrow_vault = ctx.accounts.escrow_vault.to_account_info();
-    let fee_vault = ctx.accounts.fee_vault.to_account_info();
-    let creator_payout = ctx.accounts.creator_payout_ata.to_account_info();
-    let mint = ctx.accounts.mint.to_account_info();
-    let escrow_info = ctx.accounts.escrow.to_account_info();
-    let bump_seed = [escrow_bump];
-    let seeds: &[&[u8]] = &[b"escrow", slot_key.as_ref(), &bump_seed];
-    let signer: &[&[&[u8]]] = &[seeds];
-
     let platform = &ctx.accounts.platform;
     let slot = &mut ctx.accounts.slot;
     let escrow = &mut ctx.accounts.escrow;
@@ -781,81 +1091,31 @@ pub fn sealed_auction_settle(ctx: Context<SealedAuctionSettle>) -> Result<()> {
     // We need winning_bid; reconstruct as t0_base + t1_base where t1_base = escrow.amount_locked / (1)
     // Simpler: store not available here; as MVP we assume escrow.amount_locked is t1_base.
     let t1_base = escrow.amount_locked;
-    let t1_release = super::mul_bps_u64(t1_base, super::FINAL_RELEASE_BPS)?;
-    let t1_withhold = t1_base.checked_sub(t1_release).ok_or(ErrorCode::Overflow)?;
     let eff_bps = super::effective_fee_bps(platform, &ctx.accounts.profile);
-    let t1_fee = super::mul_bps_u64(t1_base, eff_bps as u64)?;
-    let t1_creator = t1_release.checked_sub(t1_fee).ok_or(ErrorCode::Overflow)?;
-
-    // payouts
-    transfer_checked(
-        CpiContext::new_with_signer(
-            token_program.clone(),
-            TransferChecked {
-                from: escrow_vault.clone(),
-                mint: mint.clone(),
-                to: creator_payout.clone(),
-                authority: escrow_info.clone(),
-            },
-            signer,
-        ),
-        t1_creator,
-        decimals,
-    )?;
-    transfer_checked(
-        CpiContext::new_with_signer(
-            token_program.clone(),
-            TransferChecked {
-                from: escrow_vault.clone(),
-                mint: mint.clone(),
-                to: fee_vault.clone(),
-                authority: escrow_info.clone(),
-            },
-            signer,
-        ),
-        t1_fee,
-        decimals,
+    let (t1_creator, t1_fee, t1_withhold) = pay_auction_t1(
+        &payment,
+        escrow,
+        &ctx.accounts.creator_payout_ata.to_account_info(),
+        &ctx.accounts.fee_vault.to_account_info(),
+        &ctx.accounts.dispute_vault.to_account_info(),
+        t1_base,
+        eff_bps,
     )?;
-    if t1_withhold > 0 {
-        transfer_checked(
-            CpiContext::new_with_signer(
-                token_program.clone(),
-                TransferChecked {
-                    from: escrow_vault,
-                    mint: mint.clone(),
-                    to: ctx.accounts.dispute_vault.to_account_info().clone(),
-                    authority: escrow_info.clone(),
-                },
-                signer,
-            ),
-            t1_withhold,
-            decimals,
-        )?;
-    }
-
-    let total_out = t1_creator
-        .checked_add(t1_fee).ok_or(ErrorCode::Overflow)?
-        .checked_add(t1_withhold).ok_or(ErrorCode::Overflow)?;
-    require!(escrow.amount_locked >= total_out, ErrorCode::InvalidEscrowBalance);
-    escrow.amount_locked = escrow.amount_locked.checked_sub(total_out).ok_or(ErrorCode::Overflow)?;
     slot.state = SlotState::Settled;
     emit!(SettledT1Event { slot: slot_key, to: ctx.accounts.creator_payout_ata.key(), amount: t1_creator, fee: t1_fee, retained: t1_withhold });
     Ok(())
 }
-pub fn auction_settle(ctx: Context<AuctionSettle>) -> Result<()> {
-    let decimals = ctx.accounts.mint.decimals;
+pub fn auction_settle<'info>(ctx: Context<'_, '_, 'info, 'info, AuctionSettle<'info>>) -> Result<()> {
+    let payment = EscrowPayment::spl(
+        &ctx.accounts.slot,
+        &ctx.accounts.escrow,
+        ctx.accounts.token_program.to_account_info(),
+        ctx.accounts.escrow_vault.to_account_info(),
+        ctx.accounts.mint.to_account_info(),
+        ctx.accounts.mint.decimals,
+        ctx.remaining_accounts,
+    )?;
     let slot_key = ctx.accounts.slot.key();
-    let escrow_bump = ctx.accounts.escrow.bump;
-    let token_program = ctx.accounts.token_program.to_account_info();
-    let escrow_vault = ctx.accounts.escrow_vault.to_account_info();
-    let mint = ctx.accounts.mint.to_account_info();
-    let creator_payout = ctx.accounts.creator_payout_ata.to_account_info();
-    let fee_vault = ctx.accounts.fee_vault.to_account_info();
-    let escrow_info = ctx.accounts.escrow.to_account_info();
-    let bump_seed = [escrow_bump];
-    let seeds: &[&[u8]] = &[b"escrow", slot_key.as_ref(), &bump_seed];
-    let signer: &[&[&[u8]]] = &[seeds];
-
     let platform = &ctx.accounts.platform;
     let slot = &mut ctx.accounts.slot;
     let escrow = &mut ctx.accounts.escrow;
@@ -869,63 +1129,15 @@ pub fn auction_settle(ctx: Context<AuctionSettle>) -> Result<()> {
     // Remaining base after T0 is (winning_bid - T0_base)
     let t0_base = mul_bps_u64(book.highest_bid, AUCTION_T0_BPS)?;
     let t1_base = book.highest_bid.checked_sub(t0_base).ok_or(ErrorCode::Overflow)?;
-    let t1_release = mul_bps_u64(t1_base, FINAL_RELEASE_BPS)?;
-    let t1_withhold = t1_base.checked_sub(t1_release).ok_or(ErrorCode::Overflow)?;
-    let t1_fee = mul_bps_u64(t1_base, platform.platform_fee_bps as u64)?;
-    let t1_creator = t1_release.checked_sub(t1_fee).ok_or(ErrorCode::Overflow)?;
-
-    // to creator
-    transfer_checked(
-        CpiContext::new_with_signer(
-            token_program.clone(),
-            TransferChecked {
-                from: escrow_vault.clone(),
-                mint: mint.clone(),
-                to: creator_payout.clone(),
-                authority: escrow_info.clone(),
-            },
-            signer,
-        ),
-        t1_creator,
-        decimals,
+    let (t1_creator, t1_fee, t1_withhold) = pay_auction_t1(
+        &payment,
+        escrow,
+        &ctx.accounts.creator_payout_ata.to_account_info(),
+        &ctx.accounts.fee_vault.to_account_info(),
+        &ctx.accounts.dispute_vault.to_account_info(),
+        t1_base,
+        platform.platform_fee_bps,
     )?;
-    // fee
-    transfer_checked(
-        CpiContext::new_with_signer(
-            token_program.clone(),
-            TransferChecked {
-                from: escrow_vault.clone(),
-                mint: mint.clone(),
-                to: fee_vault.clone(),
-                authority: escrow_info.clone(),
-            },
-            signer,
-        ),
-        t1_fee,
-        decimals,
-    )?;
-    if t1_withhold > 0 {
-        transfer_checked(
-            CpiContext::new_with_signer(
-                token_program.clone(),
-                TransferChecked {
-                    from: escrow_vault.clone(),
-                    mint: mint.clone(),
-                    to: ctx.accounts.dispute_vault.to_account_info().clone(),
-                    authority: escrow_info.clone(),
-                },
-                signer,
-            ),
-            t1_withhold,
-            decimals,
-        )?;
-    }
-
-    let total_out = t1_creator
-        .checked_add(t1_fee).ok_or(ErrorCode::Overflow)?
-        .checked_add(t1_withhold).ok_or(ErrorCode::Overflow)?;
-    require!(escrow.amount_locked >= total_out, ErrorCode::InvalidEscrowBalance);
-    escrow.amount_locked = escrow.amount_locked.checked_sub(total_out).ok_or(ErrorCode::Overflow)?;
     slot.state = SlotState::Settled;
     emit!(SettledT1Event { slot: slot_key, to: ctx.accounts.creator_payout_ata.key(), amount: t1_creator, fee: t1_fee, retained: t1_withhold });
     Ok(())
@@ -934,9 +1146,12 @@ pub fn auction_settle(ctx: Context<AuctionSettle>) -> Result<()> {
 pub fn raise_dispute(ctx: Context<RaiseDispute>, _reason_code: u16) -> Result<()> {
     let slot = &mut ctx.accounts.slot;
     require!(!slot.frozen, ErrorCode::Frozen);
-    let buyer = ctx.accounts.escrow.buyer.ok_or(ErrorCode::NotReserved)?;
+    require!(ctx.accounts.escrow.buyer.is_some(), ErrorCode::NotReserved);
+    // Holder rights follow the ticket in ticket mode
+    let raiser = ctx.accounts.raiser.key();
     require!(
-        ctx.accounts.raiser.key() == buyer || ctx.accounts.raiser.key() == slot.creator_authority,
+        crate::ticket::is_holder(slot, &ctx.accounts.escrow, ctx.accounts.ticket.as_ref(), raiser)?
+            || raiser == slot.creator_authority,
         ErrorCode::Unauthorized
     );
     slot.frozen = true;
@@ -944,21 +1159,18 @@ pub fn raise_dispute(ctx: Context<RaiseDispute>, _reason_code: u16) -> Result<()
     Ok(())
 }
 
-pub fn resolve_dispute(ctx: Context<ResolveDispute>, payout_split_bps_to_creator: u16) -> Result<()> {
+pub fn resolve_dispute<'info>(ctx: Context<'_, '_, 'info, 'info, ResolveDispute<'info>>, payout_split_bps_to_creator: u16) -> Result<()> {
     require!(payout_split_bps_to_creator <= 10_000, ErrorCode::InvalidBps);
-    let decimals = ctx.accounts.mint.decimals;
+    let payment = EscrowPayment::spl(
+        &ctx.accounts.slot,
+        &ctx.accounts.escrow,
+        ctx.accounts.token_program.to_account_info(),
+        ctx.accounts.escrow_vault.to_account_info(),
+        ctx.accounts.mint.to_account_info(),
+        ctx.accounts.mint.decimals,
+        ctx.remaining_accounts,
+    )?;
     let slot_key = ctx.accounts.slot.key();
-    let escrow_bump = ctx.accounts.escrow.bump;
-    let token_program = ctx.accounts.token_program.to_account_info();
-    let escrow_vault = ctx.accounts.escrow_vault.to_account_info();
-    let mint = ctx.accounts.mint.to_account_info();
-    let creator_payout = ctx.accounts.creator_payout_ata.to_account_info();
-    let buyer_token = ctx.accounts.buyer_token.to_account_info();
-    let escrow_info = ctx.accounts.escrow.to_account_info();
-    let bump_seed = [escrow_bump];
-    let seeds: &[&[u8]] = &[b"escrow", slot_key.as_ref(), &bump_seed];
-    let signer: &[&[&[u8]]] = &[seeds];
-
     let slot = &mut ctx.accounts.slot;
     require!(slot.frozen, ErrorCode::Unauthorized);
     let escrow = &mut ctx.accounts.escrow;
@@ -967,38 +1179,8 @@ pub fn resolve_dispute(ctx: Context<ResolveDispute>, payout_split_bps_to_creator
     let to_creator = mul_bps_u64(remaining, payout_split_bps_to_creator as u64)?;
     let to_buyer = remaining.checked_sub(to_creator).ok_or(ErrorCode::Overflow)?;
 
-    if to_creator > 0 {
-        transfer_checked(
-            CpiContext::new_with_signer(
-                token_program.clone(),
-                TransferChecked {
-                    from: escrow_vault.clone(),
-                    mint: mint.clone(),
-                    to: creator_payout.clone(),
-                    authority: escrow_info.clone(),
-                },
-                signer,
-            ),
-            to_creator,
-            decimals,
-        )?;
-    }
-    if to_buyer > 0 {
-        transfer_checked(
-            CpiContext::new_with_signer(
-                token_program.clone(),
-                TransferChecked {
-                    from: escrow_vault,
-                    mint,
-                    to: buyer_token,
-                    authority: escrow_info.clone(),
-                },
-                signer,
-            ),
-            to_buyer,
-            decimals,
-        )?;
-    }
+    payment.pay(Payout { to: &ctx.accounts.creator_payout_ata.to_account_info(), amount: to_creator })?;
+    payment.pay(Payout { to: &ctx.accounts.buyer_token.to_account_info(), amount: to_buyer })?;
 
     escrow.amount_locked = 0;
     slot.frozen = false;
@@ -0,0 +1,99 @@
+use anchor_lang::prelude::*;
+use anchor_lang::system_program;
+use anchor_lang::Discriminator;
+
+use crate::*;
+use crate::ErrorCode;
+
+// Layout migration. Fields added after launch are appended after `bump`, so an account created
+// before the upgrade is a byte-for-byte prefix of the current layout at its LEN_V0 size and
+// fails to deserialize until it is grown to LEN. The zeroed tail reads as the defaults (None,
+// 0, false, empty Vec); the handlers below fix up the fields where that default is wrong.
+
+// Grow `account` from `8 + old_len` to `8 + new_len`, topping up rent from `payer`
+fn grow<'info>(
+    account: &AccountInfo<'info>,
+    payer: &AccountInfo<'info>,
+    system_program: &AccountInfo<'info>,
+    old_len: usize,
+    new_len: usize,
+) -> Result<()> {
+    require!(account.data_len() == 8 + old_len, ErrorCode::InvalidMigration);
+    // The full rent difference is added even if the account holds more lamports: SOL escrows
+    // keep booking funds on top of rent and those must not be counted toward it
+    let rent = Rent::get()?;
+    let top_up = rent.minimum_balance(8 + new_len).saturating_sub(rent.minimum_balance(8 + old_len));
+    if top_up > 0 {
+        system_program::transfer(
+            CpiContext::new(
+                system_program.clone(),
+                system_program::Transfer { from: payer.clone(), to: account.clone() },
+            ),
+            top_up,
+        )?;
+    }
+    account.resize(8 + new_len)?;
+    emit!(AccountMigratedEvent { account: account.key(), from_len: old_len as u32, to_len: new_len as u32 });
+    Ok(())
+}
+
+fn discriminator(account: &AccountInfo) -> Result<[u8; 8]> {
+    let data = account.try_borrow_data()?;
+    require!(data.len() >= 8, ErrorCode::InvalidMigration);
+    let mut disc = [0u8; 8];
+    disc.copy_from_slice(&data[..8]);
+    Ok(disc)
+}
+
+// Creator profiles, escrows and bid books: the new fields have no choice to make, so anyone
+// may migrate them and pay the extra rent
+pub fn migrate_account(ctx: Context<MigrateAccount>) -> Result<()> {
+    let account = ctx.accounts.account.to_account_info();
+    let payer = ctx.accounts.payer.to_account_info();
+    let system_program = ctx.accounts.system_program.to_account_info();
+    let disc = discriminator(&account)?;
+    if disc == CreatorProfile::DISCRIMINATOR {
+        // slot_count starts at 0; counter seeds never collide with the start_ts seeds of the
+        // creator's existing slots
+        grow(&account, &payer, &system_program, CreatorProfile::LEN_V0, CreatorProfile::LEN)
+    } else if disc == Escrow::DISCRIMINATOR {
+        // payer None refunds the buyer, as before the upgrade
+        grow(&account, &payer, &system_program, Escrow::LEN_V0, Escrow::LEN)
+    } else if disc == BidBook::DISCRIMINATOR {
+        grow(&account, &payer, &system_program, BidBook::LEN_V0, BidBook::LEN)?;
+        // Bids placed before the upgrade were always for the bidder themselves
+        let mut book = BidBook::try_deserialize(&mut &account.try_borrow_data()?[..])?;
+        book.highest_beneficiary = book.highest_bidder;
+        book.try_serialize(&mut &mut account.try_borrow_mut_data()?[..])?;
+        Ok(())
+    } else {
+        err!(ErrorCode::InvalidMigration)
+    }
+}
+
+// Slots record how they are paid, which only the creator knows for a pre-upgrade slot: the
+// same escrow used to take either currency
+pub fn migrate_slot(ctx: Context<MigrateSlot>, payment_kind: PaymentKind) -> Result<()> {
+    let account = ctx.accounts.slot.to_account_info();
+    require!(discriminator(&account)? == TimeSlot::DISCRIMINATOR, ErrorCode::InvalidMigration);
+    {
+        // creator_authority follows creator_profile in every layout
+        let data = account.try_borrow_data()?;
+        require!(data.len() >= 8 + 64, ErrorCode::InvalidMigration);
+        let creator_authority = Pubkey::try_from(&data[8 + 32..8 + 64]).map_err(|_| ErrorCode::InvalidMigration)?;
+        require_keys_eq!(creator_authority, ctx.accounts.authority.key(), ErrorCode::Unauthorized);
+    }
+    grow(
+        &account,
+        &ctx.accounts.authority.to_account_info(),
+        &ctx.accounts.system_program.to_account_info(),
+        TimeSlot::LEN_V0,
+        TimeSlot::LEN,
+    )?;
+    // An empty cancel_policy keeps the pre-upgrade full refund before start; index stays 0
+    // because these slots are seeded by start_ts
+    let mut slot = TimeSlot::try_deserialize(&mut &account.try_borrow_data()?[..])?;
+    slot.payment_kind = payment_kind;
+    slot.try_serialize(&mut &mut account.try_borrow_mut_data()?[..])?;
+    Ok(())
+}
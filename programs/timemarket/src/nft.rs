@@ -0,0 +1,87 @@
+use anchor_lang::prelude::*;
+use anchor_spl::metadata::{
+    create_metadata_accounts_v3,
+    mpl_token_metadata::types::{Creator, DataV2},
+    CreateMetadataAccountsV3,
+};
+use anchor_spl::token_interface::{mint_to, MintTo};
+
+use crate::*;
+use crate::ErrorCode;
+
+// Create the slot's NFT mint under the nft_auth PDA and describe it with Metaplex metadata.
+// The creator is listed (unverified) with the slot's resale royalty as seller fee.
+pub fn init_slot_nft(ctx: Context<InitSlotNft>, params: SlotNftParams) -> Result<()> {
+    let slot = &mut ctx.accounts.slot;
+    require!(slot.nft_mint == Pubkey::default(), ErrorCode::NftAlreadyInitialized);
+    require!(slot.state == SlotState::Open, ErrorCode::InvalidState);
+
+    let slot_key = slot.key();
+    let bump_seed = [ctx.bumps.nft_auth];
+    let seeds: &[&[u8]] = &[b"nft_auth", slot_key.as_ref(), &bump_seed];
+    let signer: &[&[&[u8]]] = &[seeds];
+    create_metadata_accounts_v3(
+        CpiContext::new_with_signer(
+            ctx.accounts.metadata_program.to_account_info(),
+            CreateMetadataAccountsV3 {
+                metadata: ctx.accounts.metadata.to_account_info(),
+                mint: ctx.accounts.nft_mint.to_account_info(),
+                mint_authority: ctx.accounts.nft_auth.to_account_info(),
+                payer: ctx.accounts.creator.to_account_info(),
+                update_authority: ctx.accounts.nft_auth.to_account_info(),
+                system_program: ctx.accounts.system_program.to_account_info(),
+                rent: ctx.accounts.rent.to_account_info(),
+            },
+            signer,
+        ),
+        DataV2 {
+            name: params.name,
+            symbol: params.symbol,
+            uri: params.uri,
+            seller_fee_basis_points: slot.resale_royalty_bps,
+            creators: Some(vec![Creator { address: slot.creator_authority, verified: false, share: 100 }]),
+            collection: None,
+            uses: None,
+        },
+        true,
+        true,
+        None,
+    )?;
+
+    slot.nft_mint = ctx.accounts.nft_mint.key();
+    emit!(SlotNftInitializedEvent {
+        slot: slot_key,
+        mint: slot.nft_mint,
+        metadata: ctx.accounts.metadata.key(),
+    });
+    Ok(())
+}
+
+// Mint one token of the slot's NFT; live supply never exceeds the slot's capacity
+pub fn mint_slot_nft<'info>(
+    slot: &mut Account<'info, TimeSlot>,
+    token_program: &AccountInfo<'info>,
+    nft_mint: &AccountInfo<'info>,
+    to: &AccountInfo<'info>,
+    nft_auth: &AccountInfo<'info>,
+) -> Result<()> {
+    require!(slot.nft_minted < slot.capacity_total, ErrorCode::NftSupplyExhausted);
+    let slot_key = slot.key();
+    let (_pda, bump) = Pubkey::find_program_address(&[b"nft_auth", slot_key.as_ref()], &crate::ID);
+    let seeds: &[&[u8]] = &[b"nft_auth", slot_key.as_ref(), &[bump]];
+    let signer: &[&[&[u8]]] = &[seeds];
+    mint_to(
+        CpiContext::new_with_signer(
+            token_program.clone(),
+            MintTo {
+                mint: nft_mint.clone(),
+                to: to.clone(),
+                authority: nft_auth.clone(),
+            },
+            signer,
+        ),
+        1,
+    )?;
+    slot.nft_minted = slot.nft_minted.checked_add(1).ok_or(ErrorCode::Overflow)?;
+    Ok(())
+}
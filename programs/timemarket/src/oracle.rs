@@ -0,0 +1,107 @@
+use anchor_lang::prelude::*;
+
+use crate::*;
+use crate::ErrorCode;
+
+// Pyth v2 price account layout: only the fields the USD conversion needs, read at their fixed
+// offsets so a local fixture account with the same layout can stand in for a live feed
+const PYTH_MAGIC: u32 = 0xa1b2_c3d4;
+const PYTH_ATYPE_PRICE: u32 = 3;
+const PYTH_STATUS_TRADING: u32 = 1;
+const OFF_MAGIC: usize = 0;
+const OFF_ATYPE: usize = 8;
+const OFF_EXPO: usize = 20;
+const OFF_TIMESTAMP: usize = 96;
+const OFF_AGG_PRICE: usize = 208;
+const OFF_AGG_CONF: usize = 216;
+const OFF_AGG_STATUS: usize = 224;
+const PYTH_MIN_LEN: usize = 240;
+
+// Pyth v2 oracle program that owns price accounts; fixtures must be loaded under this owner
+#[cfg(feature = "mainnet")]
+pub const PYTH_PROGRAM_ID: Pubkey = pubkey!("FsJ3A3u2vn5cTVofAjvy6y5kwABJAqYWpe4975bi2epH");
+#[cfg(not(feature = "mainnet"))]
+pub const PYTH_PROGRAM_ID: Pubkey = pubkey!("gSbePebfvPy7tRqimPoVecS2UsBvYv46ynrzWocc92s");
+
+// Aggregate USD price of one whole token: `price * 10^expo`, +/- `conf * 10^expo`
+pub struct OraclePrice {
+    pub price: i64,
+    pub conf: u64,
+    pub expo: i32,
+    pub publish_time: i64,
+}
+
+fn read_bytes<const N: usize>(data: &[u8], offset: usize) -> [u8; N] {
+    let mut out = [0u8; N];
+    out.copy_from_slice(&data[offset..offset + N]);
+    out
+}
+
+pub fn read_price(feed: &AccountInfo) -> Result<OraclePrice> {
+    let data = feed.try_borrow_data()?;
+    require!(data.len() >= PYTH_MIN_LEN, ErrorCode::InvalidOracle);
+    require!(u32::from_le_bytes(read_bytes(&data, OFF_MAGIC)) == PYTH_MAGIC, ErrorCode::InvalidOracle);
+    require!(u32::from_le_bytes(read_bytes(&data, OFF_ATYPE)) == PYTH_ATYPE_PRICE, ErrorCode::InvalidOracle);
+    require!(
+        u32::from_le_bytes(read_bytes(&data, OFF_AGG_STATUS)) == PYTH_STATUS_TRADING,
+        ErrorCode::InvalidOracle
+    );
+    Ok(OraclePrice {
+        price: i64::from_le_bytes(read_bytes(&data, OFF_AGG_PRICE)),
+        conf: u64::from_le_bytes(read_bytes(&data, OFF_AGG_CONF)),
+        expo: i32::from_le_bytes(read_bytes(&data, OFF_EXPO)),
+        publish_time: i64::from_le_bytes(read_bytes(&data, OFF_TIMESTAMP)),
+    })
+}
+
+// Convert a USD-cent amount into mint base units at the feed's current price, rounding up so
+// the creator never receives less than quoted. The feed must be the one pinned on the slot,
+// owned by the Pyth program, recent enough, and tight enough.
+pub fn usd_to_mint_units(
+    quote: &UsdQuote,
+    usd_cents: u64,
+    feed: Option<&AccountInfo>,
+    decimals: u8,
+) -> Result<u64> {
+    let feed = feed.ok_or(ErrorCode::InvalidOracle)?;
+    require_keys_eq!(feed.key(), quote.price_feed, ErrorCode::InvalidOracle);
+    // Only the oracle program can write a genuine price account
+    require_keys_eq!(*feed.owner, PYTH_PROGRAM_ID, ErrorCode::InvalidOracle);
+    let oracle = read_price(feed)?;
+    require!(oracle.price > 0, ErrorCode::InvalidOracle);
+
+    let now = Clock::get()?.unix_timestamp;
+    require!(
+        now.saturating_sub(oracle.publish_time) <= quote.max_staleness_sec as i64,
+        ErrorCode::OracleStale
+    );
+    let price = oracle.price as u128;
+    require!(
+        (oracle.conf as u128) * 10_000 <= price * quote.max_conf_bps as u128,
+        ErrorCode::OracleConfidence
+    );
+
+    // units = cents / 100 / (price * 10^expo) * 10^decimals
+    let shift = decimals as i64 - oracle.expo as i64 - 2;
+    let pow = 10u128
+        .checked_pow(shift.unsigned_abs() as u32)
+        .ok_or(ErrorCode::Overflow)?;
+    let (num, den) = if shift >= 0 {
+        ((usd_cents as u128).checked_mul(pow).ok_or(ErrorCode::Overflow)?, price)
+    } else {
+        (usd_cents as u128, price.checked_mul(pow).ok_or(ErrorCode::Overflow)?)
+    };
+    let units = num.div_ceil(den);
+    let units = u64::try_from(units).map_err(|_| ErrorCode::Overflow)?;
+    require!(units > 0, ErrorCode::InvalidPrice);
+    Ok(units)
+}
+
+// Buyer-supplied ceiling on what a USD-priced purchase may cost in mint units
+pub fn check_max_payment(amount: u64, max_payment: Option<u64>, quoted: bool) -> Result<()> {
+    match max_payment {
+        Some(max) => require!(amount <= max, ErrorCode::SlippageExceeded),
+        None => require!(!quoted, ErrorCode::SlippageExceeded),
+    }
+    Ok(())
+}
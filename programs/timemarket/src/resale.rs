@@ -0,0 +1,137 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::TransferChecked;
+
+use crate::*;
+use crate::ErrorCode;
+use crate::escrow::hooked_transfer_checked;
+
+// A booking can change hands while it is held but not yet used
+fn check_resellable(slot: &TimeSlot) -> Result<()> {
+    // Resale pays the seller wallet to wallet in the slot's mint; SOL bookings have no resale path
+    require!(slot.payment_kind == PaymentKind::Spl, ErrorCode::UnsupportedPaymentKind);
+    require!(!slot.transfers_disabled, ErrorCode::TransfersDisabled);
+    // Tickets trade as tokens on any marketplace
+    require!(!slot.nft_ticket, ErrorCode::NftTicketMode);
+    require!(!slot.frozen, ErrorCode::Frozen);
+    require!(slot.state == SlotState::Reserved || slot.state == SlotState::Locked, ErrorCode::InvalidState);
+    require!(!slot.buyer_checked_in, ErrorCode::InvalidState);
+    require!(slot.reschedule.is_none(), ErrorCode::ReschedulePending);
+    Ok(())
+}
+
+pub fn list_reservation(ctx: Context<ListReservation>, price: u64) -> Result<()> {
+    let slot = &ctx.accounts.slot;
+    check_resellable(slot)?;
+    require!(price > 0, ErrorCode::InvalidPrice);
+    if let Some(max) = slot.max_resale_price {
+        require!(price <= max, ErrorCode::ResalePriceTooHigh);
+    }
+    // Only a holder who also owns the refund can sell; a gifted booking still belongs to its payer
+    let escrow = &ctx.accounts.escrow;
+    let holder = escrow.buyer.ok_or(ErrorCode::NotReserved)?;
+    require_keys_eq!(ctx.accounts.seller.key(), holder, ErrorCode::UnauthorizedBuyer);
+    require!(escrow.refund_to() == Some(holder), ErrorCode::UnauthorizedBuyer);
+
+    let listing = &mut ctx.accounts.listing;
+    listing.slot = slot.key();
+    listing.seller = holder;
+    listing.price = price;
+    listing.bump = ctx.bumps.listing;
+    emit!(ReservationListedEvent { slot: slot.key(), seller: holder, price });
+    Ok(())
+}
+
+// A listing is stale once the seller no longer holds the booking with its refund, or the booking
+// can no longer change hands; stale listings block new ones, so anyone may close them
+fn listing_is_stale(slot: &TimeSlot, escrow: &Escrow, seller: Pubkey) -> bool {
+    escrow.buyer != Some(seller)
+        || escrow.refund_to() != Some(seller)
+        || !(slot.state == SlotState::Reserved || slot.state == SlotState::Locked)
+        || slot.buyer_checked_in
+}
+
+pub fn cancel_listing(ctx: Context<CancelListing>) -> Result<()> {
+    let seller = ctx.accounts.listing.seller;
+    require!(
+        ctx.accounts.caller.key() == seller
+            || listing_is_stale(&ctx.accounts.slot, &ctx.accounts.escrow, seller),
+        ErrorCode::Unauthorized
+    );
+    emit!(ListingCancelledEvent { slot: ctx.accounts.slot.key(), seller });
+    Ok(())
+}
+
+// Buyer pays the listing price: creator royalty and platform fee come off the top, the seller gets
+// the rest, and the booking (check-in and refund rights) moves to the buyer
+pub fn buy_listing<'info>(ctx: Context<'_, '_, 'info, 'info, BuyListing<'info>>, max_price: u64, allowlist: Option<AllowlistProof>) -> Result<()> {
+    let slot = &ctx.accounts.slot;
+    check_resellable(slot)?;
+    let listing = &ctx.accounts.listing;
+    require_keys_neq!(ctx.accounts.buyer.key(), listing.seller, ErrorCode::OwnListing);
+    let price = listing.price;
+    require!(price <= max_price, ErrorCode::SlippageExceeded);
+    // A listing goes stale once the seller no longer holds the booking
+    let escrow = &mut ctx.accounts.escrow;
+    require!(escrow.buyer == Some(listing.seller), ErrorCode::UnauthorizedBuyer);
+    require!(escrow.refund_to() == Some(listing.seller), ErrorCode::UnauthorizedBuyer);
+    crate::gate::check_gate(
+        slot,
+        ctx.accounts.buyer.key(),
+        ctx.accounts.gate_token.as_ref(),
+        ctx.accounts.gate_metadata.as_deref(),
+    )?;
+    let allowlist_cap = crate::allowlist::check_allowlist(slot, ctx.accounts.buyer.key(), allowlist.as_ref())?;
+    crate::allowlist::consume_allowlist(
+        ctx.accounts.allowlist_claim.as_mut(),
+        slot.key(),
+        ctx.accounts.buyer.key(),
+        allowlist_cap,
+        ctx.bumps.allowlist_claim,
+    )?;
+
+    let eff_bps = effective_fee_bps(&ctx.accounts.platform, &ctx.accounts.profile);
+    let royalty = mul_bps_u64(price, slot.resale_royalty_bps as u64)?;
+    let fee = mul_bps_u64(price, eff_bps as u64)?;
+    let seller_amount = price
+        .checked_sub(royalty).ok_or(ErrorCode::Overflow)?
+        .checked_sub(fee).ok_or(ErrorCode::Overflow)?;
+
+    // The buyer pays each party directly; escrow keeps holding the original price
+    let decimals = ctx.accounts.mint.decimals;
+    for (to, amount) in [
+        (ctx.accounts.seller_token.to_account_info(), seller_amount),
+        (ctx.accounts.creator_payout_ata.to_account_info(), royalty),
+        (ctx.accounts.fee_vault.to_account_info(), fee),
+    ] {
+        if amount == 0 {
+            continue;
+        }
+        hooked_transfer_checked(
+            CpiContext::new(
+                ctx.accounts.token_program.to_account_info(),
+                TransferChecked {
+                    from: ctx.accounts.buyer_token.to_account_info(),
+                    mint: ctx.accounts.mint.to_account_info(),
+                    to,
+                    authority: ctx.accounts.buyer.to_account_info(),
+                },
+            )
+            .with_remaining_accounts(ctx.remaining_accounts.to_vec()),
+            amount,
+            decimals,
+        )?;
+    }
+
+    let buyer = ctx.accounts.buyer.key();
+    escrow.buyer = Some(buyer);
+    escrow.payer = Some(buyer);
+    emit!(ReservationResoldEvent {
+        slot: slot.key(),
+        seller: listing.seller,
+        buyer,
+        price,
+        royalty,
+        fee,
+    });
+    Ok(())
+}
@@ -0,0 +1,141 @@
+use anchor_lang::prelude::*;
+
+use crate::*;
+use crate::escrow::{EscrowPayment, Payout};
+use crate::ErrorCode;
+
+// Buyer may always answer; after the deadline anyone may apply the default outcome
+fn may_answer(proposal: &RescheduleProposal, buyer: Pubkey, signer: Pubkey, now: i64, accepting: bool) -> bool {
+    signer == buyer || (now >= proposal.respond_by && proposal.default_accept == accepting)
+}
+
+pub fn propose_reschedule(
+    ctx: Context<ProposeReschedule>,
+    new_start_ts: i64,
+    new_end_ts: i64,
+    respond_by: i64,
+    default_accept: bool,
+) -> Result<()> {
+    let slot = &mut ctx.accounts.slot;
+    require!(!slot.frozen, ErrorCode::Frozen);
+    // Only booked sessions before T0 can be moved; escrow still holds the full price
+    require!(slot.state == SlotState::Reserved, ErrorCode::InvalidState);
+    require!(ctx.accounts.escrow.buyer.is_some(), ErrorCode::NotReserved);
+    require!(slot.reschedule.is_none(), ErrorCode::ReschedulePending);
+
+    let now = Clock::get()?.unix_timestamp;
+    require!(new_start_ts < new_end_ts, ErrorCode::InvalidTimes);
+    require!(respond_by > now, ErrorCode::InvalidTimes);
+    // Buyer must be able to answer before either the original or the new start
+    require!(respond_by <= slot.start_ts && respond_by < new_start_ts, ErrorCode::InvalidTimes);
+
+    slot.reschedule = Some(RescheduleProposal { new_start_ts, new_end_ts, respond_by, default_accept });
+    emit!(RescheduleProposedEvent { slot: slot.key(), new_start_ts, new_end_ts, respond_by, default_accept });
+    Ok(())
+}
+
+pub fn accept_reschedule(ctx: Context<AcceptReschedule>) -> Result<()> {
+    let slot = &mut ctx.accounts.slot;
+    require!(!slot.frozen, ErrorCode::Frozen);
+    require!(slot.state == SlotState::Reserved, ErrorCode::InvalidState);
+    let proposal = slot.reschedule.ok_or(ErrorCode::NoReschedulePending)?;
+    let buyer = ctx.accounts.escrow.buyer.ok_or(ErrorCode::NotReserved)?;
+    let now = Clock::get()?.unix_timestamp;
+    require!(
+        may_answer(&proposal, buyer, ctx.accounts.signer.key(), now, true),
+        ErrorCode::Unauthorized
+    );
+
+    // Move the session; escrow and buyer binding stay as they are
+    slot.start_ts = proposal.new_start_ts;
+    slot.end_ts = proposal.new_end_ts;
+    slot.reschedule = None;
+    emit!(RescheduleResolvedEvent {
+        slot: slot.key(),
+        accepted: true,
+        by: ctx.accounts.signer.key(),
+        start_ts: slot.start_ts,
+        end_ts: slot.end_ts,
+    });
+    Ok(())
+}
+
+pub fn reject_reschedule<'info>(ctx: Context<'_, '_, 'info, 'info, RejectReschedule<'info>>) -> Result<()> {
+    let payment = EscrowPayment::spl(
+        &ctx.accounts.slot,
+        &ctx.accounts.escrow,
+        ctx.accounts.token_program.to_account_info(),
+        ctx.accounts.escrow_vault.to_account_info(),
+        ctx.accounts.mint.to_account_info(),
+        ctx.accounts.mint.decimals,
+        ctx.remaining_accounts,
+    )?;
+    let slot = &mut ctx.accounts.slot;
+    require!(!slot.frozen, ErrorCode::Frozen);
+    require!(slot.state == SlotState::Reserved, ErrorCode::InvalidState);
+    let proposal = slot.reschedule.ok_or(ErrorCode::NoReschedulePending)?;
+    let escrow = &mut ctx.accounts.escrow;
+    let buyer = escrow.buyer.ok_or(ErrorCode::NotReserved)?;
+    let now = Clock::get()?.unix_timestamp;
+    require!(
+        may_answer(&proposal, buyer, ctx.accounts.signer.key(), now, false),
+        ErrorCode::Unauthorized
+    );
+    let refund_to = escrow.refund_to().ok_or(ErrorCode::NotReserved)?;
+
+    // Full refund: the creator could not keep the original time
+    let amount = escrow.amount_locked;
+    payment.pay(Payout { to: &ctx.accounts.buyer_token.to_account_info(), amount })?;
+    escrow.amount_locked = 0;
+    escrow.buyer = None;
+    escrow.payer = None;
+    slot.reschedule = None;
+    slot.state = SlotState::Refunded;
+    emit!(RefundedEvent { slot: slot.key(), to: refund_to, amount });
+    emit!(RescheduleResolvedEvent {
+        slot: slot.key(),
+        accepted: false,
+        by: ctx.accounts.signer.key(),
+        start_ts: slot.start_ts,
+        end_ts: slot.end_ts,
+    });
+    Ok(())
+}
+
+pub fn reject_reschedule_sol(ctx: Context<RejectRescheduleSol>) -> Result<()> {
+    let payment = EscrowPayment::sol(
+        &ctx.accounts.slot,
+        &ctx.accounts.escrow,
+        ctx.accounts.system_program.to_account_info(),
+    )?;
+    let slot = &mut ctx.accounts.slot;
+    require!(!slot.frozen, ErrorCode::Frozen);
+    require!(slot.state == SlotState::Reserved, ErrorCode::InvalidState);
+    let proposal = slot.reschedule.ok_or(ErrorCode::NoReschedulePending)?;
+    let escrow = &mut ctx.accounts.escrow;
+    let buyer = escrow.buyer.ok_or(ErrorCode::NotReserved)?;
+    let now = Clock::get()?.unix_timestamp;
+    require!(
+        may_answer(&proposal, buyer, ctx.accounts.signer.key(), now, false),
+        ErrorCode::Unauthorized
+    );
+    let refund_to = escrow.refund_to().ok_or(ErrorCode::NotReserved)?;
+
+    // Full refund to whoever paid, leaving the escrow's rent reserve in place
+    let amount = escrow.amount_locked;
+    payment.pay(Payout { to: &ctx.accounts.refund_receiver.to_account_info(), amount })?;
+    escrow.amount_locked = 0;
+    escrow.buyer = None;
+    escrow.payer = None;
+    slot.reschedule = None;
+    slot.state = SlotState::Refunded;
+    emit!(RefundedEvent { slot: slot.key(), to: refund_to, amount });
+    emit!(RescheduleResolvedEvent {
+        slot: slot.key(),
+        accepted: false,
+        by: ctx.accounts.signer.key(),
+        start_ts: slot.start_ts,
+        end_ts: slot.end_ts,
+    });
+    Ok(())
+}
@@ -0,0 +1,211 @@
+use anchor_lang::prelude::*;
+
+use crate::*;
+use crate::ErrorCode;
+use crate::market::{check_slot_mint, create_slot_account, is_untouched, slot_params, validate_slot_params, write_slot};
+
+// Recurring slots: a SlotSeries holds the shared terms and materialize_series turns the next
+// occurrences into ordinary TimeSlots. Occurrences of one series never overlap each other, but
+// they are not checked against the creator's other slots: parallel sessions at the same time
+// are allowed (slots are seeded by the creator's counter, not start_ts), so respecting the
+// creator's other slots means taking the next free slot indices rather than colliding with them.
+
+// Expand occurrence `k` of the template into the same params create_time_slot takes
+fn occurrence_params(series: &SlotSeries, k: u16) -> Result<CreateSlotParams> {
+    let offset = (series.interval_sec as i64)
+        .checked_mul(k as i64)
+        .ok_or(ErrorCode::Overflow)?;
+    let start_ts = series.first_start_ts.checked_add(offset).ok_or(ErrorCode::Overflow)?;
+    let end_ts = start_ts.checked_add(series.duration_sec as i64).ok_or(ErrorCode::Overflow)?;
+    Ok(CreateSlotParams {
+        start_ts,
+        end_ts,
+        tz_offset_min: series.tz_offset_min,
+        subject_hash: series.subject_hash,
+        venue_hash: series.venue_hash,
+        mode: series.mode,
+        capacity: series.capacity,
+        nft_mint: None,
+        price: series.price,
+        min_increment_bps: series.min_increment_bps,
+        buy_now: series.buy_now,
+        auction_start_ts: series.auction_open_before_sec.map(|s| start_ts - s as i64),
+        auction_end_ts: series.auction_close_before_sec.map(|s| start_ts - s as i64),
+        anti_sniping_sec: series.anti_sniping_sec,
+        cancel_policy: series.cancel_policy.clone(),
+        payment_kind: series.payment_kind,
+        mint: series.mint,
+        usd_quote: series.usd_quote,
+        transfers_disabled: series.transfers_disabled,
+        resale_royalty_bps: series.resale_royalty_bps,
+        max_resale_price: series.max_resale_price,
+        gate: series.gate,
+        allowlist_root: series.allowlist_root,
+        approval_window_sec: series.approval_window_sec,
+        draft: false,
+        // Occurrences get no NFT mint, so ticket mode cannot apply
+        nft_ticket: false,
+    })
+}
+
+// Template-level checks on top of the per-occurrence slot validation
+fn validate_series(series: &SlotSeries) -> Result<()> {
+    require!(series.count > 0, ErrorCode::InvalidCapacity);
+    require!(series.count >= series.materialized, ErrorCode::InvalidCapacity);
+    // Occurrences of one series must not overlap each other
+    if series.count > 1 {
+        require!(series.interval_sec >= series.duration_sec, ErrorCode::InvalidTimes);
+    }
+    // Terms are checked on the next occurrence, or on the last one once all are materialized,
+    // so edits that only reach existing slots are validated too
+    let k = series.materialized.min(series.count - 1);
+    validate_slot_params(&occurrence_params(series, k)?)
+}
+
+pub fn create_slot_series(ctx: Context<CreateSlotSeries>, params: CreateSeriesParams) -> Result<()> {
+    let profile = &mut ctx.accounts.profile;
+    let series = &mut ctx.accounts.series;
+    series.creator_profile = profile.key();
+    series.creator_authority = profile.authority;
+    series.platform = ctx.accounts.platform.key();
+    series.mint = params.mint;
+    series.index = profile.series_count;
+    series.first_start_ts = params.first_start_ts;
+    series.duration_sec = params.duration_sec;
+    series.interval_sec = params.interval_sec;
+    series.count = params.count;
+    series.materialized = 0;
+    series.tz_offset_min = params.tz_offset_min;
+    series.subject_hash = params.subject_hash;
+    series.venue_hash = params.venue_hash;
+    series.mode = params.mode;
+    series.capacity = params.capacity;
+    series.price = params.price;
+    series.min_increment_bps = params.min_increment_bps;
+    series.buy_now = params.buy_now;
+    series.auction_open_before_sec = params.auction_open_before_sec;
+    series.auction_close_before_sec = params.auction_close_before_sec;
+    series.anti_sniping_sec = params.anti_sniping_sec;
+    series.cancel_policy = params.cancel_policy;
+    series.payment_kind = params.payment_kind;
+    series.usd_quote = params.usd_quote;
+    series.transfers_disabled = params.transfers_disabled;
+    series.resale_royalty_bps = params.resale_royalty_bps;
+    series.max_resale_price = params.max_resale_price;
+    series.gate = params.gate;
+    series.allowlist_root = params.allowlist_root;
+    series.approval_window_sec = params.approval_window_sec;
+    series.bump = ctx.bumps.series;
+    validate_series(series)?;
+    check_slot_mint(
+        series.mint,
+        series.payment_kind,
+        series.platform,
+        ctx.accounts.accepted_mint.as_deref(),
+    )?;
+    profile.series_count = profile.series_count.checked_add(1).ok_or(ErrorCode::Overflow)?;
+    Ok(())
+}
+
+pub fn update_slot_series<'info>(
+    ctx: Context<'_, '_, 'info, 'info, UpdateSlotSeries<'info>>,
+    params: UpdateSeriesParams,
+) -> Result<()> {
+    let series = &mut ctx.accounts.series;
+    // Materialized occurrences keep their times, so the schedule is fixed once any exist;
+    // a shorter interval would make later occurrences overlap them
+    if series.materialized > 0 {
+        require!(
+            params.duration_sec.map_or(true, |v| v == series.duration_sec)
+                && params.interval_sec.map_or(true, |v| v == series.interval_sec),
+            ErrorCode::InvalidTimes
+        );
+    }
+    if let Some(v) = params.duration_sec { series.duration_sec = v; }
+    if let Some(v) = params.interval_sec { series.interval_sec = v; }
+    if let Some(v) = params.count { series.count = v; }
+    if let Some(v) = params.subject_hash { series.subject_hash = v; }
+    if let Some(v) = params.venue_hash { series.venue_hash = v; }
+    if let Some(v) = params.capacity { series.capacity = v; }
+    if let Some(v) = params.price { series.price = v; }
+    if let Some(v) = params.min_increment_bps { series.min_increment_bps = v; }
+    if let Some(v) = params.buy_now { series.buy_now = v; }
+    if let Some(v) = params.anti_sniping_sec { series.anti_sniping_sec = v; }
+    if let Some(v) = params.cancel_policy { series.cancel_policy = v; }
+    validate_series(series)?;
+
+    // Carry commercial terms over to materialized occurrences that are in the future and pass
+    // the same untouched check as update_slot; the others keep the terms they were engaged under.
+    let series_key = series.key();
+    let now = Clock::get()?.unix_timestamp;
+    let mut slots_updated: u16 = 0;
+    for chunk in ctx.remaining_accounts.chunks(3) {
+        let [info, escrow_info, commit_info] = chunk else {
+            return err!(ErrorCode::InvalidSlotAccount);
+        };
+        let mut slot: Account<TimeSlot> = Account::try_from(info)?;
+        require_keys_eq!(slot.series, series_key, ErrorCode::InvalidSlotAccount);
+        let slot_key = slot.key();
+        let (escrow_pda, _) = Pubkey::find_program_address(&[b"escrow", slot_key.as_ref()], &crate::ID);
+        let (commit_pda, _) = Pubkey::find_program_address(&[b"commit", slot_key.as_ref()], &crate::ID);
+        require_keys_eq!(escrow_info.key(), escrow_pda, ErrorCode::InvalidSlotAccount);
+        require_keys_eq!(commit_info.key(), commit_pda, ErrorCode::InvalidSlotAccount);
+        if slot.start_ts <= now || !is_untouched(&slot, escrow_info, commit_info, now)? {
+            continue;
+        }
+        slot.subject_hash = series.subject_hash;
+        slot.venue_hash = series.venue_hash;
+        slot.capacity_total = series.capacity;
+        slot.price = series.price;
+        slot.min_increment_bps = series.min_increment_bps;
+        slot.buy_now = series.buy_now;
+        slot.anti_sniping_sec = series.anti_sniping_sec;
+        slot.cancel_policy = series.cancel_policy.clone();
+        validate_slot_params(&slot_params(&slot))?;
+        slot.exit(&crate::ID)?;
+        slots_updated = slots_updated.saturating_add(1);
+    }
+    emit!(SeriesUpdatedEvent { series: series_key, slots_updated });
+    Ok(())
+}
+
+pub fn materialize_series<'info>(
+    ctx: Context<'_, '_, 'info, 'info, MaterializeSeries<'info>>,
+    count: u16,
+) -> Result<()> {
+    require!(count > 0, ErrorCode::InvalidCapacity);
+    require!(ctx.remaining_accounts.len() == count as usize, ErrorCode::InvalidSlotAccount);
+    let series = &mut ctx.accounts.series;
+    let remaining = series.count.checked_sub(series.materialized).ok_or(ErrorCode::Overflow)?;
+    require!(count <= remaining, ErrorCode::SeriesExhausted);
+
+    let profile = &mut ctx.accounts.profile;
+    let profile_key = profile.key();
+    let platform_key = ctx.accounts.platform.key();
+    let payer = ctx.accounts.authority.to_account_info();
+    let system_program = ctx.accounts.system_program.to_account_info();
+    let series_key = series.key();
+    let first_slot_index = profile.slot_count;
+    // The mint must still be accepted when new occurrences are created
+    check_slot_mint(series.mint, series.payment_kind, platform_key, ctx.accounts.accepted_mint.as_deref())?;
+
+    // Occurrences take the creator's next slot indices, interleaving with their other slots
+    for info in ctx.remaining_accounts.iter() {
+        let params = occurrence_params(series, series.materialized)?;
+        validate_slot_params(&params)?;
+        let bump = create_slot_account(info, &payer, &system_program, &profile_key, profile.slot_count)?;
+        let mut slot: Account<TimeSlot> = Account::try_from_unchecked(info)?;
+        write_slot(&mut slot, profile, profile_key, platform_key, params, bump);
+        slot.series = series_key;
+        slot.exit(&crate::ID)?;
+        profile.slot_count = profile.slot_count.checked_add(1).ok_or(ErrorCode::Overflow)?;
+        series.materialized = series.materialized.checked_add(1).ok_or(ErrorCode::Overflow)?;
+    }
+    emit!(SeriesMaterializedEvent {
+        series: series_key,
+        first_slot_index,
+        count,
+        materialized: series.materialized,
+    });
+    Ok(())
+}
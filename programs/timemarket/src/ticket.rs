@@ -0,0 +1,82 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{burn, Burn, Mint, TokenAccount};
+
+use crate::*;
+use crate::ErrorCode;
+
+// Ticket mode: the slot's NFT is minted when the booking is made and whoever holds it has the
+// holder rights (check-in, dispute). Wallets and marketplaces move it like any other token.
+
+// Mint the ticket to the booking's holder; a no-op for slots not in ticket mode
+pub fn mint_ticket<'info>(
+    slot: &mut Account<'info, TimeSlot>,
+    token_program: &AccountInfo<'info>,
+    nft_mint: Option<&InterfaceAccount<'info, Mint>>,
+    to: Option<&InterfaceAccount<'info, TokenAccount>>,
+    nft_auth: Option<&AccountInfo<'info>>,
+    holder: Pubkey,
+) -> Result<()> {
+    if !slot.nft_ticket {
+        return Ok(());
+    }
+    let nft_mint = nft_mint.ok_or(ErrorCode::InvalidTicket)?;
+    let to = to.ok_or(ErrorCode::InvalidTicket)?;
+    let nft_auth = nft_auth.ok_or(ErrorCode::InvalidTicket)?;
+    require_keys_eq!(nft_mint.key(), slot.nft_mint, ErrorCode::InvalidTicket);
+    require_keys_eq!(to.mint, slot.nft_mint, ErrorCode::InvalidTicket);
+    require_keys_eq!(to.owner, holder, ErrorCode::InvalidTicket);
+
+    crate::nft::mint_slot_nft(
+        slot,
+        token_program,
+        &nft_mint.to_account_info(),
+        &to.to_account_info(),
+        nft_auth,
+    )?;
+    emit!(TicketMintedEvent { slot: slot.key(), mint: slot.nft_mint, holder });
+    Ok(())
+}
+
+// Holder rights for `signer`: the ticket owner in ticket mode, otherwise the escrow's buyer
+pub fn is_holder(
+    slot: &TimeSlot,
+    escrow: &Escrow,
+    ticket: Option<&InterfaceAccount<TokenAccount>>,
+    signer: Pubkey,
+) -> Result<bool> {
+    if !slot.nft_ticket {
+        return Ok(escrow.buyer == Some(signer));
+    }
+    Ok(match ticket {
+        Some(t) => t.mint == slot.nft_mint && t.owner == signer && t.amount >= 1,
+        None => false,
+    })
+}
+
+// Use up the ticket so it cannot be presented again; its unit of supply goes back to the slot
+pub fn burn_ticket<'info>(
+    slot: &mut TimeSlot,
+    token_program: Option<&AccountInfo<'info>>,
+    nft_mint: Option<&InterfaceAccount<'info, Mint>>,
+    ticket: Option<&InterfaceAccount<'info, TokenAccount>>,
+    owner: &AccountInfo<'info>,
+) -> Result<()> {
+    let token_program = token_program.ok_or(ErrorCode::InvalidTicket)?;
+    let nft_mint = nft_mint.ok_or(ErrorCode::InvalidTicket)?;
+    let ticket = ticket.ok_or(ErrorCode::InvalidTicket)?;
+    require_keys_eq!(nft_mint.key(), slot.nft_mint, ErrorCode::InvalidTicket);
+    require_keys_eq!(ticket.owner, owner.key(), ErrorCode::InvalidTicket);
+    burn(
+        CpiContext::new(
+            token_program.clone(),
+            Burn {
+                mint: nft_mint.to_account_info(),
+                from: ticket.to_account_info(),
+                authority: owner.clone(),
+            },
+        ),
+        1,
+    )?;
+    slot.nft_minted = slot.nft_minted.checked_sub(1).ok_or(ErrorCode::Overflow)?;
+    Ok(())
+}
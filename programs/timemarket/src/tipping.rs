@@ -1,11 +1,12 @@
 use anchor_lang::prelude::*;
 use anchor_lang::system_program;
-use anchor_spl::token_interface::{transfer_checked, Mint, TokenAccount, TokenInterface, TransferChecked};
+use anchor_spl::token_interface::TransferChecked;
 
 use crate::*;
+use crate::escrow::hooked_transfer_checked;
 
 /// Tip a creator with SPL tokens (direct payment, no escrow, no platform fee)
-pub fn tip_creator_spl(ctx: Context<TipCreatorSpl>, amount: u64, message_hash: Option<[u8; 32]>) -> Result<()> {
+pub fn tip_creator_spl<'info>(ctx: Context<'_, '_, 'info, 'info, TipCreatorSpl<'info>>, amount: u64, message_hash: Option<[u8; 32]>) -> Result<()> {
     require!(amount > 0, ErrorCode::InvalidAmount);
     
     let profile = &mut ctx.accounts.creator_profile;
@@ -19,8 +20,9 @@ pub fn tip_creator_spl(ctx: Context<TipCreatorSpl>, amount: u64, message_hash: O
         authority: ctx.accounts.tipper.to_account_info(),
     };
     
-    transfer_checked(
-        CpiContext::new(ctx.accounts.token_program.to_account_info(), cpi_accounts),
+    hooked_transfer_checked(
+        CpiContext::new(ctx.accounts.token_program.to_account_info(), cpi_accounts)
+            .with_remaining_accounts(ctx.remaining_accounts.to_vec()),
         amount,
         decimals,
     )?;
@@ -73,7 +75,7 @@ pub fn tip_creator_sol(ctx: Context<TipCreatorSol>, amount: u64, message_hash: O
 }
 
 /// Tip for a specific session (optional slot reference)
-pub fn tip_for_session_spl(ctx: Context<TipForSessionSpl>, amount: u64, message_hash: Option<[u8; 32]>) -> Result<()> {
+pub fn tip_for_session_spl<'info>(ctx: Context<'_, '_, 'info, 'info, TipForSessionSpl<'info>>, amount: u64, message_hash: Option<[u8; 32]>) -> Result<()> {
     require!(amount > 0, ErrorCode::InvalidAmount);
     
     let profile = &mut ctx.accounts.creator_profile;
@@ -91,8 +93,9 @@ pub fn tip_for_session_spl(ctx: Context<TipForSessionSpl>, amount: u64, message_
         authority: ctx.accounts.tipper.to_account_info(),
     };
     
-    transfer_checked(
-        CpiContext::new(ctx.accounts.token_program.to_account_info(), cpi_accounts),
+    hooked_transfer_checked(
+        CpiContext::new(ctx.accounts.token_program.to_account_info(), cpi_accounts)
+            .with_remaining_accounts(ctx.remaining_accounts.to_vec()),
         amount,
         decimals,
     )?;
@@ -0,0 +1,41 @@
+/// <reference types="mocha" />
+/// <reference types="node" />
+import { readFileSync } from 'fs';
+import { join } from 'path';
+import { expect } from 'chai';
+
+function read(path: string) { return readFileSync(path, 'utf8'); }
+
+describe('Multiple accepted mints (P1) TDD', () => {
+  const root = process.cwd();
+  const rustLibPath = join(root, 'programs', 'timemarket', 'src', 'lib.rs');
+  const marketPath = join(root, 'programs', 'timemarket', 'src', 'market.rs');
+  const pdasPath = join(root, 'packages', 'ts-sdk', 'src', 'helpers', 'pdas.ts');
+
+  it('defines an AcceptedMint PDA registry with its own vaults', () => {
+    const lib = read(rustLibPath);
+    expect(lib).to.match(/pub struct AcceptedMint \{[\s\S]*mint: Pubkey[\s\S]*fee_vault: Pubkey[\s\S]*dispute_vault: Pubkey[\s\S]*enabled: bool/);
+    expect(lib).to.match(/seeds = \[b"accepted_mint", platform\.key\(\)\.as_ref\(\), mint\.key\(\)\.as_ref\(\)\]/);
+    expect(lib).to.match(/pub fn add_accepted_mint\(ctx: Context<AddAcceptedMint>\)/);
+    expect(lib).to.match(/pub fn set_accepted_mint_enabled\(ctx: Context<SetAcceptedMintEnabled>, enabled: bool\)/);
+    expect(read(pdasPath)).to.match(/export function acceptedMintPda/);
+  });
+
+  it('slots pick a registered mint and TimeSlot.mint drives settlement', () => {
+    const lib = read(rustLibPath);
+    const market = read(marketPath);
+    expect(lib).to.match(/struct CreateSlotParams[\s\S]*pub mint: Pubkey/);
+    expect(market).to.match(/check_slot_mint\(\s*params\.mint/);
+    expect(market).to.match(/slot\.mint = params\.mint/);
+    expect(lib).to.not.match(/mint\.key\(\) == platform\.mint/);
+    expect(lib).to.match(/struct StableSettle<'info>[\s\S]*?constraint = mint\.key\(\) == slot\.mint/);
+  });
+
+  it('fees and retained amounts go to the vaults of the slot mint', () => {
+    const lib = read(rustLibPath);
+    expect(lib).to.not.match(/address = platform\.(fee|dispute)_vault/);
+    for (const ctx of ['StableSettle', 'AuctionSettle', 'SealedAuctionSettle']) {
+      expect(lib).to.match(new RegExp(`struct ${ctx}<'info>[\\s\\S]*?slot\\.mint\\.as_ref\\(\\)[\\s\\S]*?address = accepted_mint\\.fee_vault[\\s\\S]*?address = accepted_mint\\.dispute_vault`));
+    }
+  });
+});
@@ -0,0 +1,42 @@
+/// <reference types="mocha" />
+/// <reference types="node" />
+import { readFileSync } from 'fs';
+import { join } from 'path';
+import { expect } from 'chai';
+
+function read(path: string) { return readFileSync(path, 'utf8'); }
+
+describe('Account layout migration (P1) TDD', () => {
+  const root = process.cwd();
+  const src = (f: string) => join(root, 'programs', 'timemarket', 'src', f);
+
+  it('keeps every launch-time field before bump and appends the rest after it', () => {
+    const lib = read(src('lib.rs'));
+    const body = (name: string) => (lib.match(new RegExp(`pub struct ${name} \\{[\\s\\S]*?\\n\\}`)) || [''])[0];
+    expect(body('CreatorProfile')).to.match(/pub tip_count: u32,\s*pub bump: u8,/);
+    expect(body('TimeSlot')).to.match(/pub total_tips_received: u64,\s*pub bump: u8,/);
+    expect(body('Escrow')).to.match(/pub buyer: Option<Pubkey>,\s*pub bump: u8,/);
+    expect(body('BidBook')).to.match(/pub last_bid_ts: i64,\s*pub bump: u8,/);
+    for (const name of ['CreatorProfile', 'TimeSlot', 'Escrow', 'BidBook']) {
+      expect(lib, name).to.match(new RegExp(`impl ${name} \\{[\\s\\S]*?pub const LEN_V0: usize =`));
+    }
+  });
+
+  it('exposes migrate_account and a creator-signed migrate_slot', () => {
+    const lib = read(src('lib.rs'));
+    expect(lib).to.match(/pub fn migrate_account\(ctx: Context<MigrateAccount>\)/);
+    expect(lib).to.match(/pub fn migrate_slot\(ctx: Context<MigrateSlot>, payment_kind: PaymentKind\)/);
+    expect(lib).to.match(/pub struct MigrateAccount<'info> \{[\s\S]*?#\[account\(mut, owner = crate::ID\)\]/);
+  });
+
+  it('only grows accounts still at their pre-upgrade size and covers the rent difference', () => {
+    const migrate = read(src('migrate.rs'));
+    const grow = (migrate.match(/fn grow<'info>\([\s\S]*?\n\}/) || [''])[0];
+    expect(grow).to.match(/require!\(account\.data_len\(\) == 8 \+ old_len, ErrorCode::InvalidMigration\)/);
+    expect(grow).to.match(/minimum_balance\(8 \+ new_len\)\.saturating_sub\(rent\.minimum_balance\(8 \+ old_len\)\)/);
+    expect(grow).to.match(/account\.resize\(8 \+ new_len\)/);
+    expect(migrate).to.match(/book\.highest_beneficiary = book\.highest_bidder/);
+    expect(migrate).to.match(/require_keys_eq!\(creator_authority, ctx\.accounts\.authority\.key\(\), ErrorCode::Unauthorized\)/);
+    expect(migrate).to.match(/slot\.payment_kind = payment_kind/);
+  });
+});
@@ -0,0 +1,66 @@
+/// <reference types="mocha" />
+/// <reference types="node" />
+import { readFileSync } from 'fs';
+import { join } from 'path';
+import { expect } from 'chai';
+
+function read(path: string) { return readFileSync(path, 'utf8'); }
+
+describe('Merkle allowlists (P1) TDD', () => {
+  const root = process.cwd();
+  const src = (f: string) => join(root, 'programs', 'timemarket', 'src', f);
+
+  it('slots and series carry an optional allowlist root', () => {
+    const lib = read(src('lib.rs'));
+    expect(lib).to.match(/struct CreateSlotParams \{[\s\S]*?pub allowlist_root: Option<\[u8; 32\]>/);
+    expect(lib).to.match(/pub struct TimeSlot \{[\s\S]*?pub allowlist_root: Option<\[u8; 32\]>/);
+    expect(lib).to.match(/pub struct SlotSeries \{[\s\S]*?pub allowlist_root: Option<\[u8; 32\]>/);
+    expect(read(src('market.rs'))).to.match(/slot\.allowlist_root = params\.allowlist_root;/);
+    expect(read(src('series.rs'))).to.match(/allowlist_root: series\.allowlist_root/);
+  });
+
+  it('reservation and bid instructions take a proof with an optional quantity', () => {
+    const lib = read(src('lib.rs'));
+    expect(lib).to.match(/pub struct AllowlistProof \{[\s\S]*?pub quantity: Option<u16>,[\s\S]*?pub proof: Vec<\[u8; 32\]>/);
+    for (const f of ['stable_reserve', 'stable_reserve_sol', 'bid_place', 'bid_place_sol', 'buy_now', 'buy_now_sol', 'bid_commit']) {
+      expect(lib, f).to.match(new RegExp(`pub fn ${f}(<'info>)?\\([^)]*allowlist: Option<AllowlistProof>,?\\s*\\)`));
+    }
+    const calls = ['escrow.rs', 'market.rs', 'auction_sol.rs', 'resale.rs']
+      .map((f) => (read(src(f)).match(/crate::allowlist::check_allowlist\(/g) || []).length)
+      .reduce((a, b) => a + b, 0);
+    expect(calls).to.equal(9);
+  });
+
+  it('verifies and caps the beneficiary, and re-verifies on transfer and resale', () => {
+    const escrow = read(src('escrow.rs'));
+    expect(escrow).to.match(/pub fn stable_reserve<'info>[\s\S]*?check_allowlist\(&ctx\.accounts\.slot, beneficiary,[\s\S]*?consume_allowlist\([\s\S]*?beneficiary,/);
+    expect(escrow).to.match(/pub fn stable_reserve_sol[\s\S]*?check_allowlist\(&ctx\.accounts\.slot, beneficiary,/);
+    expect(read(src('market.rs'))).to.match(/pub fn bid_place<'info>[\s\S]*?check_allowlist\(&ctx\.accounts\.slot, beneficiary,/);
+    expect(read(src('auction_sol.rs'))).to.match(/pub fn bid_place_sol[\s\S]*?check_allowlist\(&ctx\.accounts\.slot, beneficiary,/);
+    expect(escrow).to.match(/pub fn transfer_reservation\([\s\S]*?check_allowlist\(slot, new_holder,[\s\S]*?consume_allowlist\(/);
+    expect(read(src('resale.rs'))).to.match(/pub fn buy_listing[\s\S]*?check_allowlist\(slot, ctx\.accounts\.buyer\.key\(\),[\s\S]*?consume_allowlist\(/);
+    const lib = read(src('lib.rs'));
+    expect(lib).to.match(/seeds = \[b"allowlist", slot\.key\(\)\.as_ref\(\), beneficiary\.as_ref\(\)\.map_or\(buyer\.key\(\), \|b\| b\.key\(\)\)\.as_ref\(\)\]/);
+    expect(lib).to.match(/seeds = \[b"allowlist", slot\.key\(\)\.as_ref\(\), new_holder\.as_ref\(\)\]/);
+    expect(read(src('allowlist.rs'))).to.match(/require!\(!\(slot\.nft_ticket && root\.is_some\(\)\), ErrorCode::NftTicketMode\)/);
+  });
+
+  it('verifies sorted-pair proofs and caps bookings per address', () => {
+    const al = read(src('allowlist.rs'));
+    expect(al).to.match(/hashv\(&\[wallet\.as_ref\(\), &q\.to_le_bytes\(\)\]\)/);
+    expect(al).to.match(/if node <= \*sibling/);
+    expect(al).to.match(/require!\(claim\.used < cap, ErrorCode::AllowlistQuantityExceeded\)/);
+    const lib = read(src('lib.rs'));
+    expect(lib).to.match(/seeds = \[b"allowlist", slot\.key\(\)\.as_ref\(\), buyer\.key\(\)\.as_ref\(\)\]/);
+    expect(lib).to.match(/NotOnAllowlist/);
+  });
+
+  it('lets the creator rotate the root until sales close', () => {
+    const lib = read(src('lib.rs'));
+    expect(lib).to.match(/pub fn set_allowlist_root\(ctx: Context<SetAllowlistRoot>, root: Option<\[u8; 32\]>\)/);
+    expect(lib).to.match(/pub struct SetAllowlistRoot<'info> \{[\s\S]*?slot\.creator_authority == creator\.key\(\)/);
+    const al = read(src('allowlist.rs'));
+    expect(al).to.match(/require!\(now < closes_at, ErrorCode::TooLate\)/);
+    expect(al).to.match(/emit!\(AllowlistRootSetEvent/);
+  });
+});
@@ -0,0 +1,32 @@
+/// <reference types="mocha" />
+/// <reference types="node" />
+import { readFileSync } from 'fs';
+import { join } from 'path';
+import { expect } from 'chai';
+
+function read(path: string) { return readFileSync(path, 'utf8'); }
+
+describe('Soulbound attendance record (P1) TDD', () => {
+  const root = process.cwd();
+  const src = (f: string) => join(root, 'programs', 'timemarket', 'src', f);
+
+  it('Attendance PDA is keyed by creator profile and attendee', () => {
+    const lib = read(src('lib.rs'));
+    expect(lib).to.match(/pub struct Attendance \{[\s\S]*?sessions: u32[\s\S]*?seconds_attended: u64/);
+    for (const name of ['StableCheckin', 'AuctionCheckin']) {
+      const ctx = (lib.match(new RegExp(`pub struct ${name}<'info> \\{[\\s\\S]*?\\n\\}`)) || [''])[0];
+      expect(ctx).to.match(/seeds = \[b"attendance", slot\.creator_profile\.as_ref\(\), attendee\.key\(\)\.as_ref\(\)\]/);
+      expect(ctx).to.match(/init_if_needed/);
+    }
+    expect(lib).to.match(/pub struct AttendanceRecordedEvent \{/);
+  });
+
+  it('both check-in paths record attendance next to CheckinEvent', () => {
+    for (const f of ['escrow.rs', 'market.rs']) {
+      expect(read(src(f))).to.match(/attendance::record_attendance\([\s\S]*?\n\s*emit!\(CheckinEvent/);
+    }
+    const attendance = read(src('attendance.rs'));
+    expect(attendance).to.match(/slot\.end_ts\.saturating_sub\(slot\.start_ts\)/);
+    expect(attendance).to.match(/attendance\.sessions\.checked_add\(1\)/);
+  });
+});
@@ -28,7 +28,7 @@ describe('Auction safety fix (superseded by refund queue) TDD', () => {
 
   it('BidOutbidRefund consumes the refund queue entries', () => {
     const market = read(marketPath);
-    expect(market).to.match(/pub fn bid_outbid_refund\(ctx: Context<BidOutbidRefund>\)/);
+    expect(market).to.match(/pub fn bid_outbid_refund(<'info>)?\(\s*ctx: Context<(?:'_, '_, 'info, 'info, )?BidOutbidRefund(?:<'info>)?>,?\s*\)/);
     expect(market).to.match(/let idx = q\.cursor as usize/);
     expect(market).to.match(/q\.cursor\s*=\s*q\.cursor\.saturating_add\(1\)/);
     expect(market).to.match(/q\.count\s*=\s*q\.count\.saturating_sub\(1\)/);
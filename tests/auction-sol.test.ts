@@ -0,0 +1,67 @@
+/// <reference types="mocha" />
+/// <reference types="node" />
+import { readFileSync } from 'fs';
+import { join } from 'path';
+import { expect } from 'chai';
+
+function read(path: string) { return readFileSync(path, 'utf8'); }
+
+describe('Native SOL auctions (P1) TDD', () => {
+  const root = process.cwd();
+  const rustLibPath = join(root, 'programs', 'timemarket', 'src', 'lib.rs');
+  const auctionSolPath = join(root, 'programs', 'timemarket', 'src', 'auction_sol.rs');
+  const escrowPath = join(root, 'programs', 'timemarket', 'src', 'escrow.rs');
+
+  it('exposes SOL variants of every auction instruction', () => {
+    const lib = read(rustLibPath);
+    for (const ix of ['bid_place_sol', 'bid_outbid_refund_sol', 'buy_now_sol', 'auction_end_sol', 'auction_settle_sol', 'sealed_auction_end_sol', 'sealed_auction_settle_sol']) {
+      expect(lib).to.match(new RegExp(`pub fn ${ix}\\(`));
+    }
+  });
+
+  it('SOL auction accounts use system accounts instead of the platform mint', () => {
+    const lib = read(rustLibPath);
+    expect(lib).to.match(/struct BidPlaceSol<'info>[\s\S]*system_program: Program<'info, System>/);
+    expect(lib).to.match(/struct AuctionSettleSol<'info>[\s\S]*creator_payout: SystemAccount<'info>[\s\S]*fee_receiver: SystemAccount<'info>[\s\S]*dispute_receiver: SystemAccount<'info>/);
+    const section = lib.slice(lib.indexOf('Auction SOL Accounts'));
+    expect(section).to.not.match(/platform\.mint/);
+  });
+
+  it('pins SOL fee and dispute receivers to the platform configuration', () => {
+    const lib = read(rustLibPath);
+    expect(lib).to.match(/pub fn set_sol_receivers\(/);
+    for (const ctx of ['BuyNowSol', 'AuctionEndSol', 'AuctionSettleSol', 'SealedAuctionEndSol', 'SealedAuctionSettleSol']) {
+      const body = (lib.match(new RegExp(`pub struct ${ctx}<'info> \\{[\\s\\S]*?\\n\\}`)) || [''])[0];
+      expect(body, ctx).to.match(/seeds = \[b"sol_receivers", slot\.platform\.as_ref\(\)\]/);
+      expect(body, ctx).to.match(/#\[account\(mut, address = sol_receivers\.fee_receiver @ ErrorCode::InvalidSolReceiver\)\]\s*pub fee_receiver/);
+      if (/dispute_receiver/.test(body)) {
+        expect(body, ctx).to.match(/#\[account\(mut, address = sol_receivers\.dispute_receiver @ ErrorCode::InvalidSolReceiver\)\]\s*pub dispute_receiver/);
+      }
+    }
+  });
+
+  it('bids move lamports into escrow and share bid book logic with SPL', () => {
+    const sol = read(auctionSolPath);
+    expect(sol).to.match(/bid_place_sol[\s\S]*check_bid\([\s\S]*payment\.collect\([\s\S]*record_bid\(/);
+  });
+
+  it('escrow lamport payouts protect the rent-exempt minimum', () => {
+    const escrow = read(escrowPath);
+    const lib = read(rustLibPath);
+    expect(escrow).to.match(/pub fn pay_from_escrow_lamports[\s\S]*minimum_balance\(escrow\.data_len\(\)\)[\s\S]*EscrowRentViolation/);
+    expect(lib).to.match(/EscrowRentViolation/);
+    const sol = read(auctionSolPath);
+    expect(sol).to.not.match(/try_borrow_mut_lamports/);
+  });
+
+  it('keeps T0/T1 semantics with AUCTION_T0_BPS and FINAL_RELEASE_BPS', () => {
+    const sol = read(auctionSolPath);
+    const escrow = read(escrowPath);
+    // The split itself is shared with the SPL auction paths
+    expect(sol).to.match(/pay_auction_t0\(\s*&payment,/);
+    expect(sol).to.match(/pay_auction_t1\(\s*&payment,/);
+    expect(escrow).to.match(/pub fn pay_auction_t0[\s\S]*?AUCTION_T0_BPS/);
+    expect(escrow).to.match(/pub fn pay_auction_t1[\s\S]*?FINAL_RELEASE_BPS/);
+    expect(sol).to.match(/SettledT1Event/);
+  });
+});
@@ -29,7 +29,7 @@ describe('Auto-bid (P1) TDD', () => {
     const lib = read(rustLibPath);
     const market = read(marketPath);
     expect(lib).to.match(/struct BidPlace[\s\S]*auto_bid_store: Account<'info, AutoBidStore>/);
-    expect(market).to.match(/pub fn bid_place\(ctx: Context<BidPlace>, bid_amount: u64, _max_auto_bid: Option<u64>\)/);
+    expect(market).to.match(/pub fn bid_place(<'info>)?\(\s*ctx: Context<(?:'_, '_, 'info, 'info, )?BidPlace(?:<'info>)?>, bid_amount: u64, _max_auto_bid: Option<u64>(,\s*allowlist: Option<AllowlistProof>)?\)/);
     expect(market).to.match(/if let Some\(max\) = _max_auto_bid \{/);
     expect(market).to.match(/store\.count < store\.max_entries/);
   });
@@ -0,0 +1,25 @@
+/// <reference types="mocha" />
+/// <reference types="node" />
+import { readFileSync } from 'fs';
+import { join } from 'path';
+import { expect } from 'chai';
+
+function read(path: string) { return readFileSync(path, 'utf8'); }
+
+describe('Batch slot creation (P2) TDD', () => {
+  const root = process.cwd();
+  const rustLibPath = join(root, 'programs', 'timemarket', 'src', 'lib.rs');
+  const marketPath = join(root, 'programs', 'timemarket', 'src', 'market.rs');
+
+  it('lib.rs exposes create_time_slots_batch taking Vec<CreateSlotParams>', () => {
+    const lib = read(rustLibPath);
+    expect(lib).to.match(/pub fn create_time_slots_batch<'info>\([\s\S]*params: Vec<CreateSlotParams>/);
+    expect(lib).to.match(/struct CreateTimeSlotsBatch<'info>[\s\S]*authority: Signer<'info>[\s\S]*profile: Account<'info, CreatorProfile>/);
+  });
+
+  it('validates every entry before creating slots from remaining_accounts', () => {
+    const market = read(marketPath);
+    expect(market).to.match(/create_time_slots_batch[\s\S]*for p in params\.iter\(\) \{\s*validate_slot_params\(p\)\?;/);
+    expect(market).to.match(/create_time_slots_batch[\s\S]*create_slot_account\(info, &payer, &system_program, &profile_key, profile\.slot_count\)/);
+  });
+});
@@ -0,0 +1,63 @@
+/// <reference types="mocha" />
+/// <reference types="node" />
+import { readFileSync } from 'fs';
+import { join } from 'path';
+import { expect } from 'chai';
+
+function read(path: string) { return readFileSync(path, 'utf8'); }
+
+describe('Booking requests (P1) TDD', () => {
+  const root = process.cwd();
+  const src = (f: string) => join(root, 'programs', 'timemarket', 'src', f);
+
+  it('approval slots take requests instead of direct reservations', () => {
+    const lib = read(src('lib.rs'));
+    expect(lib).to.match(/struct CreateSlotParams \{[\s\S]*?pub approval_window_sec: Option<u32>/);
+    expect(lib).to.match(/pub struct TimeSlot \{[\s\S]*?pub approval_window_sec: Option<u32>,[\s\S]*?pub booking_round: u32/);
+    expect(lib).to.match(/pub struct BookingRequest \{[\s\S]*?message_hash: Option<\[u8; 32\]>[\s\S]*?expires_at: i64/);
+    expect(read(src('escrow.rs'))).to.match(/require!\(slot\.approval_window_sec\.is_none\(\), ErrorCode::ApprovalRequired\)/);
+    expect(read(src('series.rs'))).to.match(/approval_window_sec: series\.approval_window_sec/);
+  });
+
+  it('exposes request, approve, decline and refund instructions', () => {
+    const lib = read(src('lib.rs'));
+    for (const f of ['request_booking', 'request_booking_sol', 'approve_booking', 'approve_booking_sol', 'decline_booking', 'refund_booking_request', 'refund_booking_request_sol']) {
+      expect(lib, f).to.match(new RegExp(`pub fn ${f}(<'info>)?\\(`));
+    }
+    expect(lib).to.match(/seeds = \[b"booking_request", slot\.key\(\)\.as_ref\(\), requester\.key\(\)\.as_ref\(\)\]/);
+    expect(lib).to.match(/pub struct ApproveBooking<'info> \{[\s\S]*?slot\.creator_authority == creator\.key\(\)/);
+  });
+
+  it('keeps request deposits out of amount_locked until approval', () => {
+    const booking = read(src('booking.rs'));
+    expect(booking).to.match(/escrow\.requests_locked = escrow\.requests_locked\.checked_add\(amount\)/);
+    const approve = (booking.match(/fn approve\([\s\S]*?\n\}/) || [''])[0];
+    expect(approve).to.match(/require!\(now <= request\.expires_at, ErrorCode::RequestExpired\)/);
+    expect(approve).to.match(/escrow\.amount_locked = escrow\.amount_locked\.checked_add\(request\.amount\)/);
+    expect(approve).to.match(/slot\.booking_round = slot\.booking_round\.checked_add\(1\)/);
+    expect(read(src('audit.rs'))).to.match(/saturating_add\(escrow\.requests_locked\)/);
+  });
+
+  it('refunds declined, expired and superseded requests', () => {
+    const booking = read(src('booking.rs'));
+    const refund = (booking.match(/fn refund_request[\s\S]*?\n\}/) || [''])[0];
+    expect(refund).to.match(/!request\.declined/);
+    expect(refund).to.match(/request\.round == slot\.booking_round/);
+    expect(refund).to.match(/now <= request\.expires_at/);
+    expect(refund).to.match(/ErrorCode::RequestStillPending/);
+  });
+
+  it('refunds and closes every other request when one is approved', () => {
+    const booking = read(src('booking.rs'));
+    const superseded = (booking.match(/fn refund_superseded[\s\S]*?\n\}/) || [''])[0];
+    expect(superseded).to.match(/request_info\.key\(\) != approved/);
+    expect(superseded).to.match(/request\.slot, slot_key/);
+    expect(superseded).to.match(/refund_request\(/);
+    expect(superseded).to.match(/request\.close\(requester\.clone\(\)\)/);
+    for (const f of ['approve_booking', 'approve_booking_sol']) {
+      const body = (booking.match(new RegExp(`pub fn ${f}<[\\s\\S]*?\\n\\}`)) || [''])[0];
+      expect(body, f).to.match(/refund_superseded\(/);
+      expect(body, f).to.match(/require!\(ctx\.accounts\.escrow\.requests_locked == 0, ErrorCode::RequestsOutstanding\)/);
+    }
+  });
+});
@@ -13,13 +13,13 @@ describe('Buy-now (P1) TDD', () => {
 
   it('exposes buy_now instruction and BuyNow accounts', () => {
     const lib = read(rustLibPath);
-    expect(lib).to.match(/pub fn buy_now\(ctx: Context<BuyNow>\)/);
+    expect(lib).to.match(/pub fn buy_now(<'info>)?\(\s*ctx: Context<(?:'_, '_, 'info, 'info, )?BuyNow(?:<'info>)?>(,\s*max_payment: Option<u64>)?(,\s*allowlist: Option<AllowlistProof>)?,?\s*\)/);
     expect(lib).to.match(/struct BuyNow/);
   });
 
   it('buy_now implementation binds buyer, pays T0, and locks slot', () => {
     const market = read(marketPath);
-    expect(market).to.match(/pub fn buy_now\(ctx: Context<BuyNow>\)/);
+    expect(market).to.match(/pub fn buy_now(<'info>)?\(\s*ctx: Context<(?:'_, '_, 'info, 'info, )?BuyNow(?:<'info>)?>(,\s*max_payment: Option<u64>)?(,\s*allowlist: Option<AllowlistProof>)?,?\s*\)/);
     expect(market).to.match(/escrow\.buyer = Some/);
     expect(market).to.match(/slot\.state = SlotState::Locked/);
     expect(market).to.match(/emit!\(AuctionEndedEvent/);
@@ -0,0 +1,50 @@
+/// <reference types="mocha" />
+/// <reference types="node" />
+import { readFileSync } from 'fs';
+import { join } from 'path';
+import { expect } from 'chai';
+
+function read(path: string) { return readFileSync(path, 'utf8'); }
+
+describe('Tiered cancellation policy (P2) TDD', () => {
+  const root = process.cwd();
+  const rustLibPath = join(root, 'programs', 'timemarket', 'src', 'lib.rs');
+  const marketPath = join(root, 'programs', 'timemarket', 'src', 'market.rs');
+  const escrowPath = join(root, 'programs', 'timemarket', 'src', 'escrow.rs');
+
+  it('TimeSlot and CreateSlotParams carry a bounded list of CancelTier', () => {
+    const lib = read(rustLibPath);
+    expect(lib).to.match(/pub struct CancelTier[\s\S]*secs_before_start: u32[\s\S]*refund_bps: u16/);
+    expect(lib).to.match(/struct CreateSlotParams[\s\S]*cancel_policy: Vec<CancelTier>/);
+    expect(lib).to.match(/struct TimeSlot[\s\S]*cancel_policy: Vec<CancelTier>/);
+    expect(lib).to.match(/CancelTier::LEN \* MAX_CANCEL_TIERS/);
+  });
+
+  it('cancel_policy is appended after bump and defaults to a full refund on migrated slots', () => {
+    const lib = read(rustLibPath);
+    const slot = (lib.match(/pub struct TimeSlot \{[\s\S]*?\n\}/) || [''])[0];
+    expect(slot).to.match(/pub bump: u8,\s*(\/\/[^\n]*\n\s*)+pub cancel_policy: Vec<CancelTier>,/);
+    expect(lib).to.match(/pub const LEN: usize = Self::LEN_V0\s*\+ \(4 \+ CancelTier::LEN \* MAX_CANCEL_TIERS\)/);
+    // the zeroed tail of a migrated slot reads as an empty policy
+    expect(lib).to.match(/if slot\.cancel_policy\.is_empty\(\) \{\s*return BPS_DENOM;/);
+  });
+
+  it('create_time_slot validates and stores the policy', () => {
+    const market = read(marketPath);
+    expect(market).to.match(/validate_cancel_policy\(&params\.cancel_policy\)\?/);
+    expect(market).to.match(/slot\.cancel_policy\s*=\s*params\.cancel_policy/);
+  });
+
+  it('cancel accounts include creator payout and fee destinations', () => {
+    const lib = read(rustLibPath);
+    expect(lib).to.match(/struct StableCancel<'info>[\s\S]*creator_payout_ata: InterfaceAccount<'info, TokenAccount>[\s\S]*fee_vault: InterfaceAccount<'info, TokenAccount>/);
+    expect(lib).to.match(/struct StableCancelSol<'info>[\s\S]*creator_payout: SystemAccount<'info>[\s\S]*fee_receiver: SystemAccount<'info>/);
+    expect(lib).to.match(/struct StableCancelSol<'info>[\s\S]*?address = sol_receivers\.fee_receiver @ ErrorCode::InvalidSolReceiver\)\]\s*pub fee_receiver/);
+  });
+
+  it('SPL and SOL cancel split escrow by the tier in effect', () => {
+    const escrow = read(escrowPath);
+    expect(escrow).to.match(/stable_cancel(<'info>)?\(\s*ctx: Context<(?:'_, '_, 'info, 'info, )?StableCancel(?:<'info>)?>,?\s*\)[\s\S]*cancel_refund_bps\(slot, now\)[\s\S]*CancelledEvent/);
+    expect(escrow).to.match(/stable_cancel_sol\(ctx: Context<StableCancelSol>\)[\s\S]*cancel_refund_bps\(slot, now\)[\s\S]*CancelledEvent/);
+  });
+});
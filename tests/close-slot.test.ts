@@ -13,7 +13,7 @@ describe('Close/Cancel slot by creator/admin (P2) TDD', () => {
 
   it('lib.rs exposes close_slot (SPL) and close_slot_sol (SOL) entrypoints', () => {
     const lib = read(rustLibPath);
-    expect(lib).to.match(/pub fn close_slot\(ctx: Context<CloseSlot>\) -> Result<\(\)>/);
+    expect(lib).to.match(/pub fn close_slot(<'info>)?\(\s*ctx: Context<(?:'_, '_, 'info, 'info, )?CloseSlot(?:<'info>)?>,?\s*\) -> Result<\(\)>/);
     expect(lib).to.match(/pub fn close_slot_sol\(ctx: Context<CloseSlotSol>\) -> Result<\(\)>/);
   });
 
@@ -29,7 +29,7 @@ describe('Close/Cancel slot by creator/admin (P2) TDD', () => {
 
   it('escrow.rs implements close_slot using transfer_checked and sets state to Closed', () => {
     const escrow = read(escrowPath);
-    expect(escrow).to.match(/pub fn close_slot\(ctx: Context<CloseSlot>\) -> Result<\(\)>/);
+    expect(escrow).to.match(/pub fn close_slot(<'info>)?\(\s*ctx: Context<(?:'_, '_, 'info, 'info, )?CloseSlot(?:<'info>)?>,?\s*\) -> Result<\(\)>/);
     expect(escrow).to.match(/transfer_checked\(/);
     expect(escrow).to.match(/slot\.state\s*=\s*SlotState::Closed/);
   });
@@ -0,0 +1,42 @@
+/// <reference types="mocha" />
+/// <reference types="node" />
+import { readFileSync } from 'fs';
+import { join } from 'path';
+import { expect } from 'chai';
+
+function read(path: string) { return readFileSync(path, 'utf8'); }
+
+describe('Draft-to-publish lifecycle (P1) TDD', () => {
+  const root = process.cwd();
+  const src = (f: string) => join(root, 'programs', 'timemarket', 'src', f);
+
+  it('creates slots as Draft on request', () => {
+    const lib = read(src('lib.rs'));
+    expect(lib).to.match(/struct CreateSlotParams \{[\s\S]*?pub draft: bool/);
+    const market = read(src('market.rs'));
+    expect(market).to.match(/slot\.state = if params\.draft \{ SlotState::Draft \} else \{ SlotState::Open \};/);
+  });
+
+  it('exposes update_draft_slot and publish_slot', () => {
+    const lib = read(src('lib.rs'));
+    expect(lib).to.match(/pub fn update_draft_slot\(ctx: Context<UpdateDraftSlot>, params: UpdateDraftParams\)/);
+    expect(lib).to.match(/pub fn publish_slot\(ctx: Context<PublishSlot>, publish_at: Option<i64>\)/);
+    expect(lib).to.match(/pub struct UpdateDraftParams \{[\s\S]*?price: Option<u64>[\s\S]*?mode: Option<Mode>|pub struct UpdateDraftParams \{[\s\S]*?mode: Option<Mode>[\s\S]*?price: Option<u64>/);
+    expect(lib).to.match(/pub struct UpdateDraftParams \{[\s\S]*?gate: Option<Option<TokenGate>>/);
+    const draft = read(src('draft.rs'));
+    expect(draft).to.match(/require!\(slot\.state == SlotState::Draft, ErrorCode::InvalidState\)/);
+    expect(draft).to.match(/validate_slot_params\(&slot_params\(slot\)\)\?;/);
+    expect(draft).to.match(/slot\.publish_at = publish_at;/);
+  });
+
+  it('rejects sales before publish_at', () => {
+    const lib = read(src('lib.rs'));
+    expect(lib).to.match(/pub fn check_on_sale\(slot: &TimeSlot\)[\s\S]*?ErrorCode::NotYetOnSale/);
+    expect(read(src('escrow.rs'))).to.match(/fn reserve<'info>[\s\S]*?check_on_sale\(slot\)\?;/);
+    const market = read(src('market.rs'));
+    expect(market).to.match(/pub fn check_bid[\s\S]*?check_on_sale\(slot\)\?;/);
+    expect(market).to.match(/pub fn bid_commit[\s\S]*?check_on_sale\(slot\)\?;/);
+    expect(market).to.match(/pub fn buy_now<'info>[\s\S]*?check_on_sale\(slot\)\?;/);
+    expect(read(src('auction_sol.rs'))).to.match(/pub fn buy_now_sol[\s\S]*?check_on_sale\(slot\)\?;/);
+  });
+});
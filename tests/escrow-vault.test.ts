@@ -0,0 +1,30 @@
+/// <reference types="mocha" />
+/// <reference types="node" />
+import { readFileSync } from 'fs';
+import { join } from 'path';
+import { expect } from 'chai';
+
+function read(path: string) { return readFileSync(path, 'utf8'); }
+
+describe('Program-verified escrow vault (P0) TDD', () => {
+  const root = process.cwd();
+  const rustLibPath = join(root, 'programs', 'timemarket', 'src', 'lib.rs');
+  const escrowPath = join(root, 'programs', 'timemarket', 'src', 'escrow.rs');
+  const pdasPath = join(root, 'packages', 'ts-sdk', 'src', 'helpers', 'pdas.ts');
+
+  it('init_escrow creates the vault as the escrow PDA ATA for the slot mint', () => {
+    const lib = read(rustLibPath);
+    expect(lib).to.match(/struct InitEscrow<'info> \{[\s\S]*?associated_token::mint = mint,\s*associated_token::authority = escrow,[\s\S]*?pub escrow_vault: Option<InterfaceAccount<'info, TokenAccount>>/);
+    expect(read(escrowPath)).to.match(/\(PaymentKind::Spl, Some\(vault\)\) => vault\.key\(\)/);
+    expect(read(pdasPath)).to.match(/export function escrowVaultAddress/);
+  });
+
+  it('every instruction touching escrow funds pins escrow.token_acc', () => {
+    const lib = read(rustLibPath);
+    const loose = lib.match(/#\[account\(mut\)\]\s*pub escrow_vault/g) || [];
+    expect(loose.length).to.equal(0);
+    for (const ctx of ['StableReserve', 'StableSettle', 'BidPlace', 'BuyNow', 'AuctionSettle', 'ResolveDispute', 'CloseSlot', 'RejectReschedule']) {
+      expect(lib).to.match(new RegExp(`struct ${ctx}<'info> \\{[\\s\\S]*?#\\[account\\(mut, address = escrow\\.token_acc @ ErrorCode::InvalidEscrowVault\\)\\]\\s*pub escrow_vault`));
+    }
+  });
+});
@@ -28,13 +28,14 @@ describe('Separate fee vault vs dispute vault (P1) TDD', () => {
     expect(lib).to.match(/struct SealedAuctionSettle[\s\S]*fee_vault/);
     expect(lib).to.match(/struct AuctionSettle[\s\S]*fee_vault/);
     // Handler logic transfers to fee_vault instead of dispute vault
-    expect(market).to.match(/to: fee_vault/);
+    expect(market).to.match(/pay_auction_t0\(\s*&payment,\s*escrow,\s*&ctx\.accounts\.creator_payout_ata\.to_account_info\(\),\s*&ctx\.accounts\.fee_vault\.to_account_info\(\),/);
   });
 
   it('Retained T1 amounts go to dispute_vault, not fee_vault', () => {
     const market = read(marketPath);
     const escrow = read(escrowPath);
-    expect(market).to.match(/t1_withhold[\s\S]*to:\s*ctx\.accounts\.dispute_vault/);
+    expect(market).to.match(/pay_auction_t1\([\s\S]*?&ctx\.accounts\.fee_vault\.to_account_info\(\),\s*&ctx\.accounts\.dispute_vault\.to_account_info\(\),/);
+    expect(escrow).to.match(/pub fn pay_auction_t1[\s\S]*?Payout \{ to: dispute_dest, amount: t1_withhold \}/);
     expect(escrow).to.match(/t1_withhold[\s\S]*to:\s*dispute_vault/);
   });
 });
@@ -0,0 +1,225 @@
+/// <reference types="mocha" />
+/// <reference types="node" />
+import { readFileSync } from 'fs';
+import { join } from 'path';
+import { expect } from 'chai';
+import * as anchor from '@coral-xyz/anchor';
+import * as spl from '@solana/spl-token';
+
+function read(path: string) { return readFileSync(path, 'utf8'); }
+
+describe('Separate payer and beneficiary (P1) TDD', () => {
+  const root = process.cwd();
+  const src = (f: string) => join(root, 'programs', 'timemarket', 'src', f);
+  const ctx = (lib: string, name: string) => (lib.match(new RegExp(`pub struct ${name}<'info> \\{[\\s\\S]*?\\n\\}`)) || [''])[0];
+
+  it('escrow records the payer next to the beneficiary', () => {
+    const lib = read(src('lib.rs'));
+    expect(lib).to.match(/pub struct Escrow \{[\s\S]*?pub buyer: Option<Pubkey>,[\s\S]*?pub payer: Option<Pubkey>,/);
+    expect(lib).to.match(/pub const LEN: usize = Self::LEN_V0 \+ \(1 \+ 32\) \+ 8;/);
+    expect(lib).to.match(/pub fn refund_to\(&self\) -> Option<Pubkey> \{\s*self\.payer\.or\(self\.buyer\)/);
+    expect(lib).to.match(/pub struct BidBook \{[\s\S]*?pub highest_beneficiary: Pubkey/);
+  });
+
+  it('reservation instructions accept an optional beneficiary', () => {
+    const lib = read(src('lib.rs'));
+    for (const name of ['StableReserve', 'StableReserveSol', 'BidPlace', 'BidPlaceSol', 'BuyNowSol']) {
+      expect(ctx(lib, name)).to.match(/pub beneficiary: Option<UncheckedAccount<'info>>/);
+    }
+    // buy_now derives the NFT account from the beneficiary, so it is always passed
+    expect(ctx(lib, 'BuyNow')).to.match(/pub beneficiary: UncheckedAccount<'info>/);
+    expect(ctx(lib, 'BuyNow')).to.match(/associated_token::authority = beneficiary/);
+    const market = read(src('market.rs'));
+    const auctionSol = read(src('auction_sol.rs'));
+    const fn = (file: string, re: RegExp) => (file.match(re) || [''])[0];
+    for (const body of [fn(market, /pub fn buy_now<'info>[\s\S]*?\n\}/), fn(auctionSol, /pub fn buy_now_sol[\s\S]*?\n\}/)]) {
+      expect(body).to.match(/escrow\.buyer = Some\(beneficiary\);\s*escrow\.payer = Some\(ctx\.accounts\.bidder\.key\(\)\);/);
+      expect(body).to.match(/book\.highest_beneficiary = beneficiary;/);
+      expect(body).to.match(/check_allowlist\(slot, beneficiary,/);
+    }
+    const escrow = read(src('escrow.rs'));
+    expect(escrow).to.match(/escrow\.buyer = Some\(beneficiary\);\s*escrow\.payer = Some\(buyer\.key\(\)\);/);
+    expect(market).to.match(/escrow\.buyer = Some\(book\.highest_beneficiary\);\s*escrow\.payer = Some\(book\.highest_bidder\);/);
+  });
+
+  it('a sponsor signs as payer for the rent of accounts created on the buyer\'s behalf', () => {
+    const lib = read(src('lib.rs'));
+    for (const name of ['StableReserve', 'StableReserveSol', 'BuyNow', 'BuyNowSol']) {
+      const body = ctx(lib, name);
+      expect(body).to.match(/#\[account\(mut\)\]\s*pub payer: Signer<'info>/);
+      expect(body).to.match(/payer = payer,/);
+      expect(body).to.not.match(/payer = (buyer|bidder),/);
+    }
+  });
+
+  it('refunds go to the payer', () => {
+    const lib = read(src('lib.rs'));
+    expect(ctx(lib, 'StableCancel')).to.match(/Some\(buyer_token\.owner\) == escrow\.refund_to\(\)/);
+    expect(ctx(lib, 'StableCancelSol')).to.match(/refund_receiver: SystemAccount/);
+    expect(ctx(lib, 'ResolveDispute')).to.match(/Some\(buyer_token\.owner\) == escrow\.refund_to\(\)/);
+    const escrow = read(src('escrow.rs'));
+    expect(escrow).to.match(/signer == buyer \|\| signer == buyer_key/);
+    expect(escrow).to.match(/ctx\.accounts\.escrow\.refund_to\(\)/);
+    expect(read(src('reschedule.rs'))).to.match(/escrow\.refund_to\(\)/);
+  });
+
+  it('check-in NFT is delivered to the beneficiary', () => {
+    const checkin = ctx(read(src('lib.rs')), 'StableCheckin');
+    expect(checkin).to.match(/address = escrow\.buyer\.unwrap_or_default\(\)/);
+    expect(checkin).to.match(/associated_token::authority = beneficiary/);
+  });
+});
+
+(process.env.ANCHOR_PROVIDER_URL ? describe : describe.skip)('Sponsored bookings (integration)', () => {
+  const PRICE = 1_000_000;
+  const BUY_NOW = 5_000_000;
+  const programId = spl.TOKEN_PROGRAM_ID;
+
+  let provider: anchor.AnchorProvider;
+  let program: any;
+  let conn: anchor.web3.Connection;
+  let payer: anchor.web3.Keypair;
+  let admin: anchor.web3.PublicKey;
+  let mint: anchor.web3.PublicKey;
+  let platform: anchor.web3.PublicKey;
+  let acceptedMint: anchor.web3.PublicKey;
+  let profile: anchor.web3.PublicKey;
+  let feeVault: anchor.web3.PublicKey;
+  let payoutWallet: anchor.web3.PublicKey;
+  let sponsorToken: anchor.web3.PublicKey;
+  // Funds every booking and pays the rent; the beneficiary holds no SOL and never signs
+  const sponsor = anchor.web3.Keypair.generate();
+  const beneficiary = anchor.web3.Keypair.generate().publicKey;
+  const pda = (seeds: Buffer[]) => anchor.web3.PublicKey.findProgramAddressSync(seeds, program.programId)[0];
+  const ata = async (owner: anchor.web3.PublicKey) =>
+    (await spl.getOrCreateAssociatedTokenAccount(conn, payer, mint, owner, true, undefined, undefined, programId)).address;
+
+  before(async () => {
+    provider = anchor.AnchorProvider.env();
+    anchor.setProvider(provider);
+    program = anchor.workspace.Timemarket;
+    conn = provider.connection;
+    payer = (provider.wallet as anchor.Wallet).payer;
+    admin = provider.wallet.publicKey;
+
+    mint = await spl.createMint(conn, payer, admin, null, 6, undefined, undefined, programId);
+    platform = pda([Buffer.from('platform'), admin.toBuffer()]);
+    const feeAuthority = pda([Buffer.from('fee'), platform.toBuffer()]);
+    acceptedMint = pda([Buffer.from('accepted_mint'), platform.toBuffer(), mint.toBuffer()]);
+    profile = pda([Buffer.from('creator'), admin.toBuffer(), platform.toBuffer()]);
+    payoutWallet = anchor.web3.Keypair.generate().publicKey;
+    feeVault = await ata(feeAuthority);
+    const disputeVault = await ata(platform);
+    sponsorToken = await ata(sponsor.publicKey);
+    await spl.mintTo(conn, payer, mint, sponsorToken, payer, 10 * BUY_NOW, [], undefined, programId);
+    await provider.sendAndConfirm(new anchor.web3.Transaction().add(
+      anchor.web3.SystemProgram.transfer({ fromPubkey: admin, toPubkey: sponsor.publicKey, lamports: anchor.web3.LAMPORTS_PER_SOL }),
+    ));
+
+    await program.methods.initPlatform(250)
+      .accountsPartial({ admin, platform, feeAuthority, mint, feeVault, disputeVault, acceptedMint })
+      .rpc();
+    await program.methods.initCreatorProfile(payoutWallet, null)
+      .accountsPartial({ authority: admin, platform, profile })
+      .rpc();
+  });
+
+  // Next slot on the profile with its escrow (and, for auctions, bid book and queues) initialised
+  const createSlot = async (auction: boolean) => {
+    const now = Math.floor(Date.now() / 1000);
+    const { slotCount } = await program.account.creatorProfile.fetch(profile);
+    const slot = pda([Buffer.from('slot'), profile.toBuffer(), slotCount.toArrayLike(Buffer, 'le', 8)]);
+    const escrow = pda([Buffer.from('escrow'), slot.toBuffer()]);
+    const escrowVault = await ata(escrow);
+    await program.methods.createTimeSlot({
+      startTs: new anchor.BN(now + 86_400),
+      endTs: new anchor.BN(now + 90_000),
+      tzOffsetMin: 0,
+      subjectHash: Array(32).fill(0),
+      venueHash: Array(32).fill(0),
+      mode: auction ? { englishAuction: {} } : { stable: {} },
+      capacity: 1,
+      nftMint: null,
+      price: new anchor.BN(PRICE),
+      minIncrementBps: 500,
+      buyNow: auction ? new anchor.BN(BUY_NOW) : null,
+      auctionStartTs: auction ? new anchor.BN(now - 60) : null,
+      auctionEndTs: auction ? new anchor.BN(now + 3_600) : null,
+      antiSnipingSec: null,
+      cancelPolicy: [],
+      paymentKind: { spl: {} },
+      mint,
+      usdQuote: null,
+      transfersDisabled: false,
+      resaleRoyaltyBps: 0,
+      maxResalePrice: null,
+      nftTicket: false,
+      gate: null,
+      allowlistRoot: null,
+      approvalWindowSec: null,
+      draft: false,
+    }).accountsPartial({ authority: admin, platform, acceptedMint, profile, slot }).rpc();
+    await program.methods.initEscrow()
+      .accountsPartial({ admin, platform, mint, slot, escrow, escrowVault, tokenProgram: programId })
+      .rpc();
+    if (auction) {
+      await program.methods.initBidBook().accountsPartial({ authority: admin, platform, mint, slot }).rpc();
+      await program.methods.initRefundQueue(8).accountsPartial({ authority: admin, slot }).rpc();
+      await program.methods.initAutoBidStore(8).accountsPartial({ authority: admin, platform, mint, slot }).rpc();
+    }
+    return { slot, escrow, escrowVault };
+  };
+
+  const expectSponsored = async (escrow: anchor.web3.PublicKey) => {
+    const acc = await program.account.escrow.fetch(escrow);
+    expect(acc.buyer.toBase58()).to.equal(beneficiary.toBase58());
+    expect(acc.payer.toBase58()).to.equal(sponsor.publicKey.toBase58());
+  };
+
+  it('stable_reserve: the sponsor pays, the beneficiary attends', async () => {
+    const { slot, escrow, escrowVault } = await createSlot(false);
+    await program.methods.stableReserve(new anchor.BN(PRICE), null)
+      .accountsPartial({
+        buyer: sponsor.publicKey, payer: sponsor.publicKey, platform, mint, slot, escrow, escrowVault,
+        buyerToken: sponsorToken, tokenProgram: programId, priceFeed: null, beneficiary,
+      })
+      .signers([sponsor])
+      .rpc();
+    await expectSponsored(escrow);
+  });
+
+  it('bid_place: the winning bid is held for the beneficiary', async () => {
+    const { slot, escrowVault } = await createSlot(true);
+    await program.methods.auctionStart().accountsPartial({ creator: admin, platform, mint, slot }).rpc();
+    await program.methods.bidPlace(new anchor.BN(PRICE), null, null)
+      .accountsPartial({
+        bidder: sponsor.publicKey, platform, mint, slot, escrowVault, bidderToken: sponsorToken,
+        tokenProgram: programId, beneficiary,
+      })
+      .signers([sponsor])
+      .rpc();
+    const book = await program.account.bidBook.fetch(pda([Buffer.from('bidbook'), slot.toBuffer()]));
+    expect(book.highestBidder.toBase58()).to.equal(sponsor.publicKey.toBase58());
+    expect(book.highestBeneficiary.toBase58()).to.equal(beneficiary.toBase58());
+  });
+
+  it('buy_now: the sponsor pays the price and the rent, the beneficiary attends', async () => {
+    const { slot, escrow, escrowVault } = await createSlot(true);
+    const sponsorLamports = await conn.getBalance(sponsor.publicKey);
+    await program.methods.buyNow(null, null)
+      .accountsPartial({
+        bidder: sponsor.publicKey, payer: sponsor.publicKey, platform, mint, slot, escrow, escrowVault,
+        bidderToken: sponsorToken, profile, profilePayoutWallet: payoutWallet,
+        creatorPayoutAta: spl.getAssociatedTokenAddressSync(mint, payoutWallet, true, programId),
+        acceptedMint, feeVault, tokenProgram: programId, beneficiary,
+        nftMint: mint, buyerNftAta: spl.getAssociatedTokenAddressSync(mint, beneficiary, true, programId),
+        priceFeed: null,
+      })
+      .signers([sponsor])
+      .rpc();
+    await expectSponsored(escrow);
+    // rent for the accounts created on the beneficiary's behalf came out of the sponsor's wallet
+    expect(await conn.getBalance(sponsor.publicKey)).to.be.lessThan(sponsorLamports);
+    expect(await conn.getBalance(beneficiary)).to.equal(0);
+  });
+});
@@ -0,0 +1,55 @@
+/// <reference types="mocha" />
+/// <reference types="node" />
+import { readFileSync } from 'fs';
+import { join } from 'path';
+import { expect } from 'chai';
+
+function read(path: string) { return readFileSync(path, 'utf8'); }
+
+describe('Per-slot payment kind (P1) TDD', () => {
+  const root = process.cwd();
+  const rustLibPath = join(root, 'programs', 'timemarket', 'src', 'lib.rs');
+  const marketPath = join(root, 'programs', 'timemarket', 'src', 'market.rs');
+  const escrowPath = join(root, 'programs', 'timemarket', 'src', 'escrow.rs');
+  const auctionSolPath = join(root, 'programs', 'timemarket', 'src', 'auction_sol.rs');
+
+  it('PaymentKind is chosen at creation and stored on TimeSlot', () => {
+    const lib = read(rustLibPath);
+    const market = read(marketPath);
+    expect(lib).to.match(/pub enum PaymentKind \{\s*Spl,\s*Sol,\s*\}/);
+    expect(lib).to.match(/struct CreateSlotParams[\s\S]*payment_kind: PaymentKind/);
+    expect(lib).to.match(/struct TimeSlot[\s\S]*payment_kind: PaymentKind/);
+    expect(lib).to.match(/WrongPaymentKind/);
+    expect(market).to.match(/slot\.payment_kind\s*=\s*params\.payment_kind/);
+  });
+
+  it('escrow.rs routes SPL and SOL flows through one EscrowPayment abstraction', () => {
+    const escrow = read(escrowPath);
+    expect(escrow).to.match(/pub struct EscrowPayment<'info>/);
+    expect(escrow).to.match(/pub fn spl\([\s\S]*PaymentKind::Spl, ErrorCode::WrongPaymentKind/);
+    expect(escrow).to.match(/pub fn sol\([\s\S]*PaymentKind::Sol, ErrorCode::WrongPaymentKind/);
+    for (const ix of ['stable_reserve', 'stable_cancel', 'stable_settle', 'close_slot']) {
+      expect(escrow).to.match(new RegExp(`pub fn ${ix}(<'info>)?\\(ctx[^)]*\\) -> Result<\\(\\)> \\{\\s*(let decimals[^\\n]*\\n\\s*)?let payment = EscrowPayment::spl\\(`));
+      expect(escrow).to.match(new RegExp(`pub fn ${ix}_sol\\(ctx[^)]*\\) -> Result<\\(\\)> \\{\\s*let payment = EscrowPayment::sol\\(`));
+    }
+  });
+
+  it('every move into or out of escrow goes through EscrowPayment', () => {
+    for (const f of ['market.rs', 'auction_sol.rs', 'reschedule.rs', 'audit.rs', 'booking.rs']) {
+      const body = read(join(root, 'programs', 'timemarket', 'src', f));
+      expect(body, f).to.not.match(/hooked_transfer_checked|pay_from_escrow_lamports|collect_spl|try_borrow_mut_lamports/);
+    }
+  });
+
+  it('auction paths reject the other currency', () => {
+    const market = read(marketPath);
+    const sol = read(auctionSolPath);
+    for (const ix of ['buy_now', 'bid_place', 'bid_outbid_refund', 'auction_end', 'auction_settle', 'sealed_auction_end', 'sealed_auction_settle']) {
+      // Either an explicit check or the EscrowPayment constructor, which performs it
+      const spl = (market.match(new RegExp(`pub fn ${ix}(<'info>)?\\(ctx[\\s\\S]*?\\n\\}`)) || [''])[0];
+      const lamports = (sol.match(new RegExp(`pub fn ${ix}_sol\\(ctx[\\s\\S]*?\\n\\}`)) || [''])[0];
+      expect(spl, ix).to.match(/PaymentKind::Spl, ErrorCode::WrongPaymentKind|EscrowPayment::spl\(/);
+      expect(lamports, ix).to.match(/PaymentKind::Sol, ErrorCode::WrongPaymentKind|EscrowPayment::sol\(/);
+    }
+  });
+});
@@ -30,7 +30,7 @@ describe('Refund queue for auctions (P1) TDD', () => {
     const market = read(marketPath);
     expect(market).to.match(/fn init_refund_queue/);
     expect(market).to.match(/q\.entries\.push\(RefundEntry/);
-    expect(market).to.match(/bid_outbid_refund\([\s\S]*q\.cursor/);
+    expect(market).to.match(/bid_outbid_refund(<'info>)?\([\s\S]*q\.cursor/);
   });
 
   it('removes direct pending_refund fields usage in market.rs init_bid_book and guards', () => {
@@ -0,0 +1,74 @@
+/// <reference types="mocha" />
+/// <reference types="node" />
+import { readFileSync } from 'fs';
+import { join } from 'path';
+import { expect } from 'chai';
+
+function read(path: string) { return readFileSync(path, 'utf8'); }
+
+describe('Secondary market for reservations (P1) TDD', () => {
+  const root = process.cwd();
+  const src = (f: string) => join(root, 'programs', 'timemarket', 'src', f);
+
+  it('slots carry a resale royalty and max resale price', () => {
+    const lib = read(src('lib.rs'));
+    expect(lib).to.match(/struct CreateSlotParams \{[\s\S]*?pub resale_royalty_bps: u16,\s*pub max_resale_price: Option<u64>/);
+    expect(lib).to.match(/pub struct TimeSlot \{[\s\S]*?pub resale_royalty_bps: u16,\s*pub max_resale_price: Option<u64>/);
+    const market = read(src('market.rs'));
+    expect(market).to.match(/require!\(params\.resale_royalty_bps <= 10_000, ErrorCode::InvalidBps\)/);
+    expect(market).to.match(/slot\.max_resale_price = params\.max_resale_price;/);
+  });
+
+  it('listing is a per-slot PDA created by the holder', () => {
+    const lib = read(src('lib.rs'));
+    expect(lib).to.match(/pub struct Listing \{[\s\S]*?seller: Pubkey[\s\S]*?price: u64/);
+    expect(lib).to.match(/seeds = \[b"listing", slot\.key\(\)\.as_ref\(\)\]/);
+    expect(lib).to.match(/pub fn list_reservation\(ctx: Context<ListReservation>, price: u64\)/);
+    expect(lib).to.match(/pub fn cancel_listing\(ctx: Context<CancelListing>\)/);
+    const resale = read(src('resale.rs'));
+    expect(resale).to.match(/ErrorCode::ResalePriceTooHigh/);
+    expect(resale).to.match(/slot\.state == SlotState::Reserved \|\| slot\.state == SlotState::Locked/);
+    expect(resale).to.match(/escrow\.refund_to\(\) == Some\(holder\)/);
+  });
+
+  it('anyone can close a stale listing so the new holder can list', () => {
+    const lib = read(src('lib.rs'));
+    const ctx = (lib.match(/pub struct CancelListing<'info> \{[\s\S]*?\n\}/) || [''])[0];
+    expect(ctx).to.match(/pub caller: Signer<'info>/);
+    expect(ctx).to.match(/close = seller,\s*has_one = seller/);
+    expect(ctx).to.match(/pub seller: SystemAccount<'info>/);
+    const resale = read(src('resale.rs'));
+    expect(resale).to.match(/fn listing_is_stale[\s\S]*?escrow\.buyer != Some\(seller\)[\s\S]*?escrow\.refund_to\(\) != Some\(seller\)/);
+    expect(resale).to.match(/ctx\.accounts\.caller\.key\(\) == seller\s*\|\| listing_is_stale\(/);
+  });
+
+  it('buy_listing splits royalty and fee and rebinds the escrow atomically', () => {
+    const lib = read(src('lib.rs'));
+    expect(lib).to.match(/pub fn buy_listing<'info>\(\s*ctx: Context<'_, '_, 'info, 'info, BuyListing<'info>>,\s*max_price: u64/);
+    const ctx = (lib.match(/pub struct BuyListing<'info> \{[\s\S]*?\n\}/) || [''])[0];
+    expect(ctx).to.match(/close = seller/);
+    expect(ctx).to.match(/seller_token\.owner == listing\.seller/);
+    expect(ctx).to.match(/address = accepted_mint\.fee_vault/);
+    const resale = read(src('resale.rs'));
+    expect(resale).to.match(/mul_bps_u64\(price, slot\.resale_royalty_bps as u64\)/);
+    expect(resale).to.match(/effective_fee_bps\(/);
+    expect(resale).to.match(/escrow\.buyer = Some\(buyer\);\s*escrow\.payer = Some\(buyer\);/);
+    expect(resale).to.match(/ReservationResoldEvent/);
+  });
+
+  it('rejects SOL-paid bookings and sellers buying their own listing', () => {
+    const lib = read(src('lib.rs'));
+    expect(lib).to.match(/UnsupportedPaymentKind,/);
+    expect(lib).to.match(/OwnListing,/);
+    const resale = read(src('resale.rs'));
+    expect(resale).to.match(/fn check_resellable[\s\S]*?require!\(slot\.payment_kind == PaymentKind::Spl, ErrorCode::UnsupportedPaymentKind\)/);
+    const buy = (resale.match(/pub fn buy_listing<'info>[\s\S]*?\n\}/) || [''])[0];
+    expect(buy).to.match(/check_resellable\(slot\)\?;/);
+    expect(buy).to.match(/require_keys_neq!\(ctx\.accounts\.buyer\.key\(\), listing\.seller, ErrorCode::OwnListing\)/);
+  });
+
+  it('sdk derives the listing address', () => {
+    const pdas = read(join(root, 'packages', 'ts-sdk', 'src', 'helpers', 'pdas.ts'));
+    expect(pdas).to.match(/export function listingPda\(slot: PublicKey/);
+  });
+});
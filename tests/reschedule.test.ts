@@ -0,0 +1,56 @@
+/// <reference types="mocha" />
+/// <reference types="node" />
+import { readFileSync } from 'fs';
+import { join } from 'path';
+import { expect } from 'chai';
+
+function read(path: string) { return readFileSync(path, 'utf8'); }
+
+describe('Creator-initiated reschedule (P2) TDD', () => {
+  const root = process.cwd();
+  const rustLibPath = join(root, 'programs', 'timemarket', 'src', 'lib.rs');
+  const reschedulePath = join(root, 'programs', 'timemarket', 'src', 'reschedule.rs');
+  const escrowPath = join(root, 'programs', 'timemarket', 'src', 'escrow.rs');
+
+  it('lib.rs exposes propose/accept/reject entrypoints', () => {
+    const lib = read(rustLibPath);
+    expect(lib).to.match(/pub fn propose_reschedule\(\s*ctx: Context<ProposeReschedule>/);
+    expect(lib).to.match(/pub fn accept_reschedule\(ctx: Context<AcceptReschedule>\) -> Result<\(\)>/);
+    expect(lib).to.match(/pub fn reject_reschedule(<'info>)?\(\s*ctx: Context<(?:'_, '_, 'info, 'info, )?RejectReschedule(?:<'info>)?>,?\s*\) -> Result<\(\)>/);
+    expect(lib).to.match(/pub fn reject_reschedule_sol\(ctx: Context<RejectRescheduleSol>\) -> Result<\(\)>/);
+  });
+
+  it('TimeSlot stores a pending RescheduleProposal with a default outcome', () => {
+    const lib = read(rustLibPath);
+    expect(lib).to.match(/struct RescheduleProposal[\s\S]*respond_by: i64[\s\S]*default_accept: bool/);
+    expect(lib).to.match(/struct TimeSlot[\s\S]*reschedule: Option<RescheduleProposal>/);
+  });
+
+  it('accept moves times; reject refunds fully; default applies after respond_by', () => {
+    const r = read(reschedulePath);
+    expect(r).to.match(/now >= proposal\.respond_by && proposal\.default_accept == accepting/);
+    expect(r).to.match(/slot\.start_ts\s*=\s*proposal\.new_start_ts/);
+    expect(r).to.match(/reject_reschedule(<'info>)?\(\s*ctx: Context<(?:'_, '_, 'info, 'info, )?RejectReschedule(?:<'info>)?>,?\s*\)[\s\S]*EscrowPayment::spl\([\s\S]*payment\.pay\([\s\S]*slot\.state\s*=\s*SlotState::Refunded/);
+    expect(r).to.match(/reject_reschedule_sol\(ctx: Context<RejectRescheduleSol>\)[\s\S]*EscrowPayment::sol\([\s\S]*payment\.pay\(Payout \{ to: &ctx\.accounts\.refund_receiver/);
+    expect(r).to.not.match(/try_borrow_mut_lamports/);
+  });
+
+  it('answers are blocked on frozen slots and refunds go to the payer', () => {
+    const r = read(reschedulePath);
+    for (const fn of ['accept_reschedule', 'reject_reschedule<', 'reject_reschedule_sol']) {
+      const body = (r.match(new RegExp(`pub fn ${fn}[\\s\\S]*?\\n\\}`)) || [''])[0];
+      expect(body).to.match(/require!\(!slot\.frozen, ErrorCode::Frozen\)/);
+    }
+    const lib = read(rustLibPath);
+    const ctx = (name: string) => (lib.match(new RegExp(`pub struct ${name}<'info> \\{[\\s\\S]*?\\n\\}`)) || [''])[0];
+    expect(ctx('RejectReschedule')).to.match(/Some\(buyer_token\.owner\) == escrow\.refund_to\(\)/);
+    expect(ctx('RejectRescheduleSol')).to.match(/pub refund_receiver: SystemAccount/);
+    expect(ctx('RejectRescheduleSol')).to.match(/address = escrow\.refund_to\(\)\.unwrap_or_default\(\)/);
+  });
+
+  it('stable flows are blocked while a proposal is pending', () => {
+    const escrow = read(escrowPath);
+    expect(escrow).to.match(/stable_settle(<'info>)?\(\s*ctx: Context<(?:'_, '_, 'info, 'info, )?StableSettle(?:<'info>)?>,?\s*\)[\s\S]*require!\(slot\.reschedule\.is_none\(\), ErrorCode::ReschedulePending\)/);
+    expect(escrow).to.match(/stable_checkin\(ctx: Context<StableCheckin>\)[\s\S]*ErrorCode::ReschedulePending/);
+  });
+});
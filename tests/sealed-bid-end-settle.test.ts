@@ -13,16 +13,16 @@ describe('Sealed-bid End/Settle TDD', () => {
 
   it('lib.rs exposes sealed_auction_end and sealed_auction_settle entrypoints', () => {
     const lib = read(rustLibPath);
-    expect(lib).to.match(/pub fn sealed_auction_end\(/);
-    expect(lib).to.match(/pub fn sealed_auction_settle\(/);
+    expect(lib).to.match(/pub fn sealed_auction_end(<'info>)?\(/);
+    expect(lib).to.match(/pub fn sealed_auction_settle(<'info>)?\(/);
     expect(lib).to.match(/struct SealedAuctionEnd/);
     expect(lib).to.match(/struct SealedAuctionSettle/);
   });
 
   it('market.rs implements sealed_auction_end and sealed_auction_settle and allows checkin for SealedBid', () => {
     const market = read(marketPath);
-    expect(market).to.match(/pub fn sealed_auction_end\(ctx: Context<SealedAuctionEnd>\)/);
-    expect(market).to.match(/pub fn sealed_auction_settle\(ctx: Context<SealedAuctionSettle>\)/);
+    expect(market).to.match(/pub fn sealed_auction_end(<'info>)?\(\s*ctx: Context<(?:'_, '_, 'info, 'info, )?SealedAuctionEnd(?:<'info>)?>,?\s*\)/);
+    expect(market).to.match(/pub fn sealed_auction_settle(<'info>)?\(\s*ctx: Context<(?:'_, '_, 'info, 'info, )?SealedAuctionSettle(?:<'info>)?>,?\s*\)/);
     // auction_checkin should accept SealedBid as well
     expect(market).to.match(/matches!\(slot\.mode, Mode::EnglishAuction \| Mode::SealedBid\)/);
   });
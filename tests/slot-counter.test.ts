@@ -0,0 +1,48 @@
+/// <reference types="mocha" />
+/// <reference types="node" />
+import { readFileSync } from 'fs';
+import { join } from 'path';
+import { expect } from 'chai';
+
+function read(path: string) { return readFileSync(path, 'utf8'); }
+
+describe('Per-creator slot counter PDA (P1) TDD', () => {
+  const root = process.cwd();
+  const rustLibPath = join(root, 'programs', 'timemarket', 'src', 'lib.rs');
+  const marketPath = join(root, 'programs', 'timemarket', 'src', 'market.rs');
+  const pdasPath = join(root, 'packages', 'ts-sdk', 'src', 'helpers', 'pdas.ts');
+
+  it('CreatorProfile carries slot_count and TimeSlot records its index', () => {
+    const lib = read(rustLibPath);
+    expect(lib).to.match(/struct CreatorProfile[\s\S]*slot_count: u64/);
+    expect(lib).to.match(/struct TimeSlot[\s\S]*index: u64/);
+  });
+
+  it('CreateTimeSlot seeds the slot by profile.slot_count instead of start_ts', () => {
+    const lib = read(rustLibPath);
+    expect(lib).to.match(/seeds = \[b"slot", profile\.key\(\)\.as_ref\(\), &profile\.slot_count\.to_le_bytes\(\)\]/);
+    expect(lib).to.not.match(/&params\.start_ts\.to_le_bytes\(\)/);
+    expect(lib).to.match(/pub fn legacy_slot_address\(profile: &Pubkey, start_ts: i64\)/);
+  });
+
+  it('appends slot_count and index after bump so pre-upgrade accounts stay a prefix', () => {
+    const lib = read(rustLibPath);
+    const profile = (lib.match(/pub struct CreatorProfile \{[\s\S]*?\n\}/) || [''])[0];
+    expect(profile).to.match(/pub bump: u8,[\s\S]*pub slot_count: u64,[\s\S]*pub series_count: u64,\s*\}/);
+    const slot = (lib.match(/pub struct TimeSlot \{[\s\S]*?\n\}/) || [''])[0];
+    expect(slot).to.match(/pub total_tips_received: u64,\s*pub bump: u8,/);
+    expect(slot).to.match(/pub index: u64,\s*\}/);
+  });
+
+  it('create_time_slot stores the index and bumps the counter', () => {
+    const market = read(marketPath);
+    expect(market).to.match(/slot\.index\s*=\s*profile\.slot_count/);
+    expect(market).to.match(/profile\.slot_count\s*=\s*profile\.slot_count\.checked_add\(1\)/);
+  });
+
+  it('SDK derives slots by index and keeps the start_ts derivation under its old name', () => {
+    const pdas = read(pdasPath);
+    expect(pdas).to.match(/export function timeSlotPdaByIndex\(profile: PublicKey, index: number \| bigint/);
+    expect(pdas).to.match(/export function timeSlotPda\(profile: PublicKey, startTs: number/);
+  });
+});
@@ -0,0 +1,55 @@
+/// <reference types="mocha" />
+/// <reference types="node" />
+import { readFileSync } from 'fs';
+import { join } from 'path';
+import { expect } from 'chai';
+
+function read(path: string) { return readFileSync(path, 'utf8'); }
+
+describe('Checkout holds (P1) TDD', () => {
+  const root = process.cwd();
+  const src = (f: string) => join(root, 'programs', 'timemarket', 'src', f);
+
+  it('exposes hold config, hold and release instructions with their PDAs', () => {
+    const lib = read(src('lib.rs'));
+    expect(lib).to.match(/pub fn set_hold_config\(\s*ctx: Context<SetHoldConfig>,\s*max_hold_sec: u32,\s*deposit_lamports: u64,\s*cooldown_sec: u32,/);
+    expect(lib).to.match(/pub fn hold_slot\(ctx: Context<HoldSlot>, duration_sec: u32\)/);
+    expect(lib).to.match(/pub fn release_hold\(ctx: Context<ReleaseHold>\)/);
+    expect(lib).to.match(/seeds = \[b"hold_config", slot\.platform\.as_ref\(\)\]/);
+    expect(lib).to.match(/seeds = \[b"hold", slot\.key\(\)\.as_ref\(\)\]/);
+    expect(lib).to.match(/seeds = \[b"hold_quota", slot\.platform\.as_ref\(\), holder\.key\(\)\.as_ref\(\)\]/);
+    expect(lib).to.match(/close = holder,/);
+    expect(lib).to.match(/pub hold: Option<ActiveHold>,/);
+    const pdas = read(join(root, 'packages', 'ts-sdk', 'src', 'helpers', 'pdas.ts'));
+    for (const fn of ['holdConfigPda', 'slotHoldPda', 'holdQuotaPda']) {
+      expect(pdas).to.match(new RegExp(`export function ${fn}\\(`));
+    }
+  });
+
+  it('caps duration, rate-limits and takes the deposit into the hold account', () => {
+    const hold = read(src('hold.rs'));
+    expect(hold).to.match(/require!\(max_hold_sec > 0 && cooldown_sec > 0, ErrorCode::InvalidHoldConfig\)/);
+    expect(hold).to.match(/quota\.next_hold_at = expires_at\.checked_add\(config\.cooldown_sec as i64\)/);
+    expect(hold).to.match(/duration_sec <= config\.max_hold_sec, ErrorCode::HoldTooLong/);
+    expect(hold).to.match(/now >= quota\.next_hold_at, ErrorCode::HoldRateLimited/);
+    expect(hold).to.match(/to: ctx\.accounts\.hold\.to_account_info\(\)/);
+    expect(hold).to.match(/!active \|\| ctx\.accounts\.caller\.key\(\) == hold\.holder, ErrorCode::HoldStillActive/);
+  });
+
+  it('only lets the holder reserve until the hold expires', () => {
+    const escrow = read(src('escrow.rs'));
+    const body = (escrow.match(/fn reserve<'info>\([\s\S]*?\n\}/) || [''])[0];
+    expect(body).to.match(/now >= hold\.expires_at \|\| hold\.holder == beneficiary, ErrorCode::SlotHeld/);
+  });
+
+  it('an expired hold that was never released can be taken over', () => {
+    const lib = read(src('lib.rs'));
+    const ctx = (lib.match(/pub struct HoldSlot<'info> \{[\s\S]*?\n\}/) || [''])[0];
+    expect(ctx).to.match(/init_if_needed,\s*payer = holder,\s*space = 8 \+ SlotHold::LEN,\s*seeds = \[b"hold"/);
+    expect(ctx).to.match(/pub previous_holder: Option<UncheckedAccount<'info>>/);
+    const hold = read(src('hold.rs'));
+    expect(hold).to.match(/require!\(previous\.expires_at <= now, ErrorCode::SlotHeld\)/);
+    expect(hold).to.match(/require_keys_eq!\(previous_holder\.key\(\), previous\.holder, ErrorCode::Unauthorized\)/);
+    expect(hold).to.match(/HoldReleasedEvent \{ slot: slot\.key\(\), holder: previous\.holder, deposit: previous\.deposit \}/);
+  });
+});
@@ -0,0 +1,46 @@
+/// <reference types="mocha" />
+/// <reference types="node" />
+import { readFileSync } from 'fs';
+import { join } from 'path';
+import { expect } from 'chai';
+
+function read(path: string) { return readFileSync(path, 'utf8'); }
+
+describe('Program-created slot NFT (P1) TDD', () => {
+  const root = process.cwd();
+  const src = (f: string) => join(root, 'programs', 'timemarket', 'src', f);
+
+  it('init_slot_nft creates a 0-decimal mint under nft_auth', () => {
+    const lib = read(src('lib.rs'));
+    expect(lib).to.match(/pub fn init_slot_nft\(ctx: Context<InitSlotNft>, params: SlotNftParams\)/);
+    const ctx = (lib.match(/pub struct InitSlotNft<'info> \{[\s\S]*?\n\}/) || [''])[0];
+    expect(ctx).to.match(/seeds = \[b"nft_mint", slot\.key\(\)\.as_ref\(\)\]/);
+    expect(ctx).to.match(/mint::decimals = 0/);
+    expect(ctx).to.match(/mint::authority = nft_auth/);
+    expect(ctx).to.match(/seeds::program = metadata_program\.key\(\)/);
+    expect(ctx).to.match(/pub metadata_program: Program<'info, Metadata>/);
+    expect(lib).to.match(/pub struct SlotNftParams \{[\s\S]*?name: String[\s\S]*?symbol: String[\s\S]*?uri: String/);
+  });
+
+  it('writes Metaplex metadata with the creator and royalty', () => {
+    const nft = read(src('nft.rs'));
+    expect(nft).to.match(/create_metadata_accounts_v3\(/);
+    expect(nft).to.match(/seller_fee_basis_points: slot\.resale_royalty_bps/);
+    expect(nft).to.match(/Creator \{ address: slot\.creator_authority/);
+    expect(nft).to.match(/slot\.nft_mint = ctx\.accounts\.nft_mint\.key\(\);/);
+  });
+
+  it('every mint path goes through the capacity-capped helper', () => {
+    const nft = read(src('nft.rs'));
+    expect(nft).to.match(/require!\(slot\.nft_minted < slot\.capacity_total, ErrorCode::NftSupplyExhausted\)/);
+    const escrow = read(src('escrow.rs'));
+    const market = read(src('market.rs'));
+    expect(escrow).to.match(/pub fn stable_checkin[\s\S]*?crate::nft::mint_slot_nft\(/);
+    for (const fn of ['buy_now', 'auction_end', 'sealed_auction_end']) {
+      expect(market).to.match(new RegExp(`pub fn ${fn}<'info>[\\s\\S]*?crate::nft::mint_slot_nft\\(`));
+    }
+    expect(escrow).to.not.match(/mint_to\(/);
+    expect(market).to.not.match(/mint_to\(/);
+    expect(read(src('ticket.rs'))).to.match(/crate::nft::mint_slot_nft\(/);
+  });
+});
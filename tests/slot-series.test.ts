@@ -0,0 +1,57 @@
+/// <reference types="mocha" />
+/// <reference types="node" />
+import { readFileSync } from 'fs';
+import { join } from 'path';
+import { expect } from 'chai';
+
+function read(path: string) { return readFileSync(path, 'utf8'); }
+
+describe('Recurring slot series (P2) TDD', () => {
+  const root = process.cwd();
+  const rustLibPath = join(root, 'programs', 'timemarket', 'src', 'lib.rs');
+  const seriesPath = join(root, 'programs', 'timemarket', 'src', 'series.rs');
+  const marketPath = join(root, 'programs', 'timemarket', 'src', 'market.rs');
+
+  it('SlotSeries template holds mode, price, duration, capacity, interval and count', () => {
+    const lib = read(rustLibPath);
+    expect(lib).to.match(/pub struct SlotSeries[\s\S]*duration_sec: u32[\s\S]*interval_sec: u32[\s\S]*count: u16[\s\S]*materialized: u16[\s\S]*mode: Mode[\s\S]*capacity: u16[\s\S]*price: u64/);
+    expect(lib).to.match(/seeds = \[b"series", profile\.key\(\)\.as_ref\(\), &profile\.series_count\.to_le_bytes\(\)\]/);
+  });
+
+  it('lib.rs exposes create/update/materialize entrypoints', () => {
+    const lib = read(rustLibPath);
+    expect(lib).to.match(/pub fn create_slot_series\(ctx: Context<CreateSlotSeries>, params: CreateSeriesParams\)/);
+    expect(lib).to.match(/pub fn update_slot_series<'info>\(/);
+    expect(lib).to.match(/pub fn materialize_series<'info>\(/);
+  });
+
+  it('materialize_series creates slots at the creator counter with the shared validation', () => {
+    const series = read(seriesPath);
+    expect(series).to.match(/validate_slot_params\(&params\)\?/);
+    expect(series).to.match(/create_slot_account\(info, &payer, &system_program, &profile_key, profile\.slot_count\)/);
+    expect(series).to.match(/SeriesExhausted/);
+    const market = read(marketPath);
+    expect(market).to.match(/pub fn create_time_slot[\s\S]*validate_slot_params\(&params\)\?/);
+  });
+
+  it('template edits only reach untouched future occurrences', () => {
+    const series = read(seriesPath);
+    expect(series).to.match(/let \[info, escrow_info, commit_info\] = chunk else/);
+    expect(series).to.match(/require_keys_eq!\(escrow_info\.key\(\), escrow_pda, ErrorCode::InvalidSlotAccount\)/);
+    expect(series).to.match(/slot\.start_ts <= now \|\| !is_untouched\(&slot, escrow_info, commit_info, now\)\?/);
+  });
+
+  it('template terms are validated even when every occurrence is materialized', () => {
+    const series = read(seriesPath);
+    const validate = (series.match(/fn validate_series[\s\S]*?\n\}/) || [''])[0];
+    expect(validate).to.not.match(/if series\.materialized < series\.count/);
+    expect(validate).to.match(/series\.materialized\.min\(series\.count - 1\)/);
+    // each overwritten occurrence is re-checked before it is written back
+    expect(series).to.match(/validate_slot_params\(&slot_params\(&slot\)\)\?;\s*slot\.exit\(&crate::ID\)\?/);
+  });
+
+  it('the schedule is fixed once occurrences are materialized', () => {
+    const series = read(seriesPath);
+    expect(series).to.match(/if series\.materialized > 0 \{[\s\S]*?params\.duration_sec\.map_or\(true, \|v\| v == series\.duration_sec\)[\s\S]*?params\.interval_sec\.map_or\(true, \|v\| v == series\.interval_sec\)[\s\S]*?ErrorCode::InvalidTimes/);
+  });
+});
@@ -13,7 +13,7 @@ describe('SOL Stable flow (P2 optional) TDD', () => {
 
   it('lib.rs exposes SOL stable entrypoints', () => {
     const lib = read(rustLibPath);
-    expect(lib).to.match(/pub fn stable_reserve_sol\(ctx: Context<StableReserveSol>\) -> Result<\(\)>/);
+    expect(lib).to.match(/pub fn stable_reserve_sol\(ctx: Context<StableReserveSol>(,\s*allowlist: Option<AllowlistProof>)?\) -> Result<\(\)>/);
     expect(lib).to.match(/pub fn stable_cancel_sol\(ctx: Context<StableCancelSol>\) -> Result<\(\)>/);
     expect(lib).to.match(/pub fn stable_settle_sol\(ctx: Context<StableSettleSol>\) -> Result<\(\)>/);
   });
@@ -21,7 +21,7 @@ describe('SOL Stable flow (P2 optional) TDD', () => {
   it('escrow.rs implements SOL stable reserve/cancel/settle using lamports logic', () => {
     const escrow = read(escrowPath);
     // Reserve transfers via system_program::transfer
-    expect(escrow).to.match(/stable_reserve_sol\(ctx: Context<StableReserveSol>\)/);
+    expect(escrow).to.match(/stable_reserve_sol\(ctx: Context<StableReserveSol>(,\s*allowlist: Option<AllowlistProof>)?\)/);
     expect(escrow).to.match(/system_program::transfer\(/);
     // Cancel moves lamports back via lamports borrows
     expect(escrow).to.match(/stable_cancel_sol\(ctx: Context<StableCancelSol>\)/);
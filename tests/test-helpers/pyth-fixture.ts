@@ -0,0 +1,26 @@
+// Pyth v2 price account bytes for local validators/bankrun, matching the fields oracle.rs reads
+export const PYTH_PRICE_ACCOUNT_SIZE = 3312;
+// oracle.rs only reads feeds owned by the Pyth program; load fixtures under this owner
+export const PYTH_DEVNET_PROGRAM_ID = 'gSbePebfvPy7tRqimPoVecS2UsBvYv46ynrzWocc92s';
+
+export interface PythPriceFixture {
+  price: bigint;
+  conf: bigint;
+  expo: number;
+  publishTime: bigint;
+  trading?: boolean;
+}
+
+export function pythPriceAccountData(f: PythPriceFixture): Buffer {
+  const data = Buffer.alloc(PYTH_PRICE_ACCOUNT_SIZE);
+  data.writeUInt32LE(0xa1b2c3d4, 0); // magic
+  data.writeUInt32LE(2, 4); // version
+  data.writeUInt32LE(3, 8); // account type: price
+  data.writeUInt32LE(PYTH_PRICE_ACCOUNT_SIZE, 12);
+  data.writeInt32LE(f.expo, 20);
+  data.writeBigInt64LE(f.publishTime, 96);
+  data.writeBigInt64LE(f.price, 208);
+  data.writeBigUInt64LE(f.conf, 216);
+  data.writeUInt32LE(f.trading === false ? 0 : 1, 224);
+  return data;
+}
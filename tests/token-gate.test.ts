@@ -0,0 +1,63 @@
+/// <reference types="mocha" />
+/// <reference types="node" />
+import { readFileSync } from 'fs';
+import { join } from 'path';
+import { expect } from 'chai';
+
+function read(path: string) { return readFileSync(path, 'utf8'); }
+
+describe('Token-gated reservations (P1) TDD', () => {
+  const root = process.cwd();
+  const src = (f: string) => join(root, 'programs', 'timemarket', 'src', f);
+
+  it('slots and series carry an optional mint or collection gate', () => {
+    const lib = read(src('lib.rs'));
+    expect(lib).to.match(/pub enum GateKind \{[\s\S]*?Mint,[\s\S]*?Collection,/);
+    expect(lib).to.match(/pub struct TokenGate \{[\s\S]*?pub key: Pubkey,[\s\S]*?pub min_amount: u64/);
+    expect(lib).to.match(/struct CreateSlotParams \{[\s\S]*?pub gate: Option<TokenGate>/);
+    expect(lib).to.match(/pub struct TimeSlot \{[\s\S]*?pub gate: Option<TokenGate>/);
+    expect(lib).to.match(/pub struct SlotSeries \{[\s\S]*?pub gate: Option<TokenGate>/);
+    expect(read(src('market.rs'))).to.match(/slot\.gate = params\.gate;/);
+    expect(read(src('series.rs'))).to.match(/gate: series\.gate/);
+  });
+
+  it('booking entry points take gate accounts and reject non-holders', () => {
+    const lib = read(src('lib.rs'));
+    for (const ctx of ['StableReserve', 'StableReserveSol', 'BidPlace', 'BuyNow', 'BidCommit']) {
+      const body = (lib.match(new RegExp(`pub struct ${ctx}<'info> \\{[\\s\\S]*?\\n\\}`)) || [''])[0];
+      expect(body, ctx).to.match(/pub gate_token: Option<InterfaceAccount<'info, TokenAccount>>/);
+      expect(body, ctx).to.match(/pub gate_metadata: Option<UncheckedAccount<'info>>/);
+    }
+    expect(lib).to.match(/GateNotSatisfied/);
+    const calls = ['escrow.rs', 'market.rs', 'auction_sol.rs', 'resale.rs']
+      .map((f) => (read(src(f)).match(/crate::gate::check_gate\(/g) || []).length)
+      .reduce((a, b) => a + b, 0);
+    expect(calls).to.equal(9);
+  });
+
+  it('checks the attendee, not the payer, and follows the booking on transfer and resale', () => {
+    const escrow = read(src('escrow.rs'));
+    const market = read(src('market.rs'));
+    expect(escrow).to.match(/pub fn stable_reserve<'info>[\s\S]*?let beneficiary = [^;]*;\s*crate::gate::check_gate\(\s*&ctx\.accounts\.slot,\s*beneficiary,/);
+    expect(escrow).to.match(/pub fn stable_reserve_sol[\s\S]*?crate::gate::check_gate\(\s*&ctx\.accounts\.slot,\s*beneficiary,/);
+    expect(market).to.match(/pub fn bid_place<'info>[\s\S]*?crate::gate::check_gate\(\s*&ctx\.accounts\.slot,\s*beneficiary,/);
+    expect(read(src('auction_sol.rs'))).to.match(/pub fn bid_place_sol[\s\S]*?crate::gate::check_gate\(\s*&ctx\.accounts\.slot,\s*beneficiary,/);
+    expect(escrow).to.match(/pub fn transfer_reservation[\s\S]*?crate::gate::check_gate\(\s*slot,\s*new_holder,/);
+    expect(read(src('resale.rs'))).to.match(/pub fn buy_listing[\s\S]*?crate::gate::check_gate\(\s*slot,\s*ctx\.accounts\.buyer\.key\(\),/);
+    const lib = read(src('lib.rs'));
+    for (const ctx of ['TransferReservation', 'BuyListing']) {
+      const body = (lib.match(new RegExp(`pub struct ${ctx}<'info> \\{[\\s\\S]*?\\n\\}`)) || [''])[0];
+      expect(body, ctx).to.match(/pub gate_token: Option<InterfaceAccount<'info, TokenAccount>>/);
+    }
+    // tickets trade outside the program, so gated slots cannot issue them
+    expect(market).to.match(/if let Some\(gate\) = &params\.gate \{[\s\S]*?require!\(!params\.nft_ticket, ErrorCode::NftTicketMode\)/);
+  });
+
+  it('collection gates require a verified collection in the NFT metadata', () => {
+    const gate = read(src('gate.rs'));
+    expect(gate).to.match(/require_keys_eq!\(token\.owner, holder, ErrorCode::GateNotSatisfied\)/);
+    expect(gate).to.match(/token\.amount >= gate\.min_amount/);
+    expect(gate).to.match(/Metadata::find_pda\(&token\.mint\)/);
+    expect(gate).to.match(/c\.verified && c\.key == gate\.key/);
+  });
+});
@@ -0,0 +1,247 @@
+/// <reference types="mocha" />
+/// <reference types="node" />
+import { readFileSync } from 'fs';
+import { join } from 'path';
+import { expect } from 'chai';
+import * as anchor from '@coral-xyz/anchor';
+import * as spl from '@solana/spl-token';
+
+function read(path: string) { return readFileSync(path, 'utf8'); }
+
+describe('Token-2022 transfer-fee aware settlement (P1) TDD', () => {
+  const root = process.cwd();
+  const escrowPath = join(root, 'programs', 'timemarket', 'src', 'escrow.rs');
+  const marketPath = join(root, 'programs', 'timemarket', 'src', 'market.rs');
+
+  it('measures what the vault actually received before crediting escrow', () => {
+    const escrow = read(escrowPath);
+    expect(escrow).to.match(/pub fn collect_spl[\s\S]*?calculate_inverse_epoch_fee|calculate_inverse_epoch_fee[\s\S]*?pub fn collect_spl/);
+    expect(escrow).to.match(/let before = token_amount\(vault\)\?;[\s\S]*?let received = token_amount\(vault\)\?[\s\S]*?require!\(received >= amount, ErrorCode::InvalidEscrowBalance\)/);
+    expect(escrow).to.match(/PaymentRail::Spl \{[^}]*\} => \{\s*collect_spl\(/);
+  });
+
+  it('auction deposits go through the same measured collection', () => {
+    const market = read(marketPath);
+    expect(market).to.match(/pub fn buy_now[\s\S]*?EscrowPayment::spl\([\s\S]*?payment\.collect\(/);
+    expect(market).to.match(/pub fn bid_place[\s\S]*?EscrowPayment::spl\([\s\S]*?payment\.collect\(/);
+  });
+
+  it('budgets outbound fees with TransferCheckedWithFee', () => {
+    const escrow = read(escrowPath);
+    expect(escrow).to.match(/pub fn pay_spl[\s\S]*?calculate_epoch_fee|calculate_epoch_fee[\s\S]*?pub fn pay_spl/);
+    expect(escrow).to.match(/transfer_checked_with_fee\(/);
+    expect(escrow).to.match(/pay_spl\(\s*token_program,\s*vault,\s*mint,\s*payout\.to/);
+  });
+});
+
+// Runs against a local validator with the program deployed (`anchor test`)
+(process.env.ANCHOR_PROVIDER_URL ? describe : describe.skip)('Token-2022 transfer-fee settlement (integration)', () => {
+  const FEE_BPS = 100;
+  const PLATFORM_FEE_BPS = 250;
+  const PRICE = 1_000_000;
+  const programId = spl.TOKEN_2022_PROGRAM_ID;
+
+  let provider: anchor.AnchorProvider;
+  let program: any;
+  let conn: anchor.web3.Connection;
+  let payer: anchor.web3.Keypair;
+  let admin: anchor.web3.PublicKey;
+  let mint: anchor.web3.PublicKey;
+  let platform: anchor.web3.PublicKey;
+  let acceptedMint: anchor.web3.PublicKey;
+  let profile: anchor.web3.PublicKey;
+  let feeVault: anchor.web3.PublicKey;
+  let disputeVault: anchor.web3.PublicKey;
+  let buyerToken: anchor.web3.PublicKey;
+  let payoutWallet: anchor.web3.PublicKey;
+  let creatorPayoutAta: anchor.web3.PublicKey;
+  const pda = (seeds: Buffer[]) => anchor.web3.PublicKey.findProgramAddressSync(seeds, program.programId)[0];
+  const ata = async (owner: anchor.web3.PublicKey) =>
+    (await spl.getOrCreateAssociatedTokenAccount(conn, payer, mint, owner, true, undefined, undefined, programId)).address;
+  const balance = async (account: anchor.web3.PublicKey) =>
+    (await spl.getAccount(conn, account, undefined, programId)).amount;
+  const bps = (amount: number, b: number) => Math.floor((amount * b) / 10_000);
+
+  // What a recipient keeps of an outbound payout once the mint withholds its fee
+  const received = async (amount: number) => {
+    const config = spl.getTransferFeeConfig(await spl.getMint(conn, mint, undefined, programId));
+    const { epoch } = await conn.getEpochInfo();
+    return BigInt(amount) - spl.calculateEpochFee(config!, BigInt(epoch), BigInt(amount));
+  };
+
+  before(async () => {
+    provider = anchor.AnchorProvider.env();
+    anchor.setProvider(provider);
+    program = anchor.workspace.Timemarket;
+    conn = provider.connection;
+    payer = (provider.wallet as anchor.Wallet).payer;
+    admin = provider.wallet.publicKey;
+
+    // Token-2022 mint charging 1% on every transfer
+    const mintKp = anchor.web3.Keypair.generate();
+    mint = mintKp.publicKey;
+    const mintLen = spl.getMintLen([spl.ExtensionType.TransferFeeConfig]);
+    await provider.sendAndConfirm(new anchor.web3.Transaction().add(
+      anchor.web3.SystemProgram.createAccount({
+        fromPubkey: admin,
+        newAccountPubkey: mint,
+        space: mintLen,
+        lamports: await conn.getMinimumBalanceForRentExemption(mintLen),
+        programId,
+      }),
+      spl.createInitializeTransferFeeConfigInstruction(mint, admin, admin, FEE_BPS, BigInt(PRICE), programId),
+      spl.createInitializeMintInstruction(mint, 6, admin, null, programId),
+    ), [mintKp]);
+
+    platform = pda([Buffer.from('platform'), admin.toBuffer()]);
+    const feeAuthority = pda([Buffer.from('fee'), platform.toBuffer()]);
+    acceptedMint = pda([Buffer.from('accepted_mint'), platform.toBuffer(), mint.toBuffer()]);
+    profile = pda([Buffer.from('creator'), admin.toBuffer(), platform.toBuffer()]);
+
+    // Creator payouts go to their own wallet so they don't mix with the buyer's refunds
+    payoutWallet = anchor.web3.Keypair.generate().publicKey;
+    feeVault = await ata(feeAuthority);
+    disputeVault = await ata(platform);
+    buyerToken = await ata(admin);
+    creatorPayoutAta = await ata(payoutWallet);
+    await spl.mintTo(conn, payer, mint, buyerToken, payer, 10 * PRICE, [], undefined, programId);
+
+    await program.methods.initPlatform(PLATFORM_FEE_BPS)
+      .accountsPartial({ admin, platform, feeAuthority, mint, feeVault, disputeVault, acceptedMint })
+      .rpc();
+    await program.methods.initCreatorProfile(payoutWallet, null)
+      .accountsPartial({ authority: admin, platform, profile })
+      .rpc();
+  });
+
+  // Next slot on the profile, with its escrow vault initialised
+  const createSlot = async (startTs: number, cancelPolicy: { secsBeforeStart: number; refundBps: number }[]) => {
+    const { slotCount } = await program.account.creatorProfile.fetch(profile);
+    const slot = pda([Buffer.from('slot'), profile.toBuffer(), slotCount.toArrayLike(Buffer, 'le', 8)]);
+    const escrow = pda([Buffer.from('escrow'), slot.toBuffer()]);
+    const escrowVault = await ata(escrow);
+    await program.methods.createTimeSlot({
+      startTs: new anchor.BN(startTs),
+      endTs: new anchor.BN(startTs + 3_600),
+      tzOffsetMin: 0,
+      subjectHash: Array(32).fill(0),
+      venueHash: Array(32).fill(0),
+      mode: { stable: {} },
+      capacity: 1,
+      nftMint: null,
+      price: new anchor.BN(PRICE),
+      minIncrementBps: 0,
+      buyNow: null,
+      auctionStartTs: null,
+      auctionEndTs: null,
+      antiSnipingSec: null,
+      cancelPolicy,
+      paymentKind: { spl: {} },
+      mint,
+      usdQuote: null,
+      transfersDisabled: false,
+      resaleRoyaltyBps: 0,
+      maxResalePrice: null,
+      nftTicket: false,
+      gate: null,
+      allowlistRoot: null,
+      approvalWindowSec: null,
+      draft: false,
+    }).accountsPartial({ authority: admin, platform, acceptedMint, profile, slot }).rpc();
+    await program.methods.initEscrow()
+      .accountsPartial({ admin, platform, mint, slot, escrow, escrowVault, tokenProgram: programId })
+      .rpc();
+    return { slot, escrow, escrowVault };
+  };
+
+  const reserve = (slot: anchor.web3.PublicKey, escrow: anchor.web3.PublicKey, escrowVault: anchor.web3.PublicKey) =>
+    program.methods.stableReserve(new anchor.BN(PRICE), null)
+      .accountsPartial({ buyer: admin, payer: admin, platform, mint, slot, escrow, escrowVault, buyerToken, tokenProgram: programId, priceFeed: null })
+      .rpc();
+
+  it('stable_reserve locks the full price even when the mint withholds a fee', async () => {
+    const { slot, escrow, escrowVault } = await createSlot(Math.floor(Date.now() / 1000) + 86_400, []);
+
+    const buyerBefore = await balance(buyerToken);
+    await reserve(slot, escrow, escrowVault);
+
+    const escrowAcc = await program.account.escrow.fetch(escrow);
+    const vault = await spl.getAccount(conn, escrowVault, undefined, programId);
+    const buyerAfter = await balance(buyerToken);
+    expect(escrowAcc.amountLocked.toNumber()).to.equal(PRICE);
+    expect(Number(vault.amount) >= PRICE).to.equal(true);
+    // the buyer covers the inbound fee on top of the price
+    expect(Number(buyerBefore - buyerAfter) > PRICE).to.equal(true);
+  });
+
+  it('stable_cancel splits exactly what the vault received between refund, creator and platform', async () => {
+    // Half back at any lead time, so every leg of the split is non-zero
+    const { slot, escrow, escrowVault } = await createSlot(
+      Math.floor(Date.now() / 1000) + 86_400,
+      [{ secsBeforeStart: 0, refundBps: 5_000 }],
+    );
+    await reserve(slot, escrow, escrowVault);
+
+    const refund = bps(PRICE, 5_000);
+    const fee = bps(PRICE - refund, PLATFORM_FEE_BPS);
+    const creator = PRICE - refund - fee;
+    const [vaultBefore, buyerBefore, creatorBefore, feeBefore] = await Promise.all(
+      [escrowVault, buyerToken, creatorPayoutAta, feeVault].map(balance),
+    );
+
+    await program.methods.stableCancel()
+      .accountsPartial({
+        buyer: admin, platform, mint, slot, escrow, escrowVault, buyerToken, profile,
+        profilePayoutWallet: payoutWallet, creatorPayoutAta, acceptedMint, feeVault,
+        tokenProgram: programId, nftMint: null, ticket: null,
+      })
+      .rpc();
+
+    const [vaultAfter, buyerAfter, creatorAfter, feeAfter] = await Promise.all(
+      [escrowVault, buyerToken, creatorPayoutAta, feeVault].map(balance),
+    );
+    // the vault pays out exactly the locked price, no more and no less
+    expect(vaultBefore - vaultAfter).to.equal(BigInt(PRICE));
+    expect((await program.account.escrow.fetch(escrow)).amountLocked.toNumber()).to.equal(0);
+    // each recipient gets its share less the mint's outbound fee
+    expect(buyerAfter - buyerBefore).to.equal(await received(refund));
+    expect(creatorAfter - creatorBefore).to.equal(await received(creator));
+    expect(feeAfter - feeBefore).to.equal(await received(fee));
+  });
+
+  it('stable_settle releases the T0 share the vault holds to creator and platform', async function () {
+    this.timeout(60_000);
+    const startTs = Math.floor(Date.now() / 1000) + 5;
+    const { slot, escrow, escrowVault } = await createSlot(startTs, []);
+    await reserve(slot, escrow, escrowVault);
+
+    // T0 opens at the session start on the validator's clock
+    while (((await conn.getBlockTime(await conn.getSlot())) ?? 0) < startTs) {
+      await new Promise((r) => setTimeout(r, 500));
+    }
+
+    const t0Fee = bps(bps(PRICE, PLATFORM_FEE_BPS), 5_000);
+    const t0Creator = bps(PRICE, 5_000) - t0Fee;
+    const [vaultBefore, creatorBefore, feeBefore] = await Promise.all(
+      [escrowVault, creatorPayoutAta, feeVault].map(balance),
+    );
+
+    await program.methods.stableSettle()
+      .accountsPartial({
+        authority: admin, platform, mint, slot, escrow, escrowVault, profile,
+        profilePayoutWallet: payoutWallet, creatorPayoutAta, acceptedMint, feeVault, disputeVault,
+        tokenProgram: programId,
+      })
+      .rpc();
+
+    const [vaultAfter, creatorAfter, feeAfter] = await Promise.all(
+      [escrowVault, creatorPayoutAta, feeVault].map(balance),
+    );
+    const escrowAcc = await program.account.escrow.fetch(escrow);
+    // the vault pays out exactly what escrow stops tracking
+    expect(vaultBefore - vaultAfter).to.equal(BigInt(t0Creator + t0Fee));
+    expect(escrowAcc.amountLocked.toNumber()).to.equal(PRICE - t0Creator - t0Fee);
+    expect(creatorAfter - creatorBefore).to.equal(await received(t0Creator));
+    expect(feeAfter - feeBefore).to.equal(await received(t0Fee));
+  });
+});
@@ -0,0 +1,42 @@
+/// <reference types="mocha" />
+/// <reference types="node" />
+import { readFileSync } from 'fs';
+import { join } from 'path';
+import { expect } from 'chai';
+
+function read(path: string) { return readFileSync(path, 'utf8'); }
+
+describe('Token-2022 transfer hook support (P1) TDD', () => {
+  const root = process.cwd();
+  const src = (f: string) => join(root, 'programs', 'timemarket', 'src', f);
+
+  it('routes token transfers through the hook-aware onchain helpers', () => {
+    const escrow = read(src('escrow.rs'));
+    expect(escrow).to.match(/pub fn hooked_transfer_checked<'info>\(\s*ctx: CpiContext<'_, '_, '_, 'info, TransferChecked<'info>>/);
+    expect(escrow).to.match(/invoke_transfer_checked\([\s\S]*?&ctx\.remaining_accounts/);
+    expect(escrow).to.match(/invoke_transfer_checked_with_fee\(/);
+    expect(escrow).to.match(/hook_accounts: hook_accounts\.to_vec\(\)/);
+    expect(escrow).to.match(/pub fn collect_spl[\s\S]*?\.with_remaining_accounts\(hook_accounts\.to_vec\(\)\)/);
+  });
+
+  it('no transfer path calls plain transfer_checked any more', () => {
+    for (const f of ['escrow.rs', 'market.rs', 'tipping.rs', 'reschedule.rs']) {
+      expect(read(src(f))).to.not.match(/(^|[^_\w])transfer_checked\(/m);
+    }
+  });
+
+  it('forwards remaining_accounts as hook accounts on every SPL transfer path', () => {
+    const market = read(src('market.rs'));
+    const tipping = read(src('tipping.rs'));
+    const reschedule = read(src('reschedule.rs'));
+    expect(market).to.match(/EscrowPayment::spl\([\s\S]*?ctx\.remaining_accounts,\s*\)\?;/);
+    expect(market).to.not.match(/hooked_transfer_checked/);
+    expect(tipping).to.match(/pub fn tip_creator_spl<'info>\(ctx: Context<'_, '_, 'info, 'info, TipCreatorSpl<'info>>/);
+    expect(tipping).to.match(/hooked_transfer_checked\([\s\S]*?\.with_remaining_accounts\(ctx\.remaining_accounts\.to_vec\(\)\)/);
+    expect(reschedule).to.match(/EscrowPayment::spl\([\s\S]*?ctx\.remaining_accounts,\s*\)\?;/);
+    const lib = read(src('lib.rs'));
+    for (const [fn, ctx] of [['stable_reserve', 'StableReserve'], ['stable_settle', 'StableSettle'], ['auction_settle', 'AuctionSettle'], ['tip_for_session_spl', 'TipForSessionSpl']]) {
+      expect(lib).to.match(new RegExp(`pub fn ${fn}<'info>\\(\\s*ctx: Context<'_, '_, 'info, 'info, ${ctx}<'info>>`));
+    }
+  });
+});
@@ -0,0 +1,39 @@
+/// <reference types="mocha" />
+/// <reference types="node" />
+import { readFileSync } from 'fs';
+import { join } from 'path';
+import { expect } from 'chai';
+
+function read(path: string) { return readFileSync(path, 'utf8'); }
+
+describe('Reservation transfer (P1) TDD', () => {
+  const root = process.cwd();
+  const src = (f: string) => join(root, 'programs', 'timemarket', 'src', f);
+
+  it('exposes transfer_reservation with an optional refund-rights handover', () => {
+    const lib = read(src('lib.rs'));
+    expect(lib).to.match(/pub fn transfer_reservation\(\s*ctx: Context<TransferReservation>,\s*new_holder: Pubkey,\s*with_refund_rights: bool,(\s*allowlist: Option<AllowlistProof>,)?\s*\)/);
+    expect(lib).to.match(/pub struct TransferReservation<'info> \{[\s\S]*?pub holder: Signer<'info>/);
+    expect(lib).to.match(/pub struct ReservationTransferredEvent \{[\s\S]*?refund_to: Pubkey/);
+  });
+
+  it('creators can disable transfers per slot and per series', () => {
+    const lib = read(src('lib.rs'));
+    expect(lib).to.match(/struct CreateSlotParams \{[\s\S]*?pub transfers_disabled: bool/);
+    expect(lib).to.match(/pub struct TimeSlot \{[\s\S]*?pub transfers_disabled: bool/);
+    expect(lib).to.match(/pub struct SlotSeries \{[\s\S]*?pub transfers_disabled: bool/);
+    expect(read(src('market.rs'))).to.match(/slot\.transfers_disabled = params\.transfers_disabled;/);
+    expect(read(src('series.rs'))).to.match(/transfers_disabled: series\.transfers_disabled/);
+    expect(lib).to.match(/TransfersDisabled/);
+  });
+
+  it('moves holder rights before check-in and keeps refunds with the payer by default', () => {
+    const escrow = read(src('escrow.rs'));
+    const body = (escrow.match(/pub fn transfer_reservation\([\s\S]*?\n\}/) || [''])[0];
+    expect(body).to.match(/require!\(!slot\.transfers_disabled, ErrorCode::TransfersDisabled\)/);
+    expect(body).to.match(/require!\(!slot\.buyer_checked_in/);
+    expect(body).to.match(/require_keys_eq!\(ctx\.accounts\.holder\.key\(\), holder/);
+    expect(body).to.match(/require_keys_eq!\(payer, holder/);
+    expect(body).to.match(/escrow\.buyer = Some\(new_holder\);\s*escrow\.payer = Some\(refund_to\);/);
+  });
+});
@@ -0,0 +1,35 @@
+/// <reference types="mocha" />
+/// <reference types="node" />
+import { readFileSync } from 'fs';
+import { join } from 'path';
+import { expect } from 'chai';
+
+function read(path: string) { return readFileSync(path, 'utf8'); }
+
+describe('Editable open slots (P1) TDD', () => {
+  const root = process.cwd();
+  const src = (f: string) => join(root, 'programs', 'timemarket', 'src', f);
+
+  it('exposes update_slot with optional edits and a SlotUpdatedEvent', () => {
+    const lib = read(src('lib.rs'));
+    expect(lib).to.match(/pub fn update_slot\(ctx: Context<UpdateSlot>, params: UpdateSlotParams\)/);
+    for (const f of ['price: Option<u64>', 'buy_now: Option<Option<u64>>', 'min_increment_bps: Option<u16>', 'anti_sniping_sec: Option<Option<u32>>', 'subject_hash: Option<\\[u8; 32\\]>', 'venue_hash: Option<\\[u8; 32\\]>']) {
+      expect(lib, f).to.match(new RegExp(`pub struct UpdateSlotParams \\{[\\s\\S]*?pub ${f}`));
+    }
+    expect(lib).to.match(/pub struct SlotUpdatedEvent \{[\s\S]*?pub changed: Vec<SlotField>/);
+  });
+
+  it('only edits untouched Open slots and revalidates like creation', () => {
+    const market = read(src('market.rs'));
+    const body = (market.match(/pub fn update_slot\([\s\S]*?\n\}/) || [''])[0];
+    expect(body).to.match(/require!\(slot\.state == SlotState::Open, ErrorCode::InvalidState\)/);
+    expect(body).to.match(/is_untouched\(slot, &ctx\.accounts\.escrow, &ctx\.accounts\.commit_store, now\)\?,\s*ErrorCode::SlotHasActivity/);
+    const helper = (market.match(/pub fn is_untouched\([\s\S]*?\n\}/) || [''])[0];
+    expect(helper).to.match(/escrow\.buyer\.is_some\(\) \|\| escrow\.amount_locked > 0 \|\| escrow\.requests_locked > 0/);
+    expect(helper).to.match(/store\.count > 0/);
+    expect(helper).to.match(/slot\.hold\.is_some_and\(\|h\| now < h\.expires_at\)/);
+    expect(body).to.match(/changed\.push\(SlotField::Price\)/);
+    expect(body).to.match(/validate_slot_params\(&slot_params\(slot\)\)\?;/);
+    expect(body).to.match(/emit!\(SlotUpdatedEvent \{ slot: slot\.key\(\), changed \}\)/);
+  });
+});
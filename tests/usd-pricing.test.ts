@@ -0,0 +1,62 @@
+/// <reference types="mocha" />
+/// <reference types="node" />
+import { readFileSync } from 'fs';
+import { join } from 'path';
+import { expect } from 'chai';
+import { pythPriceAccountData, PYTH_DEVNET_PROGRAM_ID } from './test-helpers/pyth-fixture';
+
+function read(path: string) { return readFileSync(path, 'utf8'); }
+
+describe('USD pricing via oracle feed (P1) TDD', () => {
+  const root = process.cwd();
+  const src = (f: string) => join(root, 'programs', 'timemarket', 'src', f);
+
+  it('slots carry an optional USD quote pinned to a price feed', () => {
+    const lib = read(src('lib.rs'));
+    expect(lib).to.match(/pub struct UsdQuote \{[\s\S]*price_feed: Pubkey[\s\S]*price_usd_cents: u64[\s\S]*max_staleness_sec: u32[\s\S]*max_conf_bps: u16/);
+    expect(lib).to.match(/struct CreateSlotParams[\s\S]*?pub usd_quote: Option<UsdQuote>/);
+    expect(lib).to.match(/pub struct TimeSlot \{[\s\S]*?pub usd_quote: Option<UsdQuote>/);
+    expect(lib).to.match(/\(1 \+ UsdQuote::LEN\)/);
+    expect(lib).to.match(/pub price_feed: Option<UncheckedAccount<'info>>/);
+  });
+
+  it('oracle reader checks staleness and confidence', () => {
+    const oracle = read(src('oracle.rs'));
+    expect(oracle).to.match(/ErrorCode::OracleStale/);
+    expect(oracle).to.match(/ErrorCode::OracleConfidence/);
+    expect(oracle).to.match(/require_keys_eq!\(feed\.key\(\), quote\.price_feed/);
+    expect(oracle).to.match(/div_ceil/);
+  });
+
+  it('only trusts feeds owned by the Pyth program', () => {
+    const oracle = read(src('oracle.rs'));
+    const convert = (oracle.match(/pub fn usd_to_mint_units[\s\S]*?\n\}/) || [''])[0];
+    expect(convert).to.match(/require_keys_eq!\(\*feed\.owner, PYTH_PROGRAM_ID, ErrorCode::InvalidOracle\)/);
+    const devnet = (oracle.match(/#\[cfg\(not\(feature = "mainnet"\)\)\]\s*pub const PYTH_PROGRAM_ID: Pubkey = pubkey!\("(\w+)"\)/) || [])[1];
+    expect(devnet).to.equal(PYTH_DEVNET_PROGRAM_ID);
+  });
+
+  it('stable_reserve and buy_now convert USD and enforce the max payment', () => {
+    const escrow = read(src('escrow.rs'));
+    const market = read(src('market.rs'));
+    expect(escrow).to.match(/usd_to_mint_units\(&quote, quote\.price_usd_cents/);
+    expect(escrow).to.match(/check_max_payment\(price, Some\(amount\), true\)/);
+    expect(market).to.match(/pub fn buy_now<'info>\(ctx: [^)]*, max_payment: Option<u64>(,\s*allowlist: Option<AllowlistProof>)?\)/);
+    expect(market).to.match(/check_max_payment\(price, max_payment, slot\.usd_quote\.is_some\(\)\)/);
+    expect(read(src('lib.rs'))).to.match(/SlippageExceeded/);
+  });
+
+  it('fixture layout matches the offsets the program reads', () => {
+    const oracle = read(src('oracle.rs'));
+    const off = (name: string) => Number((oracle.match(new RegExp(`const ${name}: usize = (\\d+);`)) || [])[1]);
+    const data = pythPriceAccountData({ price: 150_000_000n, conf: 50_000n, expo: -8, publishTime: 1_700_000_000n });
+    expect(data.length >= off('PYTH_MIN_LEN')).to.equal(true);
+    expect(data.readUInt32LE(off('OFF_MAGIC'))).to.equal(0xa1b2c3d4);
+    expect(data.readUInt32LE(off('OFF_ATYPE'))).to.equal(3);
+    expect(data.readInt32LE(off('OFF_EXPO'))).to.equal(-8);
+    expect(data.readBigInt64LE(off('OFF_TIMESTAMP'))).to.equal(1_700_000_000n);
+    expect(data.readBigInt64LE(off('OFF_AGG_PRICE'))).to.equal(150_000_000n);
+    expect(data.readBigUInt64LE(off('OFF_AGG_CONF'))).to.equal(50_000n);
+    expect(data.readUInt32LE(off('OFF_AGG_STATUS'))).to.equal(1);
+  });
+});